@@ -1,8 +1,11 @@
 use core::ops::RangeInclusive;
 
 use alloc::format;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, AccessibilityTraits, Accessible,
+};
 use waterui_core::{AnyView, View, configurable};
-use waterui_reactive::Binding;
+use waterui_reactive::{Binding, ComputeExt};
 use waterui_text::text;
 
 #[derive(Debug, uniffi::Record)]
@@ -38,6 +41,28 @@ uniffi::custom_type!(RangeInclusiveF64, SliderRange,{
 
 configurable!(Slider, SliderConfig);
 
+impl Accessible for SliderConfig {
+    /// The value is announced as a percentage of the range.
+    fn accessibility_element(self) -> AccessibilityElement {
+        let (start, end) = self.range.into_inner();
+        let value = self.value.map(move |value| {
+            let fraction = if end > start {
+                (value - start) / (end - start)
+            } else {
+                0.0
+            };
+            format!("{:.0}%", fraction * 100.0)
+        });
+        AccessibilityElement::new(
+            Accessibility::new()
+                .role(AccessibilityRole::Slider)
+                .value(value)
+                .traits(AccessibilityTraits::ADJUSTABLE),
+        )
+        .content(self.label)
+    }
+}
+
 impl Slider {
     pub fn new(range: RangeInclusive<f64>, value: &Binding<f64>) -> Self {
         Self(SliderConfig {
@@ -1,5 +1,9 @@
+use alloc::string::ToString;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, AccessibilityTraits, Accessible,
+};
 use waterui_core::{AnyView, configurable};
-use waterui_reactive::{Binding, Computed, compute::IntoComputed};
+use waterui_reactive::{Binding, ComputeExt, Computed, compute::IntoComputed};
 
 #[derive(Debug, uniffi::Record)]
 #[non_exhaustive]
@@ -11,6 +15,18 @@ pub struct StepperConfig {
 
 configurable!(Stepper, StepperConfig);
 
+impl Accessible for StepperConfig {
+    fn accessibility_element(self) -> AccessibilityElement {
+        AccessibilityElement::new(
+            Accessibility::new()
+                .role(AccessibilityRole::Stepper)
+                .value(self.value.map(|value| value.to_string()))
+                .traits(AccessibilityTraits::ADJUSTABLE),
+        )
+        .content(self.label)
+    }
+}
+
 impl Stepper {
     pub fn new(value: &Binding<i32>) -> Self {
         Self(StepperConfig {
@@ -1,4 +1,7 @@
 use waterui_core::Str;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, Accessible,
+};
 use waterui_core::configurable;
 use waterui_core::{AnyView, View};
 use waterui_reactive::Binding;
@@ -17,6 +20,19 @@ pub struct TextFieldConfig {
     pub keyboard: KeyboardType,
 }
 
+impl Accessible for TextFieldConfig {
+    /// The prompt is used as the hint.
+    fn accessibility_element(self) -> AccessibilityElement {
+        AccessibilityElement::new(
+            Accessibility::new()
+                .role(AccessibilityRole::TextField)
+                .value(self.value)
+                .hint(self.prompt.content()),
+        )
+        .content(self.label)
+    }
+}
+
 #[derive(Debug, Default, uniffi::Enum)]
 #[non_exhaustive]
 pub enum KeyboardType {
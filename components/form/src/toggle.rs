@@ -1,5 +1,8 @@
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, AccessibilityTraits, Accessible,
+};
 use waterui_core::configurable;
-use waterui_reactive::Binding;
+use waterui_reactive::{Binding, Compute, ComputeExt};
 
 use waterui_core::{AnyView, View};
 
@@ -12,6 +15,24 @@ pub struct ToggleConfig {
 
 configurable!(Toggle, ToggleConfig);
 
+impl Accessible for ToggleConfig {
+    fn accessibility_element(self) -> AccessibilityElement {
+        let traits = if self.toggle.compute() {
+            AccessibilityTraits::SELECTED
+        } else {
+            AccessibilityTraits::NONE
+        };
+        let value = self.toggle.map(|on| if on { "on" } else { "off" });
+        AccessibilityElement::new(
+            Accessibility::new()
+                .role(AccessibilityRole::Toggle)
+                .value(value)
+                .traits(traits),
+        )
+        .content(self.label)
+    }
+}
+
 impl Toggle {
     pub fn new(label: impl View, toggle: &Binding<bool>) -> Self {
         Self(ToggleConfig {
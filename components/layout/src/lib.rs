@@ -1,5 +1,7 @@
 extern crate alloc;
 
+use waterui_core::animation::Animatable;
+
 pub mod stack;
 
 pub mod grid;
@@ -131,4 +133,44 @@ impl Frame {
     modify_field!(alignment, Alignment);
 }
 
+/// Interpolates a length, jumping halfway through when either side is unset (NaN).
+fn interpolate_length(from: f64, to: f64, progress: f64) -> f64 {
+    if from.is_nan() || to.is_nan() {
+        if progress < 0.5 { from } else { to }
+    } else {
+        from.interpolate(&to, progress)
+    }
+}
+
+impl Animatable for Edge {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Self {
+            top: interpolate_length(self.top, to.top, progress),
+            right: interpolate_length(self.right, to.right, progress),
+            bottom: interpolate_length(self.bottom, to.bottom, progress),
+            left: interpolate_length(self.left, to.left, progress),
+        }
+    }
+}
+
+impl Animatable for Frame {
+    /// Interpolates every length, while the alignment changes halfway through.
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Self {
+            width: interpolate_length(self.width, to.width, progress),
+            min_width: interpolate_length(self.min_width, to.min_width, progress),
+            max_width: interpolate_length(self.max_width, to.max_width, progress),
+            height: interpolate_length(self.height, to.height, progress),
+            min_height: interpolate_length(self.min_height, to.min_height, progress),
+            max_height: interpolate_length(self.max_height, to.max_height, progress),
+            margin: self.margin.interpolate(&to.margin, progress),
+            alignment: if progress < 0.5 {
+                self.alignment.clone()
+            } else {
+                to.alignment.clone()
+            },
+        }
+    }
+}
+
 uniffi::setup_scaffolding!();
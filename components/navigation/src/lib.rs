@@ -10,6 +10,7 @@ pub mod tab;
 use alloc::{boxed::Box, vec::Vec};
 use waterui_core::{
     AnyView, Color, View,
+    color::SemanticColor,
     handler::{BoxHandler, HandlerFn, into_handler},
     impl_debug, raw_view,
 };
@@ -33,11 +34,11 @@ pub struct NavigationView {
 ///
 /// Represents the appearance and behavior of a navigation bar, including
 /// its title, color, and visibility.
-#[derive(Debug, Default, uniffi::Record)]
+#[derive(Debug, uniffi::Record)]
 pub struct Bar {
     /// The title text displayed in the navigation bar
     pub title: Text,
-    /// The background color of the navigation bar
+    /// The background color of the navigation bar, the theme's surface color by default
     pub color: Computed<Color>,
     /// Whether the navigation bar is hidden
     pub hidden: Computed<bool>,
@@ -45,6 +46,16 @@ pub struct Bar {
 
 uniffi::use_remote_type!(waterui_core::Computed<Color>);
 
+impl Default for Bar {
+    fn default() -> Self {
+        Self {
+            title: Text::default(),
+            color: Computed::constant(Color::semantic(SemanticColor::Surface)),
+            hidden: Computed::default(),
+        }
+    }
+}
+
 /// A path of navigation views forming a navigation hierarchy.
 ///
 /// Used to track the navigation stack in a hierarchical UI.
@@ -1,26 +1,375 @@
-use alloc::vec::Vec;
-use waterui_core::{Color, Str};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Range};
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, Accessible,
+};
+use waterui_core::{Color, Str, configurable};
+use waterui_reactive::{
+    Compute, ComputeExt, Computed, compute::IntoComputed, ffi_computed, impl_constant,
+};
 
 use crate::font::Font;
 
-#[derive(Debug, uniffi::Enum)]
+#[derive(Debug, Clone, PartialEq, uniffi::Enum)]
 pub enum Attribute {
     Bold,
     Italic,
     Underline,
     Strikethrough,
+    /// Inline code, shown in a monospaced font.
+    Code,
+    /// A link to the URL.
+    Link(Str),
     Color(Color),
     BackgroundColor(Color),
     Font(Font),
 }
 
-#[derive(Debug, uniffi::Record)]
+/// A string whose spans carry attributes, such as bold or links.
+///
+/// Spans are pushed one after the other, and attributes can be added to byte ranges
+/// afterwards:
+///
+/// ```
+/// # use waterui_text::attributed::{Attribute, AttributedStr};
+/// let mut string = AttributedStr::new();
+/// string.push_str("Read the ");
+/// string.push_link("guide", "https://waterui.dev/guide");
+/// string.push(" now", [Attribute::Bold]);
+/// string.add_attribute(0..4, Attribute::Italic);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, uniffi::Record)]
 pub struct AttributedStr {
-    string: Vec<AttributedStrChunk>,
+    chunks: Vec<AttributedStrChunk>,
 }
 
-#[derive(Debug, uniffi::Record)]
-struct AttributedStrChunk {
-    text: Str,
-    attributes: Vec<Attribute>,
+/// A span of an [`AttributedStr`], with the attributes of all its text.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct AttributedStrChunk {
+    pub text: Str,
+    pub attributes: Vec<Attribute>,
+}
+
+impl_constant!(AttributedStr);
+ffi_computed!(AttributedStr);
+
+impl AttributedStr {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses inline markdown, such as `**bold**` or `[links](url)`.
+    ///
+    /// See [`markdown`](crate::markdown) for the syntax supported.
+    pub fn from_markdown(source: &str) -> Self {
+        crate::markdown::parse_inline(source)
+    }
+
+    /// Appends `text` with `attributes`.
+    ///
+    /// The text joins the last span if it has the same attributes.
+    pub fn push(&mut self, text: impl Into<Str>, attributes: impl IntoIterator<Item = Attribute>) {
+        let text = text.into();
+        if text.is_empty() {
+            return;
+        }
+        let mut unique = Vec::new();
+        for attribute in attributes {
+            if !unique.contains(&attribute) {
+                unique.push(attribute);
+            }
+        }
+
+        match self.chunks.last_mut() {
+            Some(last) if same_attributes(&last.attributes, &unique) => {
+                let mut joined = String::from(&*last.text);
+                joined.push_str(&text);
+                last.text = joined.into();
+            }
+            _ => self.chunks.push(AttributedStrChunk {
+                text,
+                attributes: unique,
+            }),
+        }
+    }
+
+    /// Appends `text` without attributes.
+    pub fn push_str(&mut self, text: impl Into<Str>) {
+        self.push(text, []);
+    }
+
+    /// Appends `text` linking to `url`.
+    pub fn push_link(&mut self, text: impl Into<Str>, url: impl Into<Str>) {
+        self.push(text, [Attribute::Link(url.into())]);
+    }
+
+    /// Appends the spans of `other`.
+    pub fn append(&mut self, other: AttributedStr) {
+        for chunk in other.chunks {
+            self.push(chunk.text, chunk.attributes);
+        }
+    }
+
+    /// Adds `attribute` to the text in the byte `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or doesn't fall on character boundaries.
+    pub fn add_attribute(&mut self, range: Range<usize>, attribute: Attribute) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "Range {range:?} is out of bounds for an attributed string of {} bytes",
+            self.len()
+        );
+
+        let mut chunks = Vec::with_capacity(self.chunks.len() + 2);
+        let mut offset = 0;
+        for chunk in core::mem::take(&mut self.chunks) {
+            let end = offset + chunk.text.len();
+            let start_in = range.start.clamp(offset, end) - offset;
+            let end_in = range.end.clamp(offset, end) - offset;
+            offset = end;
+            if start_in == end_in {
+                chunks.push(chunk);
+                continue;
+            }
+
+            let text = &*chunk.text;
+            let mut attributes = chunk.attributes.clone();
+            if !attributes.contains(&attribute) {
+                attributes.push(attribute.clone());
+            }
+            for (range, attributes) in [
+                (0..start_in, &chunk.attributes),
+                (start_in..end_in, &attributes),
+                (end_in..text.len(), &chunk.attributes),
+            ] {
+                if !range.is_empty() {
+                    chunks.push(AttributedStrChunk {
+                        text: String::from(&text[range]).into(),
+                        attributes: attributes.clone(),
+                    });
+                }
+            }
+        }
+
+        // Rebuild through `push` to join the spans that now share their attributes.
+        for chunk in chunks {
+            self.push(chunk.text, chunk.attributes);
+        }
+    }
+
+    /// Returns the spans of the string.
+    pub fn chunks(&self) -> &[AttributedStrChunk] {
+        &self.chunks
+    }
+
+    /// Returns the length of the text in bytes.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.text.len()).sum()
+    }
+
+    /// Returns whether the string has no text.
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Returns the text without attributes.
+    pub fn to_plain_string(&self) -> String {
+        self.chunks.iter().map(|chunk| &*chunk.text).collect()
+    }
+}
+
+fn same_attributes(a: &[Attribute], b: &[Attribute]) -> bool {
+    a.len() == b.len() && a.iter().all(|attribute| b.contains(attribute))
+}
+
+impl fmt::Display for AttributedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in &self.chunks {
+            f.write_str(&chunk.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Str> for AttributedStr {
+    fn from(value: Str) -> Self {
+        let mut string = Self::new();
+        string.push_str(value);
+        string
+    }
+}
+
+impl From<&'static str> for AttributedStr {
+    fn from(value: &'static str) -> Self {
+        Str::from(value).into()
+    }
+}
+
+impl From<String> for AttributedStr {
+    fn from(value: String) -> Self {
+        Str::from(value).into()
+    }
+}
+
+impl FromIterator<AttributedStrChunk> for AttributedStr {
+    fn from_iter<T: IntoIterator<Item = AttributedStrChunk>>(iter: T) -> Self {
+        let mut string = Self::new();
+        string.extend(iter);
+        string
+    }
+}
+
+impl Extend<AttributedStrChunk> for AttributedStr {
+    fn extend<T: IntoIterator<Item = AttributedStrChunk>>(&mut self, iter: T) {
+        for chunk in iter {
+            self.push(chunk.text, chunk.attributes);
+        }
+    }
+}
+
+configurable!(StyledText, StyledTextConfig);
+
+/// The configuration of a [`StyledText`], showing an [`AttributedStr`].
+#[derive(Debug, Clone, uniffi::Record)]
+#[non_exhaustive]
+pub struct StyledTextConfig {
+    pub content: Computed<AttributedStr>,
+    pub font: Computed<Font>,
+}
+
+impl StyledText {
+    pub fn new(content: impl IntoComputed<AttributedStr>) -> Self {
+        Self(StyledTextConfig {
+            content: content.into_computed(),
+            font: Computed::default(),
+        })
+    }
+
+    pub fn content(&self) -> Computed<AttributedStr> {
+        self.0.content.clone()
+    }
+
+    pub fn font(mut self, font: impl Compute<Output = Font>) -> Self {
+        self.0.font = font.computed();
+        self
+    }
+}
+
+impl Accessible for StyledTextConfig {
+    fn accessibility_element(self) -> AccessibilityElement {
+        let label = self
+            .content
+            .map(|content| Str::from(content.to_plain_string()));
+        AccessibilityElement::new(
+            Accessibility::new()
+                .label(label)
+                .role(AccessibilityRole::Text),
+        )
+    }
+}
+
+pub fn styled_text(content: impl IntoComputed<AttributedStr>) -> StyledText {
+    StyledText::new(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::{Attribute, AttributedStr};
+
+    fn spans(string: &AttributedStr) -> Vec<(&str, &[Attribute])> {
+        string
+            .chunks()
+            .iter()
+            .map(|chunk| (&*chunk.text, &*chunk.attributes))
+            .collect()
+    }
+
+    #[test]
+    fn spans_with_the_same_attributes_are_joined() {
+        let mut string = AttributedStr::new();
+        string.push_str("plain ");
+        string.push(
+            "bold",
+            [Attribute::Bold, Attribute::Italic, Attribute::Bold],
+        );
+        string.push(" still", [Attribute::Italic, Attribute::Bold]);
+        string.push("", [Attribute::Code]);
+        assert_eq!(
+            spans(&string),
+            [
+                ("plain ", &[][..]),
+                ("bold still", &[Attribute::Bold, Attribute::Italic][..]),
+            ]
+        );
+        assert_eq!(string.len(), 16);
+        assert_eq!(string.to_plain_string(), "plain bold still");
+    }
+
+    #[test]
+    fn links_and_appended_strings() {
+        let mut string = AttributedStr::from("Read the ");
+        string.push_link("guide", "https://waterui.dev");
+        let mut other = AttributedStr::new();
+        other.push_link(" now", "https://waterui.dev");
+        string.append(other);
+        assert_eq!(
+            spans(&string),
+            [
+                ("Read the ", &[][..]),
+                (
+                    "guide now",
+                    &[Attribute::Link("https://waterui.dev".into())][..]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn attributes_are_added_to_ranges() {
+        let mut string = AttributedStr::new();
+        string.push_str("one ");
+        string.push("two", [Attribute::Bold]);
+        string.add_attribute(2..5, Attribute::Italic);
+        assert_eq!(
+            spans(&string),
+            [
+                ("on", &[][..]),
+                ("e ", &[Attribute::Italic][..]),
+                ("t", &[Attribute::Bold, Attribute::Italic][..]),
+                ("wo", &[Attribute::Bold][..]),
+            ]
+        );
+
+        // Adding an attribute a span already has joins it with its neighbours.
+        string.add_attribute(0..7, Attribute::Italic);
+        string.add_attribute(4..7, Attribute::Bold);
+        assert_eq!(
+            spans(&string),
+            [
+                ("one ", &[Attribute::Italic][..]),
+                ("two", &[Attribute::Bold, Attribute::Italic][..]),
+            ]
+        );
+
+        string.add_attribute(3..3, Attribute::Code);
+        assert_eq!(spans(&string).len(), 2);
+    }
+
+    #[test]
+    #[should_panic = "out of bounds"]
+    fn ranges_past_the_end_panic() {
+        AttributedStr::from("short").add_attribute(2..6, Attribute::Bold);
+    }
+
+    #[test]
+    fn empty_strings() {
+        let string: AttributedStr = vec![].into_iter().collect();
+        assert!(string.is_empty());
+        assert_eq!(string.len(), 0);
+        assert_eq!(AttributedStr::from(""), AttributedStr::new());
+    }
 }
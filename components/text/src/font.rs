@@ -1,23 +1,515 @@
-use waterui_core::Color;
+//! Fonts, text styles scaling with the dynamic type size, and custom fonts.
+//!
+//! A [`Font`] names its families in order of preference, falling back to the system font.
+//! A font of a [`TextStyle`] gets the size of its style for the [`DynamicTypeSize`] of the
+//! environment when resolved, unless its size is set:
+//!
+//! ```
+//! # use waterui_text::font::{Font, FontWeight, TextStyle};
+//! let title = Font::style(TextStyle::Title).family("Inter").letter_spacing(-0.5);
+//! let price = Font::style(TextStyle::Body).weight(FontWeight::SEMIBOLD).monospaced_digits();
+//! ```
+//!
+//! Fonts bundled with the application are registered by family name with the
+//! [`FontRegistry`] plugin, which renderers load them from.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use waterui_core::{Color, Environment, Str, plugin::Plugin};
+use waterui_reactive::{ComputeExt, Computed, impl_constant};
 
 #[derive(Debug, Clone, PartialEq, uniffi::Record)]
 #[non_exhaustive]
 pub struct Font {
+    /// The family names, in order of preference. The system font is used if none of
+    /// them is available.
+    pub families: Vec<Str>,
+    /// The size in points, or `NAN` for the size of the text style.
     pub size: f64,
+    pub weight: FontWeight,
+    pub width: FontWidth,
     pub italic: bool,
     pub strikethrough: Option<Color>,
     pub underlined: Option<Color>,
-    pub bold: bool,
+    /// Additional space between letters, in points.
+    pub letter_spacing: f64,
+    /// The height of a line as a multiple of the size, or `NAN` for the natural height.
+    pub line_height: f64,
+    /// Whether digits all have the same width, so changing numbers stay aligned.
+    pub monospaced_digits: bool,
+    /// The semantic style of the text, scaling with the dynamic type size.
+    pub text_style: Option<TextStyle>,
 }
 
 impl Default for Font {
     fn default() -> Self {
         Self {
+            families: Vec::new(),
             size: f64::NAN,
+            weight: FontWeight::REGULAR,
+            width: FontWidth::NORMAL,
             italic: false,
-            bold: false,
             strikethrough: None,
             underlined: None,
+            letter_spacing: 0.0,
+            line_height: f64::NAN,
+            monospaced_digits: false,
+            text_style: None,
+        }
+    }
+}
+
+impl_constant!(Font);
+
+impl Font {
+    /// Creates a system font of `size` points.
+    pub fn system(size: f64) -> Self {
+        Self::default().size(size)
+    }
+
+    /// Creates a system font of the text style `style`, with the weight of the style.
+    pub fn style(style: TextStyle) -> Self {
+        Self {
+            weight: style.weight(),
+            text_style: Some(style),
+            ..Self::default()
+        }
+    }
+
+    /// Adds `family` to the families of the font, after the ones already added.
+    pub fn family(mut self, family: impl Into<Str>) -> Self {
+        self.families.push(family.into());
+        self
+    }
+
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.weight(FontWeight::BOLD)
+    }
+
+    pub fn width(mut self, width: FontWidth) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn letter_spacing(mut self, spacing: f64) -> Self {
+        self.letter_spacing = spacing;
+        self
+    }
+
+    pub fn line_height(mut self, multiple: f64) -> Self {
+        self.line_height = multiple;
+        self
+    }
+
+    pub fn monospaced_digits(mut self) -> Self {
+        self.monospaced_digits = true;
+        self
+    }
+
+    /// Returns whether the font is at least semibold.
+    pub fn is_bold(&self) -> bool {
+        self.weight >= FontWeight::SEMIBOLD
+    }
+
+    /// Returns this font with the size of its text style at `size`, if its size isn't set.
+    pub fn scaled(mut self, size: DynamicTypeSize) -> Self {
+        if let Some(style) = self.text_style
+            && self.size.is_nan()
+        {
+            self.size = style.size(size);
+        }
+        self
+    }
+
+    /// Resolves this font against the environment.
+    ///
+    /// A font of a text style without a size gets the size of its style for the
+    /// [`DynamicTypeSize`] of the environment, and updates when it changes. Any other
+    /// font is returned as is.
+    pub fn resolve(&self, env: &Environment) -> Computed<Font> {
+        if self.text_style.is_none() || !self.size.is_nan() {
+            return Computed::constant(self.clone());
+        }
+        let font = self.clone();
+        DynamicTypeSize::current(env)
+            .map(move |size| font.clone().scaled(size))
+            .computed()
+    }
+}
+
+/// Resolves a font against the environment for the renderer.
+#[uniffi::export]
+fn resolve_font(env: Environment, font: Font) -> Computed<Font> {
+    font.resolve(&env)
+}
+
+/// The weight of a font, from 1 to 1000, where 400 is regular and 700 is bold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, uniffi::Record)]
+pub struct FontWeight {
+    pub value: u16,
+}
+
+impl FontWeight {
+    pub const THIN: Self = Self::new(100);
+    pub const EXTRA_LIGHT: Self = Self::new(200);
+    pub const LIGHT: Self = Self::new(300);
+    pub const REGULAR: Self = Self::new(400);
+    pub const MEDIUM: Self = Self::new(500);
+    pub const SEMIBOLD: Self = Self::new(600);
+    pub const BOLD: Self = Self::new(700);
+    pub const EXTRA_BOLD: Self = Self::new(800);
+    pub const BLACK: Self = Self::new(900);
+
+    /// Creates a weight, clamped between 1 and 1000.
+    pub const fn new(value: u16) -> Self {
+        let value = if value < 1 {
+            1
+        } else if value > 1000 {
+            1000
+        } else {
+            value
+        };
+        Self { value }
+    }
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        Self::REGULAR
+    }
+}
+
+/// The width of a font, as a percentage of its normal width.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, uniffi::Record)]
+pub struct FontWidth {
+    pub percent: f64,
+}
+
+impl FontWidth {
+    pub const ULTRA_CONDENSED: Self = Self::new(50.0);
+    pub const CONDENSED: Self = Self::new(75.0);
+    pub const SEMI_CONDENSED: Self = Self::new(87.5);
+    pub const NORMAL: Self = Self::new(100.0);
+    pub const SEMI_EXPANDED: Self = Self::new(112.5);
+    pub const EXPANDED: Self = Self::new(125.0);
+    pub const ULTRA_EXPANDED: Self = Self::new(200.0);
+
+    pub const fn new(percent: f64) -> Self {
+        Self { percent }
+    }
+}
+
+impl Default for FontWidth {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// A semantic style of text, whose size follows the dynamic type size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, uniffi::Enum)]
+pub enum TextStyle {
+    LargeTitle,
+    Title,
+    Title2,
+    Title3,
+    Headline,
+    Body,
+    Callout,
+    Subheadline,
+    Footnote,
+    Caption,
+    Caption2,
+}
+
+impl TextStyle {
+    /// Returns the size of this style at the default dynamic type size, in points.
+    pub const fn base_size(self) -> f64 {
+        match self {
+            Self::LargeTitle => 34.0,
+            Self::Title => 28.0,
+            Self::Title2 => 22.0,
+            Self::Title3 => 20.0,
+            Self::Headline | Self::Body => 17.0,
+            Self::Callout => 16.0,
+            Self::Subheadline => 15.0,
+            Self::Footnote => 13.0,
+            Self::Caption => 12.0,
+            Self::Caption2 => 11.0,
         }
     }
+
+    /// Returns the weight of this style.
+    pub const fn weight(self) -> FontWeight {
+        match self {
+            Self::Headline => FontWeight::SEMIBOLD,
+            _ => FontWeight::REGULAR,
+        }
+    }
+
+    /// Returns the size of this style at the dynamic type size `size`, rounded to a point.
+    pub fn size(self, size: DynamicTypeSize) -> f64 {
+        (self.base_size() * size.scale()).round()
+    }
+}
+
+/// The preferred size of text, chosen by the user in the system settings.
+///
+/// The renderer usually stores a reactive `DynamicTypeSize` in the environment with
+/// `Environment::insert_computed`, following the system setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, uniffi::Enum)]
+pub enum DynamicTypeSize {
+    ExtraSmall,
+    Small,
+    Medium,
+    #[default]
+    Large,
+    ExtraLarge,
+    ExtraExtraLarge,
+    ExtraExtraExtraLarge,
+    Accessibility1,
+    Accessibility2,
+    Accessibility3,
+    Accessibility4,
+    Accessibility5,
+}
+
+impl_constant!(DynamicTypeSize);
+
+impl DynamicTypeSize {
+    /// Returns the dynamic type size of the environment, falling back to `Large`.
+    pub fn current(env: &Environment) -> Computed<Self> {
+        env.get_computed::<Self>()
+            .unwrap_or_else(|| Computed::constant(Self::default()))
+    }
+
+    /// Returns the factor text sizes are scaled by, relative to `Large`.
+    pub const fn scale(self) -> f64 {
+        match self {
+            Self::ExtraSmall => 0.82,
+            Self::Small => 0.88,
+            Self::Medium => 0.94,
+            Self::Large => 1.0,
+            Self::ExtraLarge => 1.12,
+            Self::ExtraExtraLarge => 1.24,
+            Self::ExtraExtraExtraLarge => 1.35,
+            Self::Accessibility1 => 1.65,
+            Self::Accessibility2 => 1.94,
+            Self::Accessibility3 => 2.35,
+            Self::Accessibility4 => 2.76,
+            Self::Accessibility5 => 3.12,
+        }
+    }
+
+    /// Returns whether this is one of the larger sizes meant for accessibility.
+    pub const fn is_accessibility_size(self) -> bool {
+        matches!(
+            self,
+            Self::Accessibility1
+                | Self::Accessibility2
+                | Self::Accessibility3
+                | Self::Accessibility4
+                | Self::Accessibility5
+        )
+    }
+}
+
+/// A font file of a family, bundled with the application.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct FontFace {
+    pub family: Str,
+    pub weight: FontWeight,
+    pub italic: bool,
+    /// The content of the font file, in a format supported by the platform, such as
+    /// TrueType or OpenType.
+    pub data: Vec<u8>,
+}
+
+impl FontFace {
+    /// Creates a regular face of `family` from the content of a font file.
+    pub fn new(family: impl Into<Str>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            family: family.into(),
+            weight: FontWeight::REGULAR,
+            italic: false,
+            data: data.into(),
+        }
+    }
+
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+}
+
+/// A plugin registering the font faces bundled with the application by family name.
+///
+/// ```no_run
+/// # use waterui_core::Environment;
+/// # use waterui_text::font::{FontFace, FontRegistry, FontWeight};
+/// let env = Environment::new().install(
+///     FontRegistry::new()
+///         .register(FontFace::new("Inter", std::fs::read("Inter-Regular.ttf").unwrap()))
+///         .register(FontFace::new("Inter", std::fs::read("Inter-Bold.ttf").unwrap()).weight(FontWeight::BOLD)),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FontRegistry {
+    families: BTreeMap<Str, Vec<FontFace>>,
+}
+
+impl FontRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `face` under its family name.
+    pub fn register(mut self, face: FontFace) -> Self {
+        self.insert(face);
+        self
+    }
+
+    /// Registers `face` under its family name.
+    pub fn insert(&mut self, face: FontFace) {
+        self.families
+            .entry(face.family.clone())
+            .or_default()
+            .push(face);
+    }
+
+    /// Returns the registered family names.
+    pub fn families(&self) -> impl Iterator<Item = &Str> {
+        self.families.keys()
+    }
+
+    /// Returns the registered faces of `family`.
+    pub fn faces(&self, family: &str) -> &[FontFace] {
+        self.families.get(family).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the registered face best matching `font`.
+    ///
+    /// The first family of the font with registered faces is used, choosing the face with
+    /// the same style and the closest weight.
+    pub fn matching(&self, font: &Font) -> Option<&FontFace> {
+        let faces = font
+            .families
+            .iter()
+            .map(|family| self.faces(family))
+            .find(|faces| !faces.is_empty())?;
+        faces.iter().min_by_key(|face| {
+            (
+                face.italic != font.italic,
+                face.weight.value.abs_diff(font.weight.value),
+            )
+        })
+    }
+}
+
+impl Plugin for FontRegistry {}
+
+/// Returns the font faces registered in the environment, for the renderer to load.
+#[uniffi::export]
+fn registered_fonts(env: Environment) -> Vec<FontFace> {
+    env.get::<FontRegistry>()
+        .map(|registry| registry.families.values().flatten().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamicTypeSize, Font, FontFace, FontRegistry, FontWeight, FontWidth, TextStyle};
+
+    #[test]
+    fn text_styles_scale_with_the_dynamic_type_size() {
+        assert_eq!(TextStyle::Body.size(DynamicTypeSize::Large), 17.0);
+        assert_eq!(TextStyle::Body.size(DynamicTypeSize::ExtraSmall), 14.0);
+        assert_eq!(TextStyle::Body.size(DynamicTypeSize::Accessibility5), 53.0);
+        assert_eq!(TextStyle::Caption2.size(DynamicTypeSize::Small), 10.0);
+        assert_eq!(
+            TextStyle::LargeTitle.size(DynamicTypeSize::ExtraLarge),
+            38.0
+        );
+        assert!(DynamicTypeSize::Accessibility1.is_accessibility_size());
+        assert!(!DynamicTypeSize::ExtraExtraExtraLarge.is_accessibility_size());
+    }
+
+    #[test]
+    fn fonts_of_a_style_are_sized_unless_their_size_is_set() {
+        let headline = Font::style(TextStyle::Headline);
+        assert_eq!(headline.weight, FontWeight::SEMIBOLD);
+        assert!(headline.size.is_nan());
+        assert_eq!(headline.clone().scaled(DynamicTypeSize::Medium).size, 16.0);
+        assert_eq!(
+            headline.size(20.0).scaled(DynamicTypeSize::Medium).size,
+            20.0
+        );
+        assert!(
+            Font::system(12.0)
+                .scaled(DynamicTypeSize::Accessibility5)
+                .size
+                == 12.0
+        );
+        assert!(Font::default().scaled(DynamicTypeSize::Large).size.is_nan());
+    }
+
+    #[test]
+    fn weights_are_clamped_and_ordered() {
+        assert_eq!(FontWeight::new(0).value, 1);
+        assert_eq!(FontWeight::new(1500).value, 1000);
+        assert_eq!(FontWeight::new(550).value, 550);
+        assert!(FontWeight::LIGHT < FontWeight::REGULAR && FontWeight::BOLD < FontWeight::BLACK);
+        assert_eq!(FontWeight::default(), FontWeight::REGULAR);
+
+        assert!(Font::default().weight(FontWeight::SEMIBOLD).is_bold());
+        assert!(!Font::default().weight(FontWeight::new(599)).is_bold());
+        assert_eq!(Font::default().bold().weight, FontWeight::BOLD);
+    }
+
+    #[test]
+    fn widths_are_percentages() {
+        assert_eq!(FontWidth::default(), FontWidth::NORMAL);
+        assert_eq!(FontWidth::CONDENSED.percent, 75.0);
+        assert!(FontWidth::SEMI_CONDENSED < FontWidth::NORMAL);
+        assert!(FontWidth::EXPANDED < FontWidth::ULTRA_EXPANDED);
+        assert_eq!(
+            Font::default().width(FontWidth::new(90.0)).width.percent,
+            90.0
+        );
+    }
+
+    #[test]
+    fn registry_matches_the_closest_face() {
+        let registry = FontRegistry::new()
+            .register(FontFace::new("Inter", [0]))
+            .register(FontFace::new("Inter", [1]).weight(FontWeight::BOLD))
+            .register(FontFace::new("Inter", [2]).italic());
+        let face = |font: Font| registry.matching(&font).map(|face| face.data[0]);
+
+        let inter = Font::default().family("Missing").family("Inter");
+        assert_eq!(face(inter.clone()), Some(0));
+        assert_eq!(face(inter.clone().weight(FontWeight::SEMIBOLD)), Some(1));
+        assert_eq!(face(inter.clone().bold().italic()), Some(2));
+        assert_eq!(face(Font::default().family("Missing")), None);
+        assert_eq!(registry.faces("Inter").len(), 3);
+        assert_eq!(registry.families().count(), 1);
+    }
 }
@@ -4,6 +4,9 @@ pub mod attributed;
 pub mod link;
 pub mod locale;
 mod macros;
+#[doc(hidden)]
+pub use macros::__private;
+pub mod markdown;
 extern crate alloc;
 
 use alloc::string::ToString;
@@ -16,6 +19,9 @@ use waterui_reactive::{Compute, Computed, compute::IntoComputed};
 use waterui_reactive::{ComputeExt, ffi_computed};
 
 use waterui_core::Str;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, Accessible,
+};
 
 configurable!(Text, TextConfig);
 
@@ -95,6 +101,16 @@ impl Text {
     }
 }
 
+impl Accessible for TextConfig {
+    fn accessibility_element(self) -> AccessibilityElement {
+        AccessibilityElement::new(
+            Accessibility::new()
+                .label(self.content)
+                .role(AccessibilityRole::Text),
+        )
+    }
+}
+
 pub fn text(text: impl IntoComputed<Str>) -> Text {
     Text::new(text)
 }
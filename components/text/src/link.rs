@@ -1,4 +1,7 @@
 use waterui_core::Str;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, Accessible,
+};
 use waterui_core::{AnyView, configurable};
 use waterui_reactive::Computed;
 
@@ -10,5 +13,12 @@ pub struct LinkConfig {
 
 configurable!(Link, LinkConfig);
 
+impl Accessible for LinkConfig {
+    fn accessibility_element(self) -> AccessibilityElement {
+        AccessibilityElement::new(Accessibility::new().role(AccessibilityRole::Link))
+            .content(self.label)
+    }
+}
+
 #[repr(C)]
 pub struct WuiLink {}
@@ -0,0 +1,485 @@
+//! Locale data, taken from the CLDR.
+//!
+//! English is always bundled, as the fallback of every locale. The other locales are
+//! bundled with their `locale-*` feature, all of them by default.
+
+/// A unit pattern for each plural category used, as `(one, other)`.
+pub(crate) type Plural = (&'static str, &'static str);
+
+/// The data formatters need for a locale.
+///
+/// Patterns put their value in place of `{0}`, and date-time patterns put the date in
+/// place of `{1}`.
+pub(crate) struct LocaleData {
+    pub id: &'static str,
+    pub decimal: &'static str,
+    pub group: &'static str,
+    /// The number of integer digits needed before the first group separator is shown.
+    pub min_grouping: usize,
+    pub percent: &'static str,
+    /// The currency pattern, where `¤` is the symbol of the currency.
+    pub currency: &'static str,
+    pub currency_symbols: &'static [(&'static str, &'static str)],
+    /// Bytes, kilobytes, megabytes, gigabytes, terabytes and petabytes.
+    pub bytes: [&'static str; 6],
+    pub months: [&'static str; 12],
+    pub months_abbreviated: [&'static str; 12],
+    /// Weekdays, from Monday.
+    pub weekdays: [&'static str; 7],
+    /// Before and after noon.
+    pub day_periods: [&'static str; 2],
+    /// Short, medium, long and full date patterns.
+    pub date: [&'static str; 4],
+    /// Short and medium time patterns.
+    pub time: [&'static str; 2],
+    pub date_time: &'static str,
+    pub now: &'static str,
+    pub future: &'static str,
+    pub past: &'static str,
+    /// Seconds, minutes, hours, days, weeks, months and years, in relative times.
+    pub relative_units: [Plural; 7],
+    /// Seconds, minutes, hours and days, in durations.
+    pub duration_units: [Plural; 4],
+    pub duration_units_short: [Plural; 4],
+    pub list_separator: &'static str,
+}
+
+/// The number of fraction digits of a currency, two unless listed.
+pub(crate) const CURRENCY_DIGITS: [(&str, usize); 6] = [
+    ("CLP", 0),
+    ("ISK", 0),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("VND", 0),
+];
+
+pub(crate) const EN: LocaleData = LocaleData {
+    id: "en",
+    decimal: ".",
+    group: ",",
+    min_grouping: 1,
+    percent: "{0}%",
+    currency: "¤{0}",
+    currency_symbols: &[
+        ("CNY", "CN¥"),
+        ("EUR", "€"),
+        ("GBP", "£"),
+        ("JPY", "¥"),
+        ("USD", "$"),
+    ],
+    bytes: ["{0} byte", "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} PB"],
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_abbreviated: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    day_periods: ["AM", "PM"],
+    date: ["M/d/yy", "MMM d, y", "MMMM d, y", "EEEE, MMMM d, y"],
+    time: ["h:mm a", "h:mm:ss a"],
+    date_time: "{1}, {0}",
+    now: "now",
+    future: "in {0}",
+    past: "{0} ago",
+    relative_units: [
+        ("{0} second", "{0} seconds"),
+        ("{0} minute", "{0} minutes"),
+        ("{0} hour", "{0} hours"),
+        ("{0} day", "{0} days"),
+        ("{0} week", "{0} weeks"),
+        ("{0} month", "{0} months"),
+        ("{0} year", "{0} years"),
+    ],
+    duration_units: [
+        ("{0} second", "{0} seconds"),
+        ("{0} minute", "{0} minutes"),
+        ("{0} hour", "{0} hours"),
+        ("{0} day", "{0} days"),
+    ],
+    duration_units_short: [
+        ("{0} sec", "{0} sec"),
+        ("{0} min", "{0} min"),
+        ("{0} hr", "{0} hr"),
+        ("{0} day", "{0} days"),
+    ],
+    list_separator: ", ",
+};
+
+#[cfg(feature = "locale-de")]
+pub(crate) const DE: LocaleData = LocaleData {
+    id: "de",
+    decimal: ",",
+    group: ".",
+    min_grouping: 1,
+    percent: "{0}\u{a0}%",
+    currency: "{0}\u{a0}¤",
+    currency_symbols: &[
+        ("CNY", "CN¥"),
+        ("EUR", "€"),
+        ("GBP", "£"),
+        ("JPY", "¥"),
+        ("USD", "$"),
+    ],
+    bytes: ["{0} Byte", "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} PB"],
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    months_abbreviated: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    weekdays: [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    day_periods: ["AM", "PM"],
+    date: ["dd.MM.yy", "dd.MM.y", "d. MMMM y", "EEEE, d. MMMM y"],
+    time: ["HH:mm", "HH:mm:ss"],
+    date_time: "{1}, {0}",
+    now: "jetzt",
+    future: "in {0}",
+    past: "vor {0}",
+    relative_units: [
+        ("{0} Sekunde", "{0} Sekunden"),
+        ("{0} Minute", "{0} Minuten"),
+        ("{0} Stunde", "{0} Stunden"),
+        ("{0} Tag", "{0} Tagen"),
+        ("{0} Woche", "{0} Wochen"),
+        ("{0} Monat", "{0} Monaten"),
+        ("{0} Jahr", "{0} Jahren"),
+    ],
+    duration_units: [
+        ("{0} Sekunde", "{0} Sekunden"),
+        ("{0} Minute", "{0} Minuten"),
+        ("{0} Stunde", "{0} Stunden"),
+        ("{0} Tag", "{0} Tage"),
+    ],
+    duration_units_short: [
+        ("{0} Sek.", "{0} Sek."),
+        ("{0} Min.", "{0} Min."),
+        ("{0} Std.", "{0} Std."),
+        ("{0} Tg.", "{0} Tg."),
+    ],
+    list_separator: ", ",
+};
+
+#[cfg(feature = "locale-es")]
+pub(crate) const ES: LocaleData = LocaleData {
+    id: "es",
+    decimal: ",",
+    group: ".",
+    min_grouping: 2,
+    percent: "{0}\u{a0}%",
+    currency: "{0}\u{a0}¤",
+    currency_symbols: &[("EUR", "€"), ("USD", "US$")],
+    bytes: ["{0} B", "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} PB"],
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    months_abbreviated: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    weekdays: [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+    day_periods: ["a.\u{a0}m.", "p.\u{a0}m."],
+    date: [
+        "d/M/yy",
+        "d MMM y",
+        "d 'de' MMMM 'de' y",
+        "EEEE, d 'de' MMMM 'de' y",
+    ],
+    time: ["H:mm", "H:mm:ss"],
+    date_time: "{1}, {0}",
+    now: "ahora",
+    future: "dentro de {0}",
+    past: "hace {0}",
+    relative_units: [
+        ("{0} segundo", "{0} segundos"),
+        ("{0} minuto", "{0} minutos"),
+        ("{0} hora", "{0} horas"),
+        ("{0} día", "{0} días"),
+        ("{0} semana", "{0} semanas"),
+        ("{0} mes", "{0} meses"),
+        ("{0} año", "{0} años"),
+    ],
+    duration_units: [
+        ("{0} segundo", "{0} segundos"),
+        ("{0} minuto", "{0} minutos"),
+        ("{0} hora", "{0} horas"),
+        ("{0} día", "{0} días"),
+    ],
+    duration_units_short: [
+        ("{0} s", "{0} s"),
+        ("{0} min", "{0} min"),
+        ("{0} h", "{0} h"),
+        ("{0} d", "{0} d"),
+    ],
+    list_separator: ", ",
+};
+
+#[cfg(feature = "locale-fr")]
+pub(crate) const FR: LocaleData = LocaleData {
+    id: "fr",
+    decimal: ",",
+    group: "\u{202f}",
+    min_grouping: 1,
+    percent: "{0}\u{202f}%",
+    currency: "{0}\u{a0}¤",
+    currency_symbols: &[("EUR", "€"), ("GBP", "£GB"), ("USD", "$US")],
+    bytes: ["{0} o", "{0} ko", "{0} Mo", "{0} Go", "{0} To", "{0} Po"],
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    months_abbreviated: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    weekdays: [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    day_periods: ["AM", "PM"],
+    date: ["dd/MM/y", "d MMM y", "d MMMM y", "EEEE d MMMM y"],
+    time: ["HH:mm", "HH:mm:ss"],
+    date_time: "{1} {0}",
+    now: "maintenant",
+    future: "dans {0}",
+    past: "il y a {0}",
+    relative_units: [
+        ("{0} seconde", "{0} secondes"),
+        ("{0} minute", "{0} minutes"),
+        ("{0} heure", "{0} heures"),
+        ("{0} jour", "{0} jours"),
+        ("{0} semaine", "{0} semaines"),
+        ("{0} mois", "{0} mois"),
+        ("{0} an", "{0} ans"),
+    ],
+    duration_units: [
+        ("{0} seconde", "{0} secondes"),
+        ("{0} minute", "{0} minutes"),
+        ("{0} heure", "{0} heures"),
+        ("{0} jour", "{0} jours"),
+    ],
+    duration_units_short: [
+        ("{0} s", "{0} s"),
+        ("{0} min", "{0} min"),
+        ("{0} h", "{0} h"),
+        ("{0} j", "{0} j"),
+    ],
+    list_separator: " ",
+};
+
+#[cfg(feature = "locale-ja")]
+pub(crate) const JA: LocaleData = LocaleData {
+    id: "ja",
+    decimal: ".",
+    group: ",",
+    min_grouping: 1,
+    percent: "{0}%",
+    currency: "¤{0}",
+    currency_symbols: &[
+        ("CNY", "元"),
+        ("EUR", "€"),
+        ("GBP", "£"),
+        ("JPY", "￥"),
+        ("USD", "$"),
+    ],
+    bytes: ["{0} byte", "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} PB"],
+    months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    months_abbreviated: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ],
+    day_periods: ["午前", "午後"],
+    date: ["y/MM/dd", "y/MM/dd", "y年M月d日", "y年M月d日EEEE"],
+    time: ["H:mm", "H:mm:ss"],
+    date_time: "{1} {0}",
+    now: "今",
+    future: "{0}後",
+    past: "{0}前",
+    relative_units: [
+        ("{0} 秒", "{0} 秒"),
+        ("{0} 分", "{0} 分"),
+        ("{0} 時間", "{0} 時間"),
+        ("{0} 日", "{0} 日"),
+        ("{0} 週間", "{0} 週間"),
+        ("{0} か月", "{0} か月"),
+        ("{0} 年", "{0} 年"),
+    ],
+    duration_units: [
+        ("{0} 秒", "{0} 秒"),
+        ("{0} 分", "{0} 分"),
+        ("{0} 時間", "{0} 時間"),
+        ("{0} 日", "{0} 日"),
+    ],
+    duration_units_short: [
+        ("{0} 秒", "{0} 秒"),
+        ("{0} 分", "{0} 分"),
+        ("{0} 時間", "{0} 時間"),
+        ("{0} 日", "{0} 日"),
+    ],
+    list_separator: " ",
+};
+
+#[cfg(feature = "locale-zh")]
+pub(crate) const ZH: LocaleData = LocaleData {
+    id: "zh",
+    decimal: ".",
+    group: ",",
+    min_grouping: 1,
+    percent: "{0}%",
+    currency: "¤{0}",
+    currency_symbols: &[
+        ("CNY", "¥"),
+        ("EUR", "€"),
+        ("GBP", "£"),
+        ("JPY", "JP¥"),
+        ("USD", "US$"),
+    ],
+    bytes: ["{0} byte", "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} PB"],
+    months: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    months_abbreviated: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ],
+    day_periods: ["上午", "下午"],
+    date: ["y/M/d", "y年M月d日", "y年M月d日", "y年M月d日EEEE"],
+    time: ["HH:mm", "HH:mm:ss"],
+    date_time: "{1} {0}",
+    now: "现在",
+    future: "{0}后",
+    past: "{0}前",
+    relative_units: [
+        ("{0}秒钟", "{0}秒钟"),
+        ("{0}分钟", "{0}分钟"),
+        ("{0}小时", "{0}小时"),
+        ("{0}天", "{0}天"),
+        ("{0}周", "{0}周"),
+        ("{0}个月", "{0}个月"),
+        ("{0}年", "{0}年"),
+    ],
+    duration_units: [
+        ("{0}秒钟", "{0}秒钟"),
+        ("{0}分钟", "{0}分钟"),
+        ("{0}小时", "{0}小时"),
+        ("{0}天", "{0}天"),
+    ],
+    duration_units_short: [
+        ("{0}秒", "{0}秒"),
+        ("{0}分钟", "{0}分钟"),
+        ("{0}小时", "{0}小时"),
+        ("{0}天", "{0}天"),
+    ],
+    list_separator: "",
+};
+
+/// The bundled locales.
+pub(crate) const LOCALES: &[&LocaleData] = &[
+    &EN,
+    #[cfg(feature = "locale-de")]
+    &DE,
+    #[cfg(feature = "locale-es")]
+    &ES,
+    #[cfg(feature = "locale-fr")]
+    &FR,
+    #[cfg(feature = "locale-ja")]
+    &JA,
+    #[cfg(feature = "locale-zh")]
+    &ZH,
+];
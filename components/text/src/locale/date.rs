@@ -0,0 +1,305 @@
+use alloc::{format, string::String};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+use waterui_core::Str;
+
+use super::{Formatter, Locale, data::LocaleData};
+
+/// The length of a formatted date.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateStyle {
+    /// Numeric, such as `1/2/25`.
+    Short,
+    /// Abbreviated, such as `Jan 2, 2025`.
+    #[default]
+    Medium,
+    /// With the month name, such as `January 2, 2025`.
+    Long,
+    /// With the weekday, such as `Thursday, January 2, 2025`.
+    Full,
+}
+
+/// The length of a formatted time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeStyle {
+    /// Hours and minutes, such as `3:04 PM`.
+    #[default]
+    Short,
+    /// With the seconds, such as `3:04:05 PM`.
+    Medium,
+}
+
+/// Formats dates in the style of a locale.
+#[derive(Debug, Clone)]
+pub struct DateFormatter {
+    locale: Locale,
+    style: DateStyle,
+}
+
+impl DateFormatter {
+    /// Creates a formatter for medium dates.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            locale: locale.clone(),
+            style: DateStyle::Medium,
+        }
+    }
+
+    pub fn style(mut self, style: DateStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn get_locale(&self) -> &Locale {
+        &self.locale
+    }
+}
+
+impl Formatter<Date> for DateFormatter {
+    fn format(&self, value: &Date) -> Str {
+        let data = self.locale.data();
+        format_pattern(data, data.date[self.style as usize], Some(*value), None).into()
+    }
+}
+
+impl Formatter<PrimitiveDateTime> for DateFormatter {
+    fn format(&self, value: &PrimitiveDateTime) -> Str {
+        self.format(&value.date())
+    }
+}
+
+impl Formatter<OffsetDateTime> for DateFormatter {
+    fn format(&self, value: &OffsetDateTime) -> Str {
+        self.format(&value.date())
+    }
+}
+
+/// Formats times of day in the style of a locale, with a 12 or 24-hour clock.
+#[derive(Debug, Clone)]
+pub struct TimeFormatter {
+    locale: Locale,
+    style: TimeStyle,
+}
+
+impl TimeFormatter {
+    /// Creates a formatter for short times.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            locale: locale.clone(),
+            style: TimeStyle::Short,
+        }
+    }
+
+    pub fn style(mut self, style: TimeStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Formatter<Time> for TimeFormatter {
+    fn format(&self, value: &Time) -> Str {
+        let data = self.locale.data();
+        format_pattern(data, data.time[self.style as usize], None, Some(*value)).into()
+    }
+}
+
+impl Formatter<PrimitiveDateTime> for TimeFormatter {
+    fn format(&self, value: &PrimitiveDateTime) -> Str {
+        self.format(&value.time())
+    }
+}
+
+impl Formatter<OffsetDateTime> for TimeFormatter {
+    fn format(&self, value: &OffsetDateTime) -> Str {
+        self.format(&value.time())
+    }
+}
+
+/// Formats dates with their time in the style of a locale.
+#[derive(Debug, Clone)]
+pub struct DateTimeFormatter {
+    date: DateFormatter,
+    time: TimeFormatter,
+}
+
+impl DateTimeFormatter {
+    /// Creates a formatter for medium dates with short times.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            date: DateFormatter::new(locale),
+            time: TimeFormatter::new(locale),
+        }
+    }
+
+    pub fn date_style(mut self, style: DateStyle) -> Self {
+        self.date = self.date.style(style);
+        self
+    }
+
+    pub fn time_style(mut self, style: TimeStyle) -> Self {
+        self.time = self.time.style(style);
+        self
+    }
+}
+
+impl Formatter<PrimitiveDateTime> for DateTimeFormatter {
+    fn format(&self, value: &PrimitiveDateTime) -> Str {
+        let data = self.date.locale.data();
+        data.date_time
+            .replacen("{1}", &self.date.format(value), 1)
+            .replacen("{0}", &self.time.format(value), 1)
+            .into()
+    }
+}
+
+impl Formatter<OffsetDateTime> for DateTimeFormatter {
+    fn format(&self, value: &OffsetDateTime) -> Str {
+        self.format(&PrimitiveDateTime::new(value.date(), value.time()))
+    }
+}
+
+/// Formats a date and a time with a CLDR date pattern, such as `MMM d, y`.
+fn format_pattern(
+    data: &LocaleData,
+    pattern: &str,
+    date: Option<Date>,
+    time: Option<Time>,
+) -> String {
+    let mut result = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // Quoted literal text, where `''` is a quote.
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                result.push('\'');
+                continue;
+            }
+            for c in chars.by_ref() {
+                if c == '\'' {
+                    break;
+                }
+                result.push(c);
+            }
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            result.push(c);
+            continue;
+        }
+
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        let field = match (c, date, time) {
+            ('y', Some(date), _) if count == 2 => format!("{:02}", date.year().rem_euclid(100)),
+            ('y', Some(date), _) => format!("{}", date.year()),
+            ('M', Some(date), _) => {
+                let month = usize::from(u8::from(date.month()));
+                match count {
+                    1 => format!("{month}"),
+                    2 => format!("{month:02}"),
+                    3 => data.months_abbreviated[month - 1].into(),
+                    _ => data.months[month - 1].into(),
+                }
+            }
+            ('d', Some(date), _) => format!("{:0count$}", date.day()),
+            ('E', Some(date), _) => {
+                data.weekdays[usize::from(date.weekday().number_days_from_monday())].into()
+            }
+            ('H', _, Some(time)) => format!("{:0count$}", time.hour()),
+            ('h', _, Some(time)) => format!("{:0count$}", (time.hour() + 11) % 12 + 1),
+            ('m', _, Some(time)) => format!("{:0count$}", time.minute()),
+            ('s', _, Some(time)) => format!("{:0count$}", time.second()),
+            ('a', _, Some(time)) => data.day_periods[usize::from(time.hour() >= 12)].into(),
+            _ => String::new(),
+        };
+        result.push_str(&field);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use super::{DateFormatter, DateStyle, DateTimeFormatter, TimeFormatter, TimeStyle};
+    use crate::locale::{Formatter, Locale};
+
+    fn locale(id: &'static str) -> Locale {
+        Locale(id.into())
+    }
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn time(hour: u8, minute: u8, second: u8) -> Time {
+        Time::from_hms(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn date_styles() {
+        let day = date(2025, Month::January, 2);
+        let format = |id, style| -> alloc::string::String {
+            DateFormatter::new(&locale(id))
+                .style(style)
+                .format(&day)
+                .into()
+        };
+        assert_eq!(format("en", DateStyle::Short), "1/2/25");
+        assert_eq!(format("en", DateStyle::Medium), "Jan 2, 2025");
+        assert_eq!(format("en", DateStyle::Long), "January 2, 2025");
+        assert_eq!(format("en", DateStyle::Full), "Thursday, January 2, 2025");
+        assert_eq!(format("de", DateStyle::Short), "02.01.25");
+        assert_eq!(format("fr", DateStyle::Medium), "2 janv. 2025");
+        assert_eq!(format("ja", DateStyle::Long), "2025年1月2日");
+        // Quoted text in the pattern is kept as is.
+        assert_eq!(format("es", DateStyle::Long), "2 de enero de 2025");
+    }
+
+    #[test]
+    fn short_years_are_two_digits() {
+        let short = DateFormatter::new(&locale("en")).style(DateStyle::Short);
+        assert_eq!(&*short.format(&date(2005, Month::December, 31)), "12/31/05");
+        let medium = DateFormatter::new(&locale("en"));
+        assert_eq!(&*medium.format(&date(5, Month::March, 1)), "Mar 1, 5");
+    }
+
+    #[test]
+    fn time_styles_and_clocks() {
+        let format = |id, style, value| -> alloc::string::String {
+            TimeFormatter::new(&locale(id))
+                .style(style)
+                .format(&value)
+                .into()
+        };
+        assert_eq!(format("en", TimeStyle::Short, time(15, 4, 5)), "3:04 PM");
+        assert_eq!(
+            format("en", TimeStyle::Medium, time(15, 4, 5)),
+            "3:04:05 PM"
+        );
+        assert_eq!(
+            format("en", TimeStyle::Medium, time(0, 4, 5)),
+            "12:04:05 AM"
+        );
+        assert_eq!(format("en", TimeStyle::Short, time(12, 0, 0)), "12:00 PM");
+        assert_eq!(format("de", TimeStyle::Short, time(9, 5, 0)), "09:05");
+        assert_eq!(format("ja", TimeStyle::Medium, time(9, 5, 7)), "9:05:07");
+    }
+
+    #[test]
+    fn dates_with_times() {
+        let value = PrimitiveDateTime::new(date(2025, Month::January, 2), time(15, 4, 5));
+        let en = DateTimeFormatter::new(&locale("en"));
+        assert_eq!(&*en.format(&value), "Jan 2, 2025, 3:04 PM");
+        let full = en.date_style(DateStyle::Full).time_style(TimeStyle::Medium);
+        assert_eq!(
+            &*full.format(&value.assume_utc()),
+            "Thursday, January 2, 2025, 3:04:05 PM"
+        );
+        let fr = DateTimeFormatter::new(&locale("fr"));
+        assert_eq!(&*fr.format(&value), "2 janv. 2025 15:04");
+    }
+}
@@ -0,0 +1,192 @@
+//! Locales, and formatters showing values the way a locale expects.
+//!
+//! Formatters are backed by locale data taken from the CLDR. English is always bundled,
+//! and the other locales with their `locale-*` feature; a locale without data falls back
+//! to its language, then to English. Traditional Chinese locales, such as `zh-Hant` or
+//! `zh-TW`, fall back to English rather than to the Simplified Chinese data of `zh`. Each formatter is extracted from the [`Locale`] of the
+//! environment, and is used with [`Text::format`](crate::Text::format):
+//!
+//! ```
+//! # use waterui_reactive::binding;
+//! # use waterui_text::{Text, locale::{CurrencyFormatter, Locale, RelativeTimeFormatter}};
+//! # let locale = Locale("fr-CA".into());
+//! # let (price, elapsed) = (binding(12.5), binding(time::Duration::minutes(-5)));
+//! let total = Text::format::<f64>(price, CurrencyFormatter::new(&locale, "EUR"));
+//! let sent = Text::format(elapsed, RelativeTimeFormatter::new(&locale));
+//! ```
+
+mod data;
+mod date;
+mod number;
+mod plural;
+mod relative;
+
+pub use date::{DateFormatter, DateStyle, DateTimeFormatter, TimeFormatter, TimeStyle};
+pub use number::{ByteFormatter, CurrencyFormatter, NumberFormatter, PercentFormatter};
+pub use plural::PluralCategory;
+pub use relative::{DurationFormatter, RelativeTimeFormatter, UnitStyle};
+
+use alloc::string::String;
+use data::{LOCALES, LocaleData};
+use waterui_core::{Compute, Str};
+use waterui_core::{Environment, Error, extract::Extractor};
+
+pub trait Formatter<T> {
+    fn format(&self, value: &T) -> Str;
+}
+
+#[derive(Debug, Clone)]
+pub struct Locale(pub Str);
+
+impl Locale {
+    /// Returns the identifier of the bundled locale used for this locale, such as `fr` for
+    /// `fr-CA`.
+    pub fn resolved(&self) -> &'static str {
+        self.data().id
+    }
+
+    /// Returns the language subtag of this locale in lowercase, such as `zh` for
+    /// `zh-Hant-TW`.
+    pub fn language(&self) -> String {
+        self.fallbacks()
+            .last()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    }
+
+    /// Returns this locale followed by its less specific locales, such as `zh-Hant-TW`,
+    /// `zh-Hant` and `zh`.
+    pub fn fallbacks(&self) -> impl Iterator<Item = &str> {
+        let mut tag = Some(self.0.trim()).filter(|tag| !tag.is_empty());
+        core::iter::from_fn(move || {
+            let current = tag?;
+            tag = current.rfind(['-', '_']).map(|index| &current[..index]);
+            Some(current)
+        })
+    }
+
+    /// Returns the plural category of `n` in this locale.
+    ///
+    /// Rules are available for Arabic, Belarusian, Bosnian, Croatian, Czech, French, Hebrew,
+    /// Italian, Latvian, Lithuanian, Polish, Portuguese, Romanian, Russian, Serbian, Slovak,
+    /// Spanish and Ukrainian, and for the languages without plural forms, such as Chinese
+    /// or Japanese. Other languages use the English rule, with `one` for 1 and `other`
+    /// otherwise, which is right for German or Dutch but not for every language.
+    pub fn plural_category(&self, n: f64) -> PluralCategory {
+        plural::category(&self.language(), n)
+    }
+
+    /// Returns the identifiers of the bundled locales.
+    pub fn bundled() -> impl Iterator<Item = &'static str> {
+        LOCALES.iter().map(|data| data.id)
+    }
+
+    pub(crate) fn data(&self) -> &'static LocaleData {
+        for tag in self.fallbacks() {
+            if let Some(data) = LOCALES.iter().find(|data| same_tag(data.id, tag)) {
+                return data;
+            }
+            if TRADITIONAL_CHINESE.iter().any(|id| same_tag(id, tag)) {
+                break;
+            }
+        }
+        &data::EN
+    }
+}
+
+/// Locales written in Traditional Chinese, which don't fall back to the Simplified Chinese
+/// data of `zh`.
+const TRADITIONAL_CHINESE: [&str; 4] = ["zh-Hant", "zh-TW", "zh-HK", "zh-MO"];
+
+/// Compares language tags, ignoring case and whether subtags are separated by `-` or `_`.
+fn same_tag(a: &str, b: &str) -> bool {
+    let normalize = |byte: u8| match byte {
+        b'_' => b'-',
+        byte => byte.to_ascii_lowercase(),
+    };
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .all(|(a, b)| normalize(a) == normalize(b))
+}
+
+impl Extractor for Locale {
+    fn extract(env: &Environment) -> Result<Self, Error> {
+        env.get_computed::<Self>()
+            .ok_or(Error::msg("Cannot determine locale"))
+            .map(|locale| locale.compute())
+    }
+}
+
+macro_rules! extract_from_locale {
+    ($($ty:ty),*) => {
+        $(
+            impl Extractor for $ty {
+                fn extract(env: &Environment) -> Result<Self, Error> {
+                    Ok(Self::new(&Locale::extract(env)?))
+                }
+            }
+        )*
+    };
+}
+
+extract_from_locale!(
+    NumberFormatter,
+    PercentFormatter,
+    ByteFormatter,
+    DateFormatter,
+    TimeFormatter,
+    DateTimeFormatter,
+    DurationFormatter,
+    RelativeTimeFormatter
+);
+
+/// Puts `value` in place of `{0}` in `pattern`.
+fn fill(pattern: &str, value: &str) -> String {
+    pattern.replacen("{0}", value, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Locale;
+
+    fn locale(id: &'static str) -> Locale {
+        Locale(id.into())
+    }
+
+    #[test]
+    fn fallbacks_drop_subtags() {
+        let (taiwan, brazil) = (locale("zh-Hant-TW"), locale(" pt_BR "));
+        let fallbacks: Vec<&str> = taiwan.fallbacks().collect();
+        assert_eq!(fallbacks, ["zh-Hant-TW", "zh-Hant", "zh"]);
+        let fallbacks: Vec<&str> = brazil.fallbacks().collect();
+        assert_eq!(fallbacks, ["pt_BR", "pt"]);
+        assert_eq!(locale("").fallbacks().count(), 0);
+
+        assert_eq!(locale("zh-Hant-TW").language(), "zh");
+        assert_eq!(locale("FR-ca").language(), "fr");
+        assert_eq!(locale("").language(), "");
+    }
+
+    #[test]
+    fn data_falls_back_to_the_language_then_english() {
+        assert_eq!(locale("fr-CA").resolved(), "fr");
+        assert_eq!(locale("DE").resolved(), "de");
+        assert_eq!(locale("zh-Hans-CN").resolved(), "zh");
+        assert_eq!(locale("en-GB").resolved(), "en");
+        assert_eq!(locale("xx").resolved(), "en");
+        assert_eq!(locale("").resolved(), "en");
+        assert!(Locale::bundled().any(|id| id == "ja"));
+    }
+
+    #[test]
+    fn traditional_chinese_does_not_fall_back_to_simplified_chinese() {
+        for id in ["zh-Hant", "zh-Hant-TW", "zh_TW", "zh-HK", "ZH-mo"] {
+            assert_eq!(locale(id).resolved(), "en", "{id}");
+        }
+        assert_eq!(locale("zh-CN").resolved(), "zh");
+        assert_eq!(locale("zh").resolved(), "zh");
+    }
+}
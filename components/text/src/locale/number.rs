@@ -0,0 +1,387 @@
+use alloc::{format, string::String, string::ToString};
+use waterui_core::Str;
+
+use super::{
+    Formatter, Locale,
+    data::{CURRENCY_DIGITS, LocaleData},
+    fill,
+};
+
+/// Formats numbers with the separators of a locale, such as `1,234.5` or `1 234,5`.
+#[derive(Debug, Clone)]
+pub struct NumberFormatter {
+    locale: Locale,
+    min_fraction_digits: usize,
+    max_fraction_digits: usize,
+    grouping: bool,
+}
+
+impl NumberFormatter {
+    /// Creates a formatter showing up to three fraction digits, with grouping.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            locale: locale.clone(),
+            min_fraction_digits: 0,
+            max_fraction_digits: 3,
+            grouping: true,
+        }
+    }
+
+    /// Shows between `min` and `max` fraction digits, rounding to `max`.
+    pub fn fraction_digits(mut self, min: usize, max: usize) -> Self {
+        self.min_fraction_digits = min;
+        self.max_fraction_digits = max.max(min);
+        self
+    }
+
+    /// Shows exactly `digits` fraction digits.
+    pub fn precision(self, digits: usize) -> Self {
+        self.fraction_digits(digits, digits)
+    }
+
+    /// Sets whether the integer digits are grouped, as in `1,234`.
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    fn format_float(&self, value: f64) -> String {
+        let data = self.locale.data();
+        if value.is_nan() {
+            return "NaN".into();
+        }
+        let digits = if value.is_infinite() {
+            "∞".into()
+        } else {
+            let rounded = format!("{:.*}", self.max_fraction_digits, value.abs());
+            let (integer, fraction) = rounded.split_once('.').unwrap_or((&rounded, ""));
+            self.digits(data, integer, fraction)
+        };
+        with_sign(value.is_sign_negative(), digits)
+    }
+
+    fn format_integer(&self, value: i128) -> String {
+        let data = self.locale.data();
+        let integer = value.unsigned_abs().to_string();
+        with_sign(value < 0, self.digits(data, &integer, ""))
+    }
+
+    /// Joins the integer and fraction digits with the separators of the locale.
+    fn digits(&self, data: &LocaleData, integer: &str, fraction: &str) -> String {
+        let significant = fraction.trim_end_matches('0').len();
+        let fraction = &fraction[..significant.max(self.min_fraction_digits.min(fraction.len()))];
+        let padding = self.min_fraction_digits.saturating_sub(fraction.len());
+
+        let mut result = String::new();
+        let grouped = self.grouping && integer.len() >= 3 + data.min_grouping;
+        for (index, digit) in integer.chars().enumerate() {
+            if grouped && index > 0 && (integer.len() - index).is_multiple_of(3) {
+                result.push_str(data.group);
+            }
+            result.push(digit);
+        }
+        if fraction.len() + padding > 0 {
+            result.push_str(data.decimal);
+            result.push_str(fraction);
+            result.extend(core::iter::repeat_n('0', padding));
+        }
+        result
+    }
+}
+
+/// Prefixes `digits` with a minus sign, unless they round to zero.
+fn with_sign(negative: bool, digits: String) -> String {
+    if negative
+        && digits
+            .chars()
+            .any(|c| c.is_ascii_digit() && c != '0' || c == '∞')
+    {
+        format!("-{digits}")
+    } else {
+        digits
+    }
+}
+
+macro_rules! impl_number_formatter {
+    (integer: $($integer:ty),*; float: $($float:ty),*) => {
+        $(
+            impl Formatter<$integer> for NumberFormatter {
+                fn format(&self, value: &$integer) -> Str {
+                    self.format_integer(i128::from(*value)).into()
+                }
+            }
+        )*
+        $(
+            impl Formatter<$float> for NumberFormatter {
+                fn format(&self, value: &$float) -> Str {
+                    self.format_float(f64::from(*value)).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_number_formatter!(integer: i8, i16, i32, i64, u8, u16, u32, u64; float: f32, f64);
+
+impl Formatter<usize> for NumberFormatter {
+    fn format(&self, value: &usize) -> Str {
+        self.format_integer(*value as i128).into()
+    }
+}
+
+impl Formatter<isize> for NumberFormatter {
+    fn format(&self, value: &isize) -> Str {
+        self.format_integer(*value as i128).into()
+    }
+}
+
+/// Formats fractions as percentages, such as `0.25` as `25%`.
+#[derive(Debug, Clone)]
+pub struct PercentFormatter {
+    number: NumberFormatter,
+}
+
+impl PercentFormatter {
+    /// Creates a formatter showing whole percentages.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            number: NumberFormatter::new(locale).fraction_digits(0, 0),
+        }
+    }
+
+    /// Shows between `min` and `max` fraction digits of the percentage.
+    pub fn fraction_digits(mut self, min: usize, max: usize) -> Self {
+        self.number = self.number.fraction_digits(min, max);
+        self
+    }
+}
+
+impl Formatter<f64> for PercentFormatter {
+    fn format(&self, value: &f64) -> Str {
+        let data = self.number.locale.data();
+        fill(data.percent, &self.number.format_float(value * 100.0)).into()
+    }
+}
+
+impl Formatter<f32> for PercentFormatter {
+    fn format(&self, value: &f32) -> Str {
+        self.format(&f64::from(*value))
+    }
+}
+
+/// Formats amounts of a currency, such as `$1,234.50` or `1.234,50 €`.
+#[derive(Debug, Clone)]
+pub struct CurrencyFormatter {
+    number: NumberFormatter,
+    code: Str,
+}
+
+impl CurrencyFormatter {
+    /// Creates a formatter for the currency with the ISO 4217 `code`, such as `USD`,
+    /// showing the usual fraction digits of the currency.
+    pub fn new(locale: &Locale, code: impl Into<Str>) -> Self {
+        let code = code.into();
+        let digits = CURRENCY_DIGITS
+            .iter()
+            .find(|(currency, _)| code.eq_ignore_ascii_case(currency))
+            .map_or(2, |(_, digits)| *digits);
+        Self {
+            number: NumberFormatter::new(locale).precision(digits),
+            code,
+        }
+    }
+
+    /// Returns the symbol of the currency in the locale, or its code without one.
+    pub fn symbol(&self) -> Str {
+        self.number
+            .locale
+            .data()
+            .currency_symbols
+            .iter()
+            .find(|(currency, _)| self.code.eq_ignore_ascii_case(currency))
+            .map_or_else(|| self.code.clone(), |(_, symbol)| Str::from(*symbol))
+    }
+}
+
+impl Formatter<f64> for CurrencyFormatter {
+    fn format(&self, value: &f64) -> Str {
+        let data = self.number.locale.data();
+        let amount = self.number.format_float(value.abs());
+        let formatted = fill(data.currency, &amount).replacen('¤', &self.symbol(), 1);
+        with_sign(value.is_sign_negative(), formatted).into()
+    }
+}
+
+impl Formatter<f32> for CurrencyFormatter {
+    fn format(&self, value: &f32) -> Str {
+        self.format(&f64::from(*value))
+    }
+}
+
+/// Formats byte counts with decimal units, such as `1.5 MB`.
+#[derive(Debug, Clone)]
+pub struct ByteFormatter {
+    number: NumberFormatter,
+}
+
+impl ByteFormatter {
+    /// Creates a formatter showing up to one fraction digit.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            number: NumberFormatter::new(locale).fraction_digits(0, 1),
+        }
+    }
+}
+
+impl Formatter<u64> for ByteFormatter {
+    fn format(&self, value: &u64) -> Str {
+        let data = self.number.locale.data();
+        let mut unit = 0;
+        let mut scaled = *value as f64;
+        // Amounts rounding up to 1000 with one fraction digit use the next unit.
+        while scaled >= 999.95 && unit < data.bytes.len() - 1 {
+            scaled /= 1000.0;
+            unit += 1;
+        }
+        let amount = if unit == 0 {
+            self.number.format_integer(i128::from(*value))
+        } else {
+            self.number.format_float(scaled)
+        };
+        fill(data.bytes[unit], &amount).into()
+    }
+}
+
+impl Formatter<usize> for ByteFormatter {
+    fn format(&self, value: &usize) -> Str {
+        self.format(&(*value as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ByteFormatter, CurrencyFormatter, NumberFormatter, PercentFormatter};
+    use crate::locale::{Formatter, Locale};
+
+    fn locale(id: &'static str) -> Locale {
+        Locale(id.into())
+    }
+
+    fn number<T>(id: &'static str, value: T) -> alloc::string::String
+    where
+        NumberFormatter: Formatter<T>,
+    {
+        NumberFormatter::new(&locale(id)).format(&value).into()
+    }
+
+    #[test]
+    fn grouping() {
+        assert_eq!(number("en", 1_234_567.891), "1,234,567.891");
+        assert_eq!(number("en", 999), "999");
+        assert_eq!(number("en", -1234), "-1,234");
+        assert_eq!(number("en", u64::MAX), "18,446,744,073,709,551,615");
+        assert_eq!(number("en", i64::MIN), "-9,223,372,036,854,775,808");
+        assert_eq!(number("de", 1_234_567.891), "1.234.567,891");
+        assert_eq!(number("fr", 1_234_567.891), "1\u{202f}234\u{202f}567,891");
+
+        let ungrouped = NumberFormatter::new(&locale("en")).grouping(false);
+        assert_eq!(&*ungrouped.format(&1_234_567), "1234567");
+    }
+
+    #[test]
+    fn minimum_grouping_digits() {
+        // Spanish only groups numbers of five digits or more.
+        assert_eq!(number("es", 1234), "1234");
+        assert_eq!(number("es", -1234.5), "-1234,5");
+        assert_eq!(number("es", 12_345), "12.345");
+    }
+
+    #[test]
+    fn fraction_digits_and_rounding() {
+        let en = locale("en");
+        assert_eq!(number("en", 1.100), "1.1");
+        assert_eq!(number("en", 9.9996), "10");
+        assert_eq!(number("en", 999.9996), "1,000");
+
+        let padded = NumberFormatter::new(&en).fraction_digits(2, 4);
+        assert_eq!(&*padded.format(&1.5), "1.50");
+        assert_eq!(&*padded.format(&1.234_56), "1.2346");
+        assert_eq!(&*padded.format(&7), "7.00");
+
+        let precise = NumberFormatter::new(&en).precision(2);
+        assert_eq!(&*precise.format(&1.996), "2.00");
+        assert_eq!(&*precise.format(&0.0), "0.00");
+        // Fraction digits are swapped if given in the wrong order.
+        let swapped = NumberFormatter::new(&en).fraction_digits(2, 1);
+        assert_eq!(&*swapped.format(&1.0), "1.00");
+    }
+
+    #[test]
+    fn negative_zero_has_no_sign() {
+        assert_eq!(number("en", -0.0), "0");
+        assert_eq!(number("en", -0.0001), "0");
+        let precise = NumberFormatter::new(&locale("en")).precision(4);
+        assert_eq!(&*precise.format(&-0.0001), "-0.0001");
+    }
+
+    #[test]
+    fn nan_and_infinity() {
+        assert_eq!(number("en", f64::NAN), "NaN");
+        assert_eq!(number("en", -f64::NAN), "NaN");
+        assert_eq!(number("en", f64::INFINITY), "∞");
+        assert_eq!(number("fr", f64::NEG_INFINITY), "-∞");
+        assert_eq!(number("en", f32::INFINITY), "∞");
+    }
+
+    #[test]
+    fn percentages() {
+        let en = PercentFormatter::new(&locale("en"));
+        assert_eq!(&*en.format(&0.256), "26%");
+        assert_eq!(&*en.format(&-0.001), "0%");
+        assert_eq!(&*en.fraction_digits(1, 1).format(&0.256), "25.6%");
+        let fr = PercentFormatter::new(&locale("fr"));
+        assert_eq!(&*fr.format(&0.5), "50\u{202f}%");
+    }
+
+    #[test]
+    fn currencies() {
+        let currency = |id, code, value: f64| -> alloc::string::String {
+            CurrencyFormatter::new(&locale(id), code)
+                .format(&value)
+                .into()
+        };
+        assert_eq!(currency("en", "EUR", -1234.5), "-€1,234.50");
+        assert_eq!(currency("en", "usd", 0.5), "$0.50");
+        assert_eq!(currency("en", "USD", -0.001), "$0.00");
+        assert_eq!(currency("fr", "EUR", -1234.5), "-1\u{202f}234,50\u{a0}€");
+
+        // Currencies without cents, or with three decimals.
+        assert_eq!(currency("en", "JPY", 1234.4), "¥1,234");
+        assert_eq!(currency("zh", "JPY", 1234.0), "JP¥1,234");
+        assert_eq!(currency("fr", "KWD", 1.5), "1,500\u{a0}KWD");
+
+        // Currencies without a symbol in the locale show their code.
+        let franc = CurrencyFormatter::new(&locale("en"), "CHF");
+        assert_eq!(&*franc.symbol(), "CHF");
+        assert_eq!(&*franc.format(&2.0), "CHF2.00");
+    }
+
+    #[test]
+    fn byte_units() {
+        let bytes = |id, value: u64| -> alloc::string::String {
+            ByteFormatter::new(&locale(id)).format(&value).into()
+        };
+        assert_eq!(bytes("en", 0), "0 byte");
+        assert_eq!(bytes("en", 999), "999 byte");
+        assert_eq!(bytes("en", 1000), "1 kB");
+        assert_eq!(bytes("en", 1_500_000), "1.5 MB");
+        assert_eq!(bytes("en", 999_949), "999.9 kB");
+        assert_eq!(bytes("en", 999_950), "1 MB");
+        assert_eq!(bytes("en", u64::MAX), "18,446.7 PB");
+        assert_eq!(bytes("fr", 1_500_000), "1,5 Mo");
+        assert_eq!(
+            &*ByteFormatter::new(&locale("en")).format(&2048_usize),
+            "2 kB"
+        );
+    }
+}
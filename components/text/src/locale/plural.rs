@@ -0,0 +1,173 @@
+//! The plural rules of the CLDR, choosing the form of a word for a number.
+//!
+//! Rules are available for more languages than the bundled locale data, as messages
+//! of any language need them. Languages without a rule use the English one.
+
+use alloc::format;
+
+/// The plural category of a number, choosing between the forms of a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the category with the CLDR name `name`, such as `few`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "zero" => Self::Zero,
+            "one" => Self::One,
+            "two" => Self::Two,
+            "few" => Self::Few,
+            "many" => Self::Many,
+            "other" => Self::Other,
+            _ => return None,
+        })
+    }
+}
+
+/// The operands of a number used by plural rules.
+struct Operands {
+    /// The absolute value.
+    n: f64,
+    /// The integer digits.
+    i: u64,
+    /// The number of visible fraction digits.
+    v: usize,
+    /// The last two visible fraction digits, as an integer.
+    f: u64,
+}
+
+impl Operands {
+    fn new(n: f64) -> Self {
+        let n = n.abs();
+        let shown = format!("{n}");
+        let fraction = shown.split_once('.').map_or("", |(_, fraction)| fraction);
+        Self {
+            n,
+            i: n.trunc() as u64,
+            v: fraction.len(),
+            f: fraction[fraction.len().saturating_sub(2)..]
+                .parse()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Returns the plural category of `n` in `language`, a language subtag such as `ru`.
+pub(crate) fn category(language: &str, n: f64) -> PluralCategory {
+    use PluralCategory::*;
+
+    let Operands { n, i, v, f } = Operands::new(n);
+    let (i10, i100, f10) = (i % 10, i % 100, f % 10);
+    match language {
+        "ja" | "zh" | "ko" | "vi" | "th" | "id" | "ms" => Other,
+        "fr" | "pt" if i <= 1 => One,
+        "fr" | "pt" => Other,
+        "es" | "it" if n == 1.0 => One,
+        "es" | "it" => Other,
+        "ru" | "uk" | "be" if v != 0 => Other,
+        "ru" | "uk" | "be" if i10 == 1 && i100 != 11 => One,
+        "ru" | "uk" | "be" if (2..=4).contains(&i10) && !(12..=14).contains(&i100) => Few,
+        "ru" | "uk" | "be" => Many,
+        "pl" if v != 0 => Other,
+        "pl" if i == 1 => One,
+        "pl" if (2..=4).contains(&i10) && !(12..=14).contains(&i100) => Few,
+        "pl" => Many,
+        "cs" | "sk" if v != 0 => Many,
+        "cs" | "sk" if i == 1 => One,
+        "cs" | "sk" if (2..=4).contains(&i) => Few,
+        "cs" | "sk" => Other,
+        "ar" if n == 0.0 => Zero,
+        "ar" if n == 1.0 => One,
+        "ar" if n == 2.0 => Two,
+        "ar" if v == 0 && (3..=10).contains(&i100) => Few,
+        "ar" if v == 0 && (11..=99).contains(&i100) => Many,
+        "ar" => Other,
+        "hr" | "sr" | "bs" if v == 0 && i10 == 1 && i100 != 11 => One,
+        "hr" | "sr" | "bs" if v == 0 && (2..=4).contains(&i10) && !(12..=14).contains(&i100) => Few,
+        "hr" | "sr" | "bs" if f10 == 1 && f != 11 => One,
+        "hr" | "sr" | "bs" if (2..=4).contains(&f10) && !(12..=14).contains(&f) => Few,
+        "hr" | "sr" | "bs" => Other,
+        "lt" if v != 0 => Many,
+        "lt" if i10 == 1 && !(11..=19).contains(&i100) => One,
+        "lt" if (2..=9).contains(&i10) && !(11..=19).contains(&i100) => Few,
+        "lt" => Other,
+        "lv" if v == 0 && (i10 == 0 || (11..=19).contains(&i100)) => Zero,
+        "lv" if v == 2 && (11..=19).contains(&f) => Zero,
+        "lv" if v == 0 && i10 == 1 && i100 != 11 => One,
+        "lv" if v != 0 && f10 == 1 && !(v == 2 && f == 11) => One,
+        "lv" => Other,
+        "ro" if i == 1 && v == 0 => One,
+        "ro" if v != 0 || n == 0.0 || (1..=19).contains(&i100) => Few,
+        "ro" => Other,
+        "he" if (i == 1 && v == 0) || (i == 0 && v != 0) => One,
+        "he" if i == 2 && v == 0 => Two,
+        "he" => Other,
+        _ if i == 1 && v == 0 => One,
+        _ => Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PluralCategory, category};
+
+    /// Returns the categories of 0, 1, 2, 5, 11, 21 and 1.5 in `language`.
+    fn table(language: &str) -> [PluralCategory; 7] {
+        [0.0, 1.0, 2.0, 5.0, 11.0, 21.0, 1.5].map(|n| category(language, n))
+    }
+
+    #[test]
+    fn slavic_languages() {
+        use PluralCategory::*;
+        assert_eq!(table("ru"), [Many, One, Few, Many, Many, One, Other]);
+        assert_eq!(table("uk"), table("ru"));
+        assert_eq!(table("pl"), [Many, One, Few, Many, Many, Many, Other]);
+        assert_eq!(table("cs"), [Other, One, Few, Other, Other, Other, Many]);
+        assert_eq!(table("hr"), [Other, One, Few, Other, Other, One, Other]);
+        assert_eq!(table("sr"), table("hr"));
+        assert_eq!(category("hr", 1.1), One);
+        assert_eq!(category("bs", 2.3), Few);
+        assert_eq!(category("ru", 112.0), Many);
+        assert_eq!(category("ru", 22.0), Few);
+    }
+
+    #[test]
+    fn other_languages() {
+        use PluralCategory::*;
+        assert_eq!(table("ar"), [Zero, One, Two, Few, Many, Many, Other]);
+        assert_eq!(category("ar", 102.0), Other);
+        assert_eq!(table("fr"), [One, One, Other, Other, Other, Other, One]);
+        assert_eq!(table("lt"), [Other, One, Few, Few, Other, One, Many]);
+        assert_eq!(table("lv"), [Zero, One, Other, Other, Zero, One, Other]);
+        assert_eq!(category("lv", 0.1), One);
+        assert_eq!(category("lv", 0.11), Zero);
+        assert_eq!(table("ro"), [Few, One, Few, Few, Few, Other, Few]);
+        assert_eq!(category("ro", 101.0), Few);
+        assert_eq!(table("he"), [Other, One, Two, Other, Other, Other, Other]);
+        assert_eq!(category("he", 0.5), One);
+        assert_eq!(table("ja"), [Other; 7]);
+    }
+
+    #[test]
+    fn english_is_the_default() {
+        use PluralCategory::*;
+        assert_eq!(table("en"), [Other, One, Other, Other, Other, Other, Other]);
+        assert_eq!(table("de"), table("en"));
+        assert_eq!(table("xx"), table("en"));
+        assert_eq!(category("en", -1.0), One);
+        assert_eq!(category("en", 1.0e-20), Other);
+    }
+
+    #[test]
+    fn category_names() {
+        assert_eq!(PluralCategory::from_name("few"), Some(PluralCategory::Few));
+        assert_eq!(PluralCategory::from_name("Few"), None);
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::{string::String, vec::Vec};
+use waterui_core::Str;
+
+use super::{Formatter, Locale, NumberFormatter, PluralCategory, data::Plural, fill, plural};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// The length of the unit names in a formatted duration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnitStyle {
+    /// Full unit names, such as `2 hours`.
+    #[default]
+    Long,
+    /// Abbreviated unit names, such as `2 hr`.
+    Short,
+}
+
+/// Formats durations with their largest units, such as `1 hour, 5 minutes`.
+#[derive(Debug, Clone)]
+pub struct DurationFormatter {
+    locale: Locale,
+    style: UnitStyle,
+    max_units: usize,
+}
+
+impl DurationFormatter {
+    /// Creates a formatter showing up to two units, with their full names.
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            locale: locale.clone(),
+            style: UnitStyle::Long,
+            max_units: 2,
+        }
+    }
+
+    pub fn style(mut self, style: UnitStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Shows up to `max_units` units, from days to seconds, omitting the smaller ones.
+    pub fn max_units(mut self, max_units: usize) -> Self {
+        self.max_units = max_units.max(1);
+        self
+    }
+}
+
+impl Formatter<core::time::Duration> for DurationFormatter {
+    fn format(&self, value: &core::time::Duration) -> Str {
+        let data = self.locale.data();
+        let units = match self.style {
+            UnitStyle::Long => &data.duration_units,
+            UnitStyle::Short => &data.duration_units_short,
+        };
+
+        let mut remaining = value.as_secs();
+        let mut parts = Vec::new();
+        for (index, size) in [DAY, HOUR, MINUTE, 1].into_iter().enumerate() {
+            let count = remaining / size;
+            remaining %= size;
+            if count > 0 && parts.len() < self.max_units {
+                parts.push(unit_pattern(&self.locale, units[3 - index], count));
+            }
+        }
+        if parts.is_empty() {
+            parts.push(unit_pattern(&self.locale, units[0], 0));
+        }
+        parts.join(data.list_separator).into()
+    }
+}
+
+impl Formatter<time::Duration> for DurationFormatter {
+    fn format(&self, value: &time::Duration) -> Str {
+        self.format(&value.unsigned_abs())
+    }
+}
+
+/// Formats signed durations relative to now with their largest unit, such as `in 3 days`
+/// for positive durations or `5 minutes ago` for negative ones.
+#[derive(Debug, Clone)]
+pub struct RelativeTimeFormatter {
+    locale: Locale,
+}
+
+impl RelativeTimeFormatter {
+    pub fn new(locale: &Locale) -> Self {
+        Self {
+            locale: locale.clone(),
+        }
+    }
+}
+
+impl Formatter<time::Duration> for RelativeTimeFormatter {
+    fn format(&self, value: &time::Duration) -> Str {
+        let data = self.locale.data();
+        let seconds = value.whole_seconds().unsigned_abs();
+        let units = [1, MINUTE, HOUR, DAY, 7 * DAY, 30 * DAY, 365 * DAY];
+        let Some(unit) = units.iter().rposition(|size| seconds >= *size) else {
+            return data.now.into();
+        };
+
+        let amount = unit_pattern(
+            &self.locale,
+            data.relative_units[unit],
+            seconds / units[unit],
+        );
+        let pattern = if value.is_negative() {
+            data.past
+        } else {
+            data.future
+        };
+        fill(pattern, &amount).into()
+    }
+}
+
+/// Fills the form of `unit` for `count` in the locale.
+///
+/// The form is chosen with the plural rule of the language of the data, which is English
+/// for locales without data of their own.
+fn unit_pattern(locale: &Locale, (one, other): Plural, count: u64) -> String {
+    let pattern = match plural::category(locale.resolved(), count as f64) {
+        PluralCategory::One => one,
+        _ => other,
+    };
+    fill(pattern, &NumberFormatter::new(locale).format(&count))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::{DurationFormatter, RelativeTimeFormatter, UnitStyle};
+    use crate::locale::{Formatter, Locale};
+
+    fn locale(id: &'static str) -> Locale {
+        Locale(id.into())
+    }
+
+    #[test]
+    fn durations() {
+        let en = DurationFormatter::new(&locale("en"));
+        assert_eq!(&*en.format(&Duration::from_secs(3900)), "1 hour, 5 minutes");
+        assert_eq!(&*en.format(&Duration::from_secs(1)), "1 second");
+        assert_eq!(&*en.format(&Duration::from_millis(999)), "0 seconds");
+        // Only the largest units are shown, skipping the empty ones.
+        assert_eq!(
+            &*en.format(&Duration::from_secs(86_400 + 7)),
+            "1 day, 7 seconds"
+        );
+        let all = en.clone().max_units(4);
+        assert_eq!(
+            &*all.format(&Duration::from_secs(2 * 86_400 + 3 * 3600 + 4 * 60 + 5)),
+            "2 days, 3 hours, 4 minutes, 5 seconds"
+        );
+        let one = en.clone().max_units(0);
+        assert_eq!(&*one.format(&Duration::from_secs(3900)), "1 hour");
+
+        let short = en.style(UnitStyle::Short);
+        assert_eq!(&*short.format(&Duration::from_secs(7260)), "2 hr, 1 min");
+        let signed = DurationFormatter::new(&locale("en"));
+        assert_eq!(&*signed.format(&time::Duration::minutes(-2)), "2 minutes");
+    }
+
+    #[test]
+    fn relative_times() {
+        let format = |id, value| -> alloc::string::String {
+            RelativeTimeFormatter::new(&locale(id))
+                .format(&value)
+                .into()
+        };
+        assert_eq!(format("en", time::Duration::ZERO), "now");
+        assert_eq!(format("en", time::Duration::milliseconds(-900)), "now");
+        assert_eq!(format("en", time::Duration::seconds(-1)), "1 second ago");
+        assert_eq!(format("en", time::Duration::seconds(-119)), "1 minute ago");
+        assert_eq!(format("en", time::Duration::days(3)), "in 3 days");
+        assert_eq!(format("en", time::Duration::days(14)), "in 2 weeks");
+        assert_eq!(format("en", time::Duration::days(-400)), "1 year ago");
+        assert_eq!(format("fr", time::Duration::minutes(-1)), "il y a 1 minute");
+        assert_eq!(format("de", time::Duration::days(3)), "in 3 Tagen");
+        assert_eq!(format("ja", time::Duration::minutes(-1)), "1 分前");
+        assert_eq!(format("zh", time::Duration::minutes(-3)), "3分钟前");
+        assert_eq!(
+            format("zh-Hant-TW", time::Duration::minutes(-3)),
+            "3 minutes ago"
+        );
+    }
+
+    #[test]
+    fn english_fallbacks_use_the_english_plural_rule() {
+        let relative = RelativeTimeFormatter::new(&locale("ru"));
+        assert_eq!(
+            &*relative.format(&time::Duration::minutes(-21)),
+            "21 minutes ago"
+        );
+        let duration = DurationFormatter::new(&locale("pt-BR"));
+        assert_eq!(&*duration.format(&Duration::ZERO), "0 seconds");
+    }
+}
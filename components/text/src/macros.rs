@@ -1,15 +1,105 @@
+/// Creates a [`Text`](crate::Text) formatted from reactive values, again whenever one of
+/// them changes.
+///
+/// The format string follows [`format!`](alloc::format), with positional and named
+/// arguments, captured variables and format specs. Each argument is any
+/// [`Compute`](waterui_reactive::Compute), such as a binding or a computed value, and is
+/// watched for changes; literal arguments are constants, and aren't watched.
+///
+/// ```
+/// # use waterui_reactive::binding;
+/// # use waterui_text::text;
+/// # let (name, amount) = (binding("Tea"), binding(4.5));
+/// let price = text!("{name}: {:.2} {currency}", amount, currency = "EUR");
+/// ```
+///
+/// Interpolations can be styled by ending their spec with styles, joined with `+`:
+/// `bold`, `italic`, `underline`, `strikethrough` and `code`. The macro then creates a
+/// [`StyledText`](crate::attributed::StyledText) showing an
+/// [`AttributedStr`](crate::attributed::AttributedStr):
+///
+/// ```
+/// # use waterui_reactive::binding;
+/// # use waterui_text::text;
+/// # let (name, count) = (binding("Ada"), binding(3));
+/// let greeting = text!("Hello {name:bold}, you have {count:>3:bold+italic} messages");
+/// ```
 #[macro_export]
 macro_rules! text {
-    ($fmt:tt,$($arg:ident),*) => {
-        {
-            let args=($($arg.clone()),*);
-            use waterui_reactive::ComputeExt;
-            #[allow(unused_parens)]
-            $crate::Text::new(ComputeExt::map(
-                args.clone(),|($($arg),*)|{
-                    format!($fmt,$($arg),*)
-                }
-            ).computed())
-        }
+    ($($input:tt)*) => {
+        $crate::__private::text!($crate; $($input)*)
     };
 }
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+    pub use waterui_core::Str;
+    pub use waterui_macro::__text as text;
+    pub use waterui_reactive::ComputeExt;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec, vec::Vec};
+
+    use waterui_reactive::{Compute, binding};
+
+    use crate::attributed::{Attribute, AttributedStr};
+
+    #[test]
+    fn positional_named_and_captured_arguments() {
+        let (count, kind) = (binding(2), binding("messages"));
+        let text = text!(
+            "{} new {kind} ({0} of {total}), {1}",
+            count,
+            "ok",
+            total = 10
+        );
+        assert_eq!(&*text.content().compute(), "2 new messages (2 of 10), ok");
+
+        count.set(3);
+        kind.set("mails");
+        assert_eq!(&*text.content().compute(), "3 new mails (3 of 10), ok");
+    }
+
+    #[test]
+    fn escapes_and_format_specs() {
+        let value = binding(1.23456);
+        let text = text!("{{{value:>8.2}}} {value:e} {:#x}", 255);
+        assert_eq!(&*text.content().compute(), "{    1.23} 1.23456e0 0xff");
+    }
+
+    #[test]
+    fn constant_arguments_only() {
+        let text = text!("{} {name}", 1, name = "two");
+        assert_eq!(&*text.content().compute(), "1 two");
+    }
+
+    #[test]
+    fn styled_interpolations() {
+        let (name, count) = (binding("Ada"), binding(3));
+        let text = text!("Hi {name:bold}, {count:>2:bold+italic} {{new}}");
+
+        let mut expected = AttributedStr::new();
+        expected.push_str("Hi ");
+        expected.push("Ada", [Attribute::Bold]);
+        expected.push_str(", ");
+        expected.push(" 3", [Attribute::Bold, Attribute::Italic]);
+        expected.push_str(" {new}");
+        assert_eq!(text.content().compute(), expected);
+
+        name.set("Grace");
+        let plain = text.content().compute().to_plain_string();
+        assert_eq!(plain, "Hi Grace,  3 {new}");
+        let bold: Vec<String> = text
+            .content()
+            .compute()
+            .chunks()
+            .iter()
+            .filter(|chunk| chunk.attributes == vec![Attribute::Bold])
+            .map(|chunk| String::from(&*chunk.text))
+            .collect();
+        assert_eq!(bold, ["Grace"]);
+    }
+}
@@ -0,0 +1,714 @@
+//! A subset of CommonMark, parsed into attributed strings and blocks.
+//!
+//! Inline markdown becomes an [`AttributedStr`]:
+//! - `**bold**` or `__bold__`, `*italic*` or `_italic_`, and `~~strikethrough~~`,
+//! - `` `code` ``, `[links](https://waterui.dev)` and `<https://waterui.dev>`,
+//! - backslash escapes, and hard line breaks from a trailing backslash or two trailing spaces.
+//!
+//! Documents become a list of [`Block`]s: ATX headings (`# Title`), paragraphs, fenced
+//! code blocks, block quotes, bullet and ordered lists nested by indentation, and thematic
+//! breaks. Setext headings, indented code blocks, HTML, images, tables and reference
+//! links are not supported, and are kept as text.
+
+use alloc::{string::String, vec, vec::Vec};
+use waterui_core::Str;
+
+use crate::attributed::{Attribute, AttributedStr};
+
+/// A block of a markdown document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    /// A paragraph of text.
+    Paragraph(AttributedStr),
+    /// A heading, of level 1 to 6.
+    Heading { level: u8, content: AttributedStr },
+    /// A fenced code block, with the language of its info string.
+    CodeBlock { language: Option<Str>, code: Str },
+    /// A block quote.
+    Quote(Vec<Block>),
+    /// A list, numbered from `start` if it is ordered.
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    /// A thematic break, such as `---`.
+    Rule,
+}
+
+/// Parses a markdown document into blocks.
+pub fn parse(source: &str) -> Vec<Block> {
+    let lines: Vec<&str> = source.lines().collect();
+    blocks(&lines)
+}
+
+/// Parses inline markdown into an attributed string.
+pub fn parse_inline(source: &str) -> AttributedStr {
+    let mut output = AttributedStr::new();
+    inline(source.trim(), &[], &mut output);
+    output
+}
+
+fn blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let indent = indent_of(line);
+        let trimmed = &line[indent..];
+        if trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some((fence, language)) = fence(trimmed) {
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() {
+                let line = lines[i];
+                i += 1;
+                if closes_fence(line.trim_start(), fence) {
+                    break;
+                }
+                let strip = indent.min(indent_of(line));
+                code.push(&line[strip..]);
+            }
+            blocks.push(Block::CodeBlock {
+                language: language.map(|language| String::from(language).into()),
+                code: code.join("\n").into(),
+            });
+        } else if let Some((level, content)) = heading(trimmed) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inline(content),
+            });
+            i += 1;
+        } else if is_rule(trimmed) {
+            blocks.push(Block::Rule);
+            i += 1;
+        } else if trimmed.starts_with('>') {
+            let mut quoted = Vec::new();
+            while let Some(line) = lines
+                .get(i)
+                .and_then(|line| line.trim_start().strip_prefix('>'))
+            {
+                quoted.push(line.strip_prefix(' ').unwrap_or(line));
+                i += 1;
+            }
+            blocks.push(Block::Quote(self::blocks(&quoted)));
+        } else if let Some(marker) = list_marker(trimmed) {
+            let (block, next) = list(lines, i, marker.ordered);
+            blocks.push(block);
+            i = next;
+        } else {
+            let start = i;
+            i += 1;
+            while i < lines.len() {
+                let trimmed = &lines[i][indent_of(lines[i])..];
+                if trimmed.is_empty() || starts_block(trimmed) {
+                    break;
+                }
+                i += 1;
+            }
+            let paragraph: Vec<&str> = lines[start..i]
+                .iter()
+                .map(|line| &line[indent_of(line)..])
+                .collect();
+            blocks.push(Block::Paragraph(parse_inline(&paragraph.join("\n"))));
+        }
+    }
+    blocks
+}
+
+/// Parses the list starting at `lines[start]`, returning it with the index of the line
+/// after it.
+fn list(lines: &[&str], start: usize, ordered: Option<u64>) -> (Block, usize) {
+    let same_kind = |line: &str| {
+        list_marker(&line[indent_of(line)..])
+            .is_some_and(|marker| marker.ordered.is_some() == ordered.is_some())
+    };
+
+    let mut items: Vec<Vec<&str>> = Vec::new();
+    let mut content_indent = 0;
+    let mut i = start;
+    while i < lines.len() {
+        let line = lines[i];
+        let indent = indent_of(line);
+        let trimmed = &line[indent..];
+
+        if trimmed.is_empty() {
+            // A blank line only continues the list if the next line belongs to it.
+            let next = lines[i..]
+                .iter()
+                .find(|line| !line.trim().is_empty())
+                .filter(|line| indent_of(line) >= content_indent || same_kind(line));
+            match (next, items.last_mut()) {
+                (Some(_), Some(item)) => item.push(""),
+                _ => break,
+            }
+        } else if indent >= content_indent && !items.is_empty() {
+            items.last_mut().unwrap().push(&line[content_indent..]);
+        } else if let Some(marker) = list_marker(trimmed).filter(|_| same_kind(line)) {
+            content_indent = indent + marker.width;
+            items.push(vec![&trimmed[marker.width.min(trimmed.len())..]]);
+        } else if !lines[i - 1].trim().is_empty() && !starts_block(trimmed) {
+            // A lazy continuation of the paragraph of the last item.
+            items.last_mut().unwrap().push(trimmed);
+        } else {
+            break;
+        }
+        i += 1;
+    }
+
+    let items = items.iter().map(|item| blocks(item)).collect();
+    let start = list_marker(lines[start].trim_start()).and_then(|marker| marker.ordered);
+    (Block::List { start, items }, i)
+}
+
+/// Returns the width of the indentation of `line` in bytes.
+///
+/// Only spaces and tabs indent a line, as in CommonMark: other whitespace, such as the
+/// ideographic space, is text.
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+fn starts_block(trimmed: &str) -> bool {
+    fence(trimmed).is_some()
+        || heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || trimmed.starts_with('>')
+        || list_marker(trimmed).is_some()
+}
+
+/// Returns the fence opening a code block, and its language.
+fn fence(trimmed: &str) -> Option<(&str, Option<&str>)> {
+    let character = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.len() - trimmed.trim_start_matches(character).len();
+    if length < 3 {
+        return None;
+    }
+    let info = trimmed[length..].trim();
+    if character == '`' && info.contains('`') {
+        return None;
+    }
+    let language = info.split_whitespace().next();
+    Some((&trimmed[..length], language))
+}
+
+fn closes_fence(trimmed: &str, fence: &str) -> bool {
+    let character = fence.as_bytes()[0] as char;
+    let rest = trimmed.trim_start_matches(character);
+    trimmed.len() - rest.len() >= fence.len() && rest.trim().is_empty()
+}
+
+fn heading(trimmed: &str) -> Option<(u8, &str)> {
+    let level = trimmed.len() - trimmed.trim_start_matches('#').len();
+    let rest = &trimmed[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with([' ', '\t'])) {
+        return None;
+    }
+    // An optional closing sequence of `#` is removed, if separated by a space.
+    let mut content = rest.trim();
+    let without_closing = content.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with([' ', '\t']) {
+        content = without_closing.trim_end();
+    }
+    Some((level as u8, content))
+}
+
+fn is_rule(trimmed: &str) -> bool {
+    let Some(character) = trimmed
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '-' | '*' | '_'))
+    else {
+        return false;
+    };
+    trimmed
+        .chars()
+        .all(|c| c == character || c == ' ' || c == '\t')
+        && trimmed.chars().filter(|c| *c == character).count() >= 3
+}
+
+struct ListMarker {
+    /// The number of an ordered list item.
+    ordered: Option<u64>,
+    /// The width of the marker, with the space following it.
+    width: usize,
+}
+
+fn list_marker(trimmed: &str) -> Option<ListMarker> {
+    let (ordered, length) = if trimmed.starts_with(['-', '*', '+']) {
+        (None, 1)
+    } else {
+        let digits = trimmed.len()
+            - trimmed
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if !(1..=9).contains(&digits) || !trimmed[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        (trimmed[..digits].parse().ok(), digits + 1)
+    };
+    let rest = &trimmed[length..];
+    if rest.is_empty() {
+        return Some(ListMarker {
+            ordered,
+            width: length,
+        });
+    }
+    rest.starts_with(' ').then_some(ListMarker {
+        ordered,
+        width: length + 1,
+    })
+}
+
+/// Appends the inline markdown of `source` to `output`, on top of `attributes`.
+fn inline(source: &str, attributes: &[Attribute], output: &mut AttributedStr) {
+    let with = |attribute: Attribute| {
+        let mut attributes = attributes.to_vec();
+        attributes.push(attribute);
+        attributes
+    };
+    let mut text = String::new();
+    let mut i = 0;
+    while let Some(character) = source[i..].chars().next() {
+        let rest = &source[i..];
+        match character {
+            '\\' => match rest[1..].chars().next() {
+                Some('\n') => {
+                    text.push('\n');
+                    i += 2;
+                }
+                Some(escaped) if escaped.is_ascii_punctuation() => {
+                    text.push(escaped);
+                    i += 2;
+                }
+                _ => {
+                    text.push('\\');
+                    i += 1;
+                }
+            },
+            '\n' => {
+                let hard = text.ends_with("  ");
+                text.truncate(text.trim_end_matches(' ').len());
+                text.push(if hard { '\n' } else { ' ' });
+                i += 1;
+                i += source[i..].len() - source[i..].trim_start_matches(' ').len();
+            }
+            '`' => {
+                let run = run_length(rest, '`');
+                match closing_backticks(source, i + run, run) {
+                    Some(end) => {
+                        output.push(core::mem::take(&mut text), attributes.to_vec());
+                        output.push(code_span(&source[i + run..end]), with(Attribute::Code));
+                        i = end + run;
+                    }
+                    None => {
+                        text.push_str(&rest[..run]);
+                        i += run;
+                    }
+                }
+            }
+            '*' | '_' | '~' => {
+                let run = run_length(rest, character);
+                let length = match (character, run) {
+                    ('~', 2) | ('*' | '_', 2) => 2,
+                    ('*' | '_', 1) => 1,
+                    _ => 0,
+                };
+                let closer = (length > 0 && can_open(source, i, length))
+                    .then(|| closing_delimiter(source, i + length, character, length))
+                    .flatten();
+                match closer {
+                    Some(close) => {
+                        output.push(core::mem::take(&mut text), attributes.to_vec());
+                        let attribute = match (character, length) {
+                            ('~', _) => Attribute::Strikethrough,
+                            (_, 2) => Attribute::Bold,
+                            _ => Attribute::Italic,
+                        };
+                        inline(&source[i + length..close], &with(attribute), output);
+                        i = close + length;
+                    }
+                    None => {
+                        text.push_str(&rest[..run]);
+                        i += run;
+                    }
+                }
+            }
+            '[' => match link(source, i) {
+                Some((label_end, url, end)) => {
+                    output.push(core::mem::take(&mut text), attributes.to_vec());
+                    let url = Attribute::Link(String::from(url).into());
+                    inline(&source[i + 1..label_end], &with(url), output);
+                    i = end;
+                }
+                None => {
+                    text.push('[');
+                    i += 1;
+                }
+            },
+            '<' => match autolink(rest) {
+                Some(url) => {
+                    output.push(core::mem::take(&mut text), attributes.to_vec());
+                    i += url.len() + 2;
+                    let url: Str = String::from(url).into();
+                    output.push(url.clone(), with(Attribute::Link(url)));
+                }
+                None => {
+                    text.push('<');
+                    i += 1;
+                }
+            },
+            character => {
+                text.push(character);
+                i += character.len_utf8();
+            }
+        }
+    }
+    output.push(text, attributes.to_vec());
+}
+
+fn run_length(source: &str, character: char) -> usize {
+    source.len() - source.trim_start_matches(character).len()
+}
+
+/// Returns the content of a code span, without the single spaces padding it.
+fn code_span(code: &str) -> String {
+    let code = code.replace('\n', " ");
+    let padded = code.len() > 2 && code.starts_with(' ') && code.ends_with(' ');
+    if padded && !code.trim().is_empty() {
+        String::from(&code[1..code.len() - 1])
+    } else {
+        code
+    }
+}
+
+/// Finds the run of exactly `run` backticks closing a code span, from `from`.
+fn closing_backticks(source: &str, from: usize, run: usize) -> Option<usize> {
+    let mut i = from;
+    while let Some(offset) = source[i..].find('`') {
+        let start = i + offset;
+        let length = run_length(&source[start..], '`');
+        if length == run {
+            return Some(start);
+        }
+        i = start + length;
+    }
+    None
+}
+
+/// Returns whether the delimiter at `i` can open emphasis: it must be followed by text,
+/// and `_` must not be inside a word.
+fn can_open(source: &str, i: usize, length: usize) -> bool {
+    let next = source[i + length..].chars().next();
+    let previous = source[..i].chars().next_back();
+    next.is_some_and(|next| !next.is_whitespace())
+        && !(source[i..].starts_with('_') && previous.is_some_and(char::is_alphanumeric))
+}
+
+/// Finds the delimiter closing emphasis opened before `from`, skipping escapes and code
+/// spans.
+fn closing_delimiter(source: &str, from: usize, character: char, length: usize) -> Option<usize> {
+    let mut i = from;
+    while let Some(current) = source[i..].chars().next() {
+        if current == '\\' {
+            i += 1 + source[i + 1..].chars().next().map_or(0, char::len_utf8);
+        } else if current == '`' {
+            let run = run_length(&source[i..], '`');
+            i = closing_backticks(source, i + run, run).map_or(i + run, |end| end + run);
+        } else if current == character {
+            let run = run_length(&source[i..], character);
+            let previous = source[..i].chars().next_back();
+            let next = source[i + run..].chars().next();
+            let closes = run == length
+                && i > from
+                && previous.is_some_and(|previous| !previous.is_whitespace())
+                && !(character == '_' && next.is_some_and(char::is_alphanumeric));
+            if closes {
+                return Some(i);
+            }
+            i += run;
+        } else {
+            i += current.len_utf8();
+        }
+    }
+    None
+}
+
+/// Parses a link at `i`, returning the end of its label, its URL and its end.
+fn link(source: &str, i: usize) -> Option<(usize, &str, usize)> {
+    let mut depth = 0;
+    let mut label_end = None;
+    let mut j = i;
+    while let Some(current) = source[j..].chars().next() {
+        match current {
+            '\\' => j += source[j + 1..].chars().next().map_or(0, char::len_utf8),
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    label_end = Some(j);
+                    break;
+                }
+            }
+            _ => {}
+        }
+        j += current.len_utf8();
+    }
+    let label_end = label_end?;
+
+    let destination = source[label_end + 1..].strip_prefix('(')?;
+    let close = destination.find(')')?;
+    // An optional title after the URL is ignored.
+    let url = destination[..close]
+        .split_whitespace()
+        .next()
+        .unwrap_or_default();
+    let url = url
+        .strip_prefix('<')
+        .and_then(|url| url.strip_suffix('>'))
+        .unwrap_or(url);
+    Some((label_end, url, label_end + 2 + close + 1))
+}
+
+/// Returns the URL of an autolink such as `<https://waterui.dev>`.
+fn autolink(rest: &str) -> Option<&str> {
+    let end = rest.find('>')?;
+    let url = &rest[1..end];
+    let valid = !url.contains(char::is_whitespace)
+        && !url.contains('<')
+        && (url.contains("://") || url.starts_with("mailto:"));
+    valid.then_some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::{Block, parse, parse_inline};
+    use crate::attributed::{Attribute, AttributedStr};
+
+    /// Builds an attributed string from spans.
+    fn spans<const N: usize>(spans: [(&'static str, Vec<Attribute>); N]) -> AttributedStr {
+        let mut string = AttributedStr::new();
+        for (text, attributes) in spans {
+            string.push(text, attributes);
+        }
+        string
+    }
+
+    fn paragraph(text: &'static str) -> Block {
+        Block::Paragraph(text.into())
+    }
+
+    #[test]
+    fn emphasis() {
+        assert_eq!(
+            parse_inline("**bold**, *italic* and __also__ _both_"),
+            spans([
+                ("bold", vec![Attribute::Bold]),
+                (", ", vec![]),
+                ("italic", vec![Attribute::Italic]),
+                (" and ", vec![]),
+                ("also", vec![Attribute::Bold]),
+                (" ", vec![]),
+                ("both", vec![Attribute::Italic]),
+            ])
+        );
+        assert_eq!(
+            parse_inline("**bold _and italic_**"),
+            spans([
+                ("bold ", vec![Attribute::Bold]),
+                ("and italic", vec![Attribute::Bold, Attribute::Italic]),
+            ])
+        );
+        assert_eq!(
+            parse_inline("~~gone~~"),
+            spans([("gone", vec![Attribute::Strikethrough])])
+        );
+    }
+
+    #[test]
+    fn delimiters_without_a_match_are_text() {
+        assert_eq!(parse_inline("2 * 3 * 4"), "2 * 3 * 4".into());
+        assert_eq!(parse_inline("snake_case_name"), "snake_case_name".into());
+        assert_eq!(parse_inline("**unclosed"), "**unclosed".into());
+        assert_eq!(parse_inline("~single~"), "~single~".into());
+    }
+
+    #[test]
+    fn code_spans() {
+        assert_eq!(
+            parse_inline("run `cargo **test**`"),
+            spans([("run ", vec![]), ("cargo **test**", vec![Attribute::Code]),])
+        );
+        assert_eq!(
+            parse_inline("``a ` b``"),
+            spans([("a ` b", vec![Attribute::Code])])
+        );
+        assert_eq!(
+            parse_inline("*`code` in italic*"),
+            spans([
+                ("code", vec![Attribute::Italic, Attribute::Code]),
+                (" in italic", vec![Attribute::Italic]),
+            ])
+        );
+        assert_eq!(parse_inline("`unclosed"), "`unclosed".into());
+    }
+
+    #[test]
+    fn links() {
+        let link = |url: &str| Attribute::Link(alloc::string::String::from(url).into());
+        assert_eq!(
+            parse_inline("see [the **guide**](https://waterui.dev \"Title\")"),
+            spans([
+                ("see ", vec![]),
+                ("the ", vec![link("https://waterui.dev")]),
+                ("guide", vec![link("https://waterui.dev"), Attribute::Bold]),
+            ])
+        );
+        assert_eq!(
+            parse_inline("<https://waterui.dev>"),
+            spans([("https://waterui.dev", vec![link("https://waterui.dev")])])
+        );
+        assert_eq!(parse_inline("[no destination]"), "[no destination]".into());
+        assert_eq!(parse_inline("a <b> c"), "a <b> c".into());
+    }
+
+    #[test]
+    fn escapes_and_line_breaks() {
+        assert_eq!(parse_inline("\\*not italic\\*"), "*not italic*".into());
+        assert_eq!(parse_inline("C:\\path"), "C:\\path".into());
+        assert_eq!(parse_inline("soft\nbreak"), "soft break".into());
+        assert_eq!(parse_inline("hard  \nbreak"), "hard\nbreak".into());
+        assert_eq!(parse_inline("hard\\\nbreak"), "hard\nbreak".into());
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            parse("# Title #\n###### Six\n####### Seven\n#NoSpace"),
+            [
+                Block::Heading {
+                    level: 1,
+                    content: "Title".into(),
+                },
+                Block::Heading {
+                    level: 6,
+                    content: "Six".into(),
+                },
+                paragraph("####### Seven #NoSpace"),
+            ]
+        );
+    }
+
+    #[test]
+    fn paragraphs_and_rules() {
+        assert_eq!(
+            parse("one\nline\n\ntwo\n---\n* * *"),
+            [
+                paragraph("one line"),
+                paragraph("two"),
+                Block::Rule,
+                Block::Rule,
+            ]
+        );
+    }
+
+    #[test]
+    fn lists() {
+        assert_eq!(
+            parse("- a\n- b\n  - nested\n\n3. three\n4. four"),
+            [
+                Block::List {
+                    start: None,
+                    items: vec![
+                        vec![paragraph("a")],
+                        vec![
+                            paragraph("b"),
+                            Block::List {
+                                start: None,
+                                items: vec![vec![paragraph("nested")]],
+                            },
+                        ],
+                    ],
+                },
+                Block::List {
+                    start: Some(3),
+                    items: vec![vec![paragraph("three")], vec![paragraph("four")]],
+                },
+            ]
+        );
+        // A lazy continuation line joins the paragraph of the last item.
+        assert_eq!(
+            parse("- a\nlazy"),
+            [Block::List {
+                start: None,
+                items: vec![vec![paragraph("a lazy")]],
+            }]
+        );
+    }
+
+    #[test]
+    fn quotes() {
+        assert_eq!(
+            parse("> # Quoted\n> text\n>> nested"),
+            [Block::Quote(vec![
+                Block::Heading {
+                    level: 1,
+                    content: "Quoted".into(),
+                },
+                paragraph("text"),
+                Block::Quote(vec![paragraph("nested")]),
+            ])]
+        );
+    }
+
+    #[test]
+    fn fenced_code() {
+        assert_eq!(
+            parse("  ```rust ignore\n  let a = 1;\n    indented\n  ```\nafter"),
+            [
+                Block::CodeBlock {
+                    language: Some("rust".into()),
+                    code: "let a = 1;\n  indented".into(),
+                },
+                paragraph("after"),
+            ]
+        );
+        // A fence is closed by a longer fence of the same character, or by the end.
+        assert_eq!(
+            parse("~~~\n```\n~~~~\n```\nunclosed"),
+            [
+                Block::CodeBlock {
+                    language: None,
+                    code: "```".into(),
+                },
+                Block::CodeBlock {
+                    language: None,
+                    code: "unclosed".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn only_spaces_and_tabs_indent() {
+        assert_eq!(
+            parse("- a\n\u{3000}b"),
+            [Block::List {
+                start: None,
+                items: vec![vec![paragraph("a \u{3000}b")]],
+            }]
+        );
+        assert_eq!(
+            parse("  ```\n\u{3000}x\n```"),
+            [Block::CodeBlock {
+                language: None,
+                code: "\u{3000}x".into(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Checks of accessibility trees for common mistakes.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+use super::{AccessibilityNode, AccessibilityRole, AccessibilityTraits, AccessibilityTree};
+
+/// A kind of accessibility issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueKind {
+    /// An interactive element has no label, like a button showing only an icon.
+    MissingLabel,
+    /// An image has no label, while not being hidden as decorative.
+    UnlabeledImage,
+    /// An adjustable element, such as a slider, has no value.
+    MissingValue,
+    /// The label repeats the role, like "Play button", which is announced anyway.
+    RedundantRole,
+}
+
+impl Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingLabel => "interactive element without label",
+            Self::UnlabeledImage => "image without label, hide it if it is decorative",
+            Self::MissingValue => "adjustable element without value",
+            Self::RedundantRole => "label repeating the role of the element",
+        })
+    }
+}
+
+/// An accessibility issue found in an [`AccessibilityTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibilityIssue {
+    /// The kind of issue.
+    pub kind: IssueKind,
+    /// The role of the element.
+    pub role: AccessibilityRole,
+    /// The type name of the view describing the element.
+    pub view: &'static str,
+    /// The indices of the element and of its ancestors, from the top of the tree.
+    pub path: Vec<usize>,
+}
+
+impl Display for AccessibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {:?} ({}): {}",
+            self.role, self.path, self.view, self.kind
+        )
+    }
+}
+
+impl core::error::Error for AccessibilityIssue {}
+
+impl AccessibilityTree {
+    /// Checks the tree for common mistakes, returning the issues found in reading order.
+    ///
+    /// Run it in tests to catch regressions, such as an icon-only button without label.
+    pub fn lint(&self) -> Vec<AccessibilityIssue> {
+        let mut issues = Vec::new();
+        let mut path = Vec::new();
+        lint_nodes(self.nodes(), &mut path, &mut issues);
+        issues
+    }
+}
+
+fn lint_nodes(
+    nodes: &[AccessibilityNode],
+    path: &mut Vec<usize>,
+    issues: &mut Vec<AccessibilityIssue>,
+) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+        for kind in check(node) {
+            issues.push(AccessibilityIssue {
+                kind,
+                role: node.role,
+                view: node.view,
+                path: path.clone(),
+            });
+        }
+        lint_nodes(&node.children, path, issues);
+        path.pop();
+    }
+}
+
+fn check(node: &AccessibilityNode) -> impl Iterator<Item = IssueKind> {
+    let label = node.label.as_deref().map(str::trim).unwrap_or_default();
+    let unlabeled = label.is_empty();
+    let missing_label = unlabeled && node.role.is_interactive();
+    let unlabeled_image = unlabeled && node.role == AccessibilityRole::Image;
+    let missing_value = node.traits.contains(AccessibilityTraits::ADJUSTABLE)
+        && node.value.as_deref().is_none_or(str::is_empty);
+    let role = node.role.name();
+    let redundant_role = node.role != AccessibilityRole::Group
+        && node.role != AccessibilityRole::Text
+        && label.len() > role.len()
+        && label
+            .get(..label.len() - role.len())
+            .zip(label.get(label.len() - role.len()..))
+            .is_some_and(|(start, end)| start.ends_with(' ') && end.eq_ignore_ascii_case(role));
+
+    [
+        (missing_label, IssueKind::MissingLabel),
+        (unlabeled_image, IssueKind::UnlabeledImage),
+        (missing_value, IssueKind::MissingValue),
+        (redundant_role, IssueKind::RedundantRole),
+    ]
+    .into_iter()
+    .filter_map(|(found, kind)| found.then_some(kind))
+}
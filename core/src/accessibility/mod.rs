@@ -0,0 +1,424 @@
+//! # Accessibility
+//!
+//! Descriptions of views for assistive technologies, such as screen readers.
+//!
+//! A view is described by an [`Accessibility`]: its label, hint, value, role and traits,
+//! whether it is hidden, and its sort priority among its siblings. Descriptions are
+//! attached with the accessibility modifiers of `ViewExt`, as an
+//! `IgnorableMetadata<Accessibility>`, so renderers without accessibility support can
+//! skip them. Components provide their default description through [`Accessible`].
+//!
+//! An [`AccessibilityTree`] derives what assistive technologies see from a view, without
+//! any renderer, and [`AccessibilityTree::lint`] checks it for common mistakes:
+//!
+//! ```rust
+//! use waterui_core::{Environment, Str, accessibility::AccessibilityTree};
+//!
+//! // `waterui::accessibility::AccessibilityPlugin` adds inspectors for the built-in views.
+//! let env = Environment::new();
+//! let tree = AccessibilityTree::new(Str::from("Welcome"), &env);
+//! for issue in tree.lint() {
+//!     panic!("{issue}");
+//! }
+//! ```
+
+mod lint;
+mod tree;
+
+pub use lint::{AccessibilityIssue, IssueKind};
+pub use tree::{AccessibilityInspectors, AccessibilityNode, AccessibilityTree, Inspection};
+#[doc(hidden)]
+pub use tree::{Inspecting, Opaque};
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Display},
+    ops::{BitOr, BitOrAssign},
+};
+
+use waterui_reactive::{compute::IntoComputed, Compute, Computed};
+
+use crate::{AnyView, Str, View};
+
+/// What an element is, announced by assistive technologies along with its label.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AccessibilityRole {
+    /// A group of elements, without a role of its own.
+    #[default]
+    Group,
+    /// Static text.
+    Text,
+    /// A heading, dividing content into sections.
+    Header,
+    /// An image.
+    Image,
+    /// A button, triggering an action.
+    Button,
+    /// A link, opening some content.
+    Link,
+    /// A switch between on and off.
+    Toggle,
+    /// A control picking a value from a continuous range.
+    Slider,
+    /// A control incrementing or decrementing a value.
+    Stepper,
+    /// An indicator of the progress of a task.
+    ProgressIndicator,
+    /// An editable text field.
+    TextField,
+}
+
+impl AccessibilityRole {
+    /// Returns whether users interact with elements of this role.
+    pub const fn is_interactive(&self) -> bool {
+        matches!(
+            self,
+            Self::Button
+                | Self::Link
+                | Self::Toggle
+                | Self::Slider
+                | Self::Stepper
+                | Self::TextField
+        )
+    }
+
+    /// Returns the name of the role, as announced by assistive technologies.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Group => "group",
+            Self::Text => "text",
+            Self::Header => "heading",
+            Self::Image => "image",
+            Self::Button => "button",
+            Self::Link => "link",
+            Self::Toggle => "switch",
+            Self::Slider => "slider",
+            Self::Stepper => "stepper",
+            Self::ProgressIndicator => "progress indicator",
+            Self::TextField => "text field",
+        }
+    }
+}
+
+impl Display for AccessibilityRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A set of states and behaviors of an element, combined with `|`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AccessibilityTraits(u8);
+
+impl AccessibilityTraits {
+    /// No trait.
+    pub const NONE: Self = Self(0);
+    /// The element is selected, or switched on.
+    pub const SELECTED: Self = Self(1);
+    /// The element can't be interacted with.
+    pub const DISABLED: Self = Self(1 << 1);
+    /// The element is busy, for instance loading.
+    pub const BUSY: Self = Self(1 << 2);
+    /// The value of the element can be incremented and decremented.
+    pub const ADJUSTABLE: Self = Self(1 << 3);
+    /// The value of the element changes often, and isn't announced on every change.
+    pub const UPDATES_FREQUENTLY: Self = Self(1 << 4);
+
+    /// Returns whether every trait of `other` is in this set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the traits of both sets.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns whether the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for AccessibilityTraits {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for AccessibilityTraits {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/// The accessibility description of a view.
+///
+/// Every property is optional: properties left unset keep the default of the view they
+/// describe, and traits add up to those of the view.
+#[derive(Debug, Clone, Default)]
+pub struct Accessibility {
+    /// A short name of the element, such as "Play" for a button showing a play icon.
+    pub label: Option<Computed<Str>>,
+    /// The result of interacting with the element, such as "Plays the song".
+    pub hint: Option<Computed<Str>>,
+    /// The current value of the element, such as "50%" for a slider.
+    pub value: Option<Computed<Str>>,
+    /// What the element is.
+    pub role: Option<AccessibilityRole>,
+    /// The states and behaviors of the element.
+    pub traits: AccessibilityTraits,
+    /// Whether the element and its content are hidden from assistive technologies.
+    pub hidden: Option<Computed<bool>>,
+    /// The priority of the element in the reading order of its siblings, higher first.
+    /// Elements default to 0, and keep their order among equal priorities.
+    pub sort_priority: Option<f64>,
+}
+
+impl Accessibility {
+    /// Creates an empty description, keeping every default of the view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the label.
+    pub fn label(mut self, label: impl IntoComputed<Str>) -> Self {
+        self.label = Some(label.into_computed());
+        self
+    }
+
+    /// Sets the hint.
+    pub fn hint(mut self, hint: impl IntoComputed<Str>) -> Self {
+        self.hint = Some(hint.into_computed());
+        self
+    }
+
+    /// Sets the value.
+    pub fn value(mut self, value: impl IntoComputed<Str>) -> Self {
+        self.value = Some(value.into_computed());
+        self
+    }
+
+    /// Sets the role.
+    pub fn role(mut self, role: AccessibilityRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Adds traits.
+    pub fn traits(mut self, traits: AccessibilityTraits) -> Self {
+        self.traits |= traits;
+        self
+    }
+
+    /// Sets whether the element is hidden.
+    pub fn hidden(mut self, hidden: impl IntoComputed<bool>) -> Self {
+        self.hidden = Some(hidden.into_computed());
+        self
+    }
+
+    /// Sets the sort priority.
+    pub fn sort_priority(mut self, priority: f64) -> Self {
+        self.sort_priority = Some(priority);
+        self
+    }
+
+    /// Returns whether the element is currently hidden.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden.as_ref().is_some_and(Compute::compute)
+    }
+}
+
+/// An element exposed to assistive technologies, as described by a view.
+#[derive(Debug, Default)]
+pub struct AccessibilityElement {
+    /// The description of the element.
+    pub accessibility: Accessibility,
+    /// The views shown by the element, such as the label of a button. Their text labels
+    /// the element unless it has a label of its own.
+    pub contents: Vec<AnyView>,
+}
+
+impl AccessibilityElement {
+    /// Creates an element with the given description.
+    pub fn new(accessibility: Accessibility) -> Self {
+        Self {
+            accessibility,
+            contents: Vec::new(),
+        }
+    }
+
+    /// Adds a view shown by the element.
+    pub fn content(mut self, content: impl View) -> Self {
+        self.contents.push(AnyView::new(content));
+        self
+    }
+}
+
+/// A view configuration with a default accessibility description.
+///
+/// Components implement it on their configuration, and are registered with
+/// [`AccessibilityInspectors::accessible`] to appear in accessibility trees.
+pub trait Accessible {
+    /// Returns the element described by this configuration.
+    fn accessibility_element(self) -> AccessibilityElement;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::{
+        Accessibility, AccessibilityElement, AccessibilityInspectors, AccessibilityRole,
+        AccessibilityTraits, AccessibilityTree, Inspection, IssueKind,
+    };
+    use crate::{
+        components::{IgnorableMetadata, Native},
+        shape::Circle,
+        AnyView, Environment, Str, View,
+    };
+
+    struct Group(Vec<AnyView>);
+    raw_view!(Group);
+
+    configurable!(Map, Str, "A map, shown by renderers.");
+
+    struct Button {
+        label: AnyView,
+        disabled: bool,
+    }
+    raw_view!(Button);
+
+    fn button(label: impl View) -> Button {
+        Button {
+            label: AnyView::new(label),
+            disabled: false,
+        }
+    }
+
+    fn env() -> Environment {
+        let mut inspectors = AccessibilityInspectors::new();
+        inspectors.contents(|group: Group| group.0);
+        inspectors.insert(|button: Button, _env: &Environment| {
+            let traits = if button.disabled {
+                AccessibilityTraits::DISABLED
+            } else {
+                AccessibilityTraits::NONE
+            };
+            Inspection::Element(
+                AccessibilityElement::new(
+                    Accessibility::new()
+                        .role(AccessibilityRole::Button)
+                        .traits(traits),
+                )
+                .content(button.label),
+            )
+        });
+        Environment::new().with(inspectors)
+    }
+
+    fn describe(view: impl View, accessibility: Accessibility) -> AnyView {
+        AnyView::new(IgnorableMetadata::new(view, accessibility))
+    }
+
+    #[test]
+    fn tree() {
+        let tree = AccessibilityTree::new(
+            Group(vec![
+                describe("Footer", Accessibility::new().sort_priority(-1.0)),
+                AnyView::new(button("Play")),
+                describe(
+                    Button {
+                        label: AnyView::new(Circle),
+                        disabled: true,
+                    },
+                    Accessibility::new().label("Stop").hint("Stops the song"),
+                ),
+                describe("Decoration", Accessibility::new().hidden(true)),
+                describe(
+                    Group(vec![AnyView::new("3:20"), AnyView::new("Song")]),
+                    Accessibility::new().value("42%"),
+                ),
+            ]),
+            &env(),
+        );
+
+        let labels: Vec<_> = tree
+            .nodes()
+            .iter()
+            .map(|node| node.label.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(labels, ["Play", "Stop", "3:20, Song", "Footer"]);
+
+        let stop = tree.find("Stop").unwrap();
+        assert_eq!(stop.role, AccessibilityRole::Button);
+        assert_eq!(stop.hint.as_deref(), Some("Stops the song"));
+        assert!(stop.traits.contains(AccessibilityTraits::DISABLED));
+
+        let group = &tree.nodes()[2];
+        assert_eq!(group.role, AccessibilityRole::Group);
+        assert_eq!(group.value.as_deref(), Some("42%"));
+        assert_eq!(group.children.len(), 2);
+        assert_eq!(tree.iter().count(), 6);
+    }
+
+    #[test]
+    fn lint() {
+        let tree = AccessibilityTree::new(
+            Group(vec![
+                AnyView::new(button("Play")),
+                AnyView::new(button(Circle)),
+                AnyView::new(button("Pause button")),
+                describe(
+                    "Volume",
+                    Accessibility::new()
+                        .role(AccessibilityRole::Slider)
+                        .traits(AccessibilityTraits::ADJUSTABLE),
+                ),
+                describe(Circle, Accessibility::new().role(AccessibilityRole::Image)),
+            ]),
+            &env(),
+        );
+
+        let issues: Vec<_> = tree
+            .lint()
+            .into_iter()
+            .map(|issue| (issue.kind, issue.path))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                (IssueKind::MissingLabel, vec![1]),
+                (IssueKind::RedundantRole, vec![2]),
+                (IssueKind::MissingValue, vec![3]),
+                (IssueKind::UnlabeledImage, vec![4]),
+            ]
+        );
+    }
+
+    #[test]
+    fn views_depending_on_a_renderer_are_opaque() {
+        let tree = AccessibilityTree::new(
+            Group(vec![
+                AnyView::new(Map::from(Str::from("Paris"))),
+                AnyView::new(Native(0_u8)),
+                AnyView::new(button("Play")),
+            ]),
+            &env(),
+        );
+
+        let views: Vec<_> = tree.iter().map(|node| node.view).collect();
+        assert_eq!(
+            views,
+            [
+                core::any::type_name::<Map>(),
+                core::any::type_name::<Native<u8>>(),
+                core::any::type_name::<Button>(),
+            ]
+        );
+        assert_eq!(tree.nodes()[0].label, None);
+        assert_eq!(tree.nodes()[1].role, AccessibilityRole::Group);
+    }
+}
@@ -0,0 +1,395 @@
+//! Headless accessibility trees.
+
+use alloc::{collections::BTreeMap, rc::Rc, string::String, vec, vec::Vec};
+use core::{
+    any::TypeId,
+    fmt::{self, Debug},
+};
+
+use waterui_reactive::Compute;
+
+use super::{
+    Accessibility, AccessibilityElement, AccessibilityRole, AccessibilityTraits, Accessible,
+};
+use crate::{
+    animation::Transition,
+    components::{Dynamic, IgnorableMetadata, Metadata},
+    shape::{
+        Capsule, Circle, Ellipse, FilledShape, Path, Polygon, Rectangle, RoundedRectangle, Star,
+        StrokedShape, UnevenRoundedRectangle,
+    },
+    view::ConfigurableView,
+    AnyView, Color, Environment, Str, View,
+};
+
+/// How a view is exposed to assistive technologies, as reported by an inspector.
+#[derive(Debug)]
+pub enum Inspection {
+    /// The view is decorative, and isn't exposed.
+    Hidden,
+    /// The view exposes the given views in its place, like a stack does.
+    Contents(Vec<AnyView>),
+    /// The view is an element.
+    Element(AccessibilityElement),
+}
+
+type InspectFn = dyn Fn(AnyView, &Environment) -> Inspection;
+
+/// The inspectors used to derive accessibility trees, by view type.
+///
+/// Views without an inspector are replaced by their body, so only raw views and
+/// configurable views need one, as they can't be built without a renderer. The default
+/// inspectors cover the views of this crate; component crates register theirs in the
+/// environment.
+///
+/// Content of [`Dynamic`] views is only delivered to renderers, so it doesn't appear in
+/// accessibility trees.
+#[derive(Clone)]
+pub struct AccessibilityInspectors(BTreeMap<TypeId, Rc<InspectFn>>);
+
+impl Debug for AccessibilityInspectors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessibilityInspectors")
+            .field("len", &self.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for AccessibilityInspectors {
+    fn default() -> Self {
+        let mut inspectors = Self(BTreeMap::new());
+        inspectors.insert(|text: Str, _env: &Environment| {
+            Inspection::Element(AccessibilityElement::new(
+                Accessibility::new()
+                    .label(text)
+                    .role(AccessibilityRole::Text),
+            ))
+        });
+        inspectors.contents(|view: IgnorableMetadata<Transition>| vec![view.content]);
+        inspectors.hidden::<()>();
+        inspectors.hidden::<Dynamic>();
+        inspectors.hidden::<Color>();
+        inspectors.hidden::<Rectangle>();
+        inspectors.hidden::<RoundedRectangle>();
+        inspectors.hidden::<UnevenRoundedRectangle>();
+        inspectors.hidden::<Circle>();
+        inspectors.hidden::<Ellipse>();
+        inspectors.hidden::<Capsule>();
+        inspectors.hidden::<Polygon>();
+        inspectors.hidden::<Star>();
+        inspectors.hidden::<Path>();
+        inspectors.hidden::<FilledShape>();
+        inspectors.hidden::<StrokedShape>();
+        inspectors
+    }
+}
+
+impl AccessibilityInspectors {
+    /// Creates the default inspectors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the inspector of views of type `V`.
+    pub fn insert<V: View>(&mut self, inspect: impl Fn(V, &Environment) -> Inspection + 'static) {
+        self.0.insert(
+            TypeId::of::<V>(),
+            Rc::new(
+                move |view: AnyView, env: &Environment| match view.downcast::<V>() {
+                    Ok(view) => inspect(*view, env),
+                    Err(_) => unreachable!("Inspectors are looked up by the type of the view"),
+                },
+            ),
+        );
+    }
+
+    /// Exposes views of type `V` as elements described by their configuration.
+    pub fn accessible<V>(&mut self)
+    where
+        V: ConfigurableView,
+        V::Config: Accessible,
+    {
+        self.insert(|view: V, _env: &Environment| {
+            Inspection::Element(view.config().accessibility_element())
+        });
+    }
+
+    /// Exposes the views returned by `contents` in place of views of type `V`.
+    pub fn contents<V: View>(&mut self, contents: impl Fn(V) -> Vec<AnyView> + 'static) {
+        self.insert(move |view: V, _env: &Environment| Inspection::Contents(contents(view)));
+    }
+
+    /// Hides views of type `V`, which are decorative.
+    pub fn hidden<V: View>(&mut self) {
+        self.insert(|_: V, _env: &Environment| Inspection::Hidden);
+    }
+
+    fn inspect(&self, view: AnyView, env: &Environment) -> Result<Inspection, AnyView> {
+        match self.0.get(&view.type_id()) {
+            Some(inspect) => Ok(inspect(view, env)),
+            None => Err(view),
+        }
+    }
+}
+
+/// Marks the environment of views inspected by [`AccessibilityTree`].
+///
+/// Views depending on a renderer build to an [`Opaque`] view in such an environment,
+/// instead of panicking.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct Inspecting;
+
+/// A view depending on a renderer, without an inspector.
+///
+/// It appears in accessibility trees as an element without a label or contents.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct Opaque {
+    view: &'static str,
+}
+
+impl Opaque {
+    /// Stands in for a view of type `V`.
+    pub fn of<V: 'static>() -> Self {
+        Self {
+            view: core::any::type_name::<V>(),
+        }
+    }
+}
+
+raw_view!(Opaque);
+
+/// An element of an accessibility tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityNode {
+    /// The role of the element.
+    pub role: AccessibilityRole,
+    /// The label of the element.
+    pub label: Option<Str>,
+    /// The hint of the element.
+    pub hint: Option<Str>,
+    /// The value of the element.
+    pub value: Option<Str>,
+    /// The traits of the element.
+    pub traits: AccessibilityTraits,
+    /// The sort priority of the element among its siblings.
+    pub sort_priority: f64,
+    /// The type name of the view describing the element.
+    pub view: &'static str,
+    /// The elements grouped under this one, in reading order.
+    pub children: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityNode {
+    fn new(accessibility: Accessibility, view: &'static str) -> Self {
+        Self {
+            role: accessibility.role.unwrap_or_default(),
+            label: accessibility.label.map(|label| label.compute()),
+            hint: accessibility.hint.map(|hint| hint.compute()),
+            value: accessibility.value.map(|value| value.compute()),
+            traits: accessibility.traits,
+            sort_priority: accessibility.sort_priority.unwrap_or_default(),
+            view,
+            children: Vec::new(),
+        }
+    }
+
+    /// Overrides the properties of this element set by `accessibility`.
+    fn apply(&mut self, accessibility: Accessibility) {
+        if let Some(role) = accessibility.role {
+            self.role = role;
+        }
+        if let Some(label) = accessibility.label {
+            self.label = Some(label.compute());
+        }
+        if let Some(hint) = accessibility.hint {
+            self.hint = Some(hint.compute());
+        }
+        if let Some(value) = accessibility.value {
+            self.value = Some(value.compute());
+        }
+        if let Some(priority) = accessibility.sort_priority {
+            self.sort_priority = priority;
+        }
+        self.traits |= accessibility.traits;
+    }
+
+    /// Returns this element and the elements under it, depth first.
+    pub fn iter(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    fn sort(nodes: &mut [Self]) {
+        nodes.sort_by(|a, b| b.sort_priority.total_cmp(&a.sort_priority));
+        for node in nodes {
+            Self::sort(&mut node.children);
+        }
+    }
+}
+
+/// The elements of a view exposed to assistive technologies, derived without a renderer.
+///
+/// Views are inspected with the [`AccessibilityInspectors`] of the environment, and
+/// their descriptions are applied as follows:
+/// - A description applied to a single element overrides the properties it sets.
+/// - A description with a label, hint, value or role applied to several elements
+///   groups them under a new element.
+/// - Hidden views are left out with their content.
+/// - Elements without a label are labeled by the text they show.
+/// - Configurable and native views without an inspector are elements without a label,
+///   as their contents are only known to renderers.
+///
+/// The tree is a snapshot: reactive properties are read once, when it is built.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityTree {
+    nodes: Vec<AccessibilityNode>,
+}
+
+impl AccessibilityTree {
+    /// Derives the accessibility tree of `view`.
+    pub fn new(view: impl View, env: &Environment) -> Self {
+        let walker = Walker {
+            defaults: AccessibilityInspectors::default(),
+        };
+        let env = env.clone().with(Inspecting);
+        let mut nodes = Vec::new();
+        walker.walk(AnyView::new(view), &env, &mut nodes);
+        AccessibilityNode::sort(&mut nodes);
+        Self { nodes }
+    }
+
+    /// Returns the top-level elements, in reading order.
+    pub fn nodes(&self) -> &[AccessibilityNode] {
+        &self.nodes
+    }
+
+    /// Returns every element, depth first, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        self.nodes.iter().flat_map(AccessibilityNode::iter)
+    }
+
+    /// Returns the first element labeled `label`.
+    pub fn find(&self, label: &str) -> Option<&AccessibilityNode> {
+        self.iter()
+            .find(|node| node.label.as_deref() == Some(label))
+    }
+}
+
+struct Walker {
+    defaults: AccessibilityInspectors,
+}
+
+impl Walker {
+    fn walk(&self, view: AnyView, env: &Environment, nodes: &mut Vec<AccessibilityNode>) {
+        let view = match view.downcast::<IgnorableMetadata<Accessibility>>() {
+            Ok(metadata) => {
+                let IgnorableMetadata { content, value } = *metadata;
+                if !value.is_hidden() {
+                    let name = content.name();
+                    let mut contents = Vec::new();
+                    self.walk(content, env, &mut contents);
+                    nodes.extend(describe(value, contents, name));
+                }
+                return;
+            }
+            Err(view) => view,
+        };
+
+        // Modifiers build their views in an environment of their own.
+        let view = match view.downcast::<Metadata<Environment>>() {
+            Ok(metadata) => return self.walk(metadata.content, &metadata.value, nodes),
+            Err(view) => view,
+        };
+
+        let view = match view.downcast::<Opaque>() {
+            Ok(opaque) => {
+                return nodes.push(AccessibilityNode::new(Accessibility::new(), opaque.view))
+            }
+            Err(view) => view,
+        };
+
+        let name = view.name();
+        let inspectors = env
+            .get::<AccessibilityInspectors>()
+            .unwrap_or(&self.defaults);
+        let inspection = match inspectors.inspect(view, env) {
+            Ok(inspection) => inspection,
+            Err(view) => return self.walk(AnyView::new(view.body(env)), env, nodes),
+        };
+
+        match inspection {
+            Inspection::Hidden => {}
+            Inspection::Contents(contents) => {
+                for content in contents {
+                    self.walk(content, env, nodes);
+                }
+            }
+            Inspection::Element(element) => {
+                if !element.accessibility.is_hidden() {
+                    let mut contents = Vec::new();
+                    for content in element.contents {
+                        self.walk(content, env, &mut contents);
+                    }
+                    let mut node = AccessibilityNode::new(element.accessibility, name);
+                    if node.label.is_none() {
+                        node.label = combined_label(&contents);
+                    }
+                    nodes.push(node);
+                }
+            }
+        }
+    }
+}
+
+/// Applies a description to the elements of a view.
+fn describe(
+    accessibility: Accessibility,
+    mut nodes: Vec<AccessibilityNode>,
+    view: &'static str,
+) -> Vec<AccessibilityNode> {
+    if let [node] = nodes.as_mut_slice() {
+        node.apply(accessibility);
+        return nodes;
+    }
+
+    let groups = accessibility.label.is_some()
+        || accessibility.hint.is_some()
+        || accessibility.value.is_some()
+        || accessibility.role.is_some();
+    if groups {
+        let mut group = AccessibilityNode::new(accessibility, view);
+        if group.label.is_none() {
+            group.label = combined_label(&nodes);
+        }
+        group.children = nodes;
+        vec![group]
+    } else {
+        for node in &mut nodes {
+            node.apply(accessibility.clone());
+        }
+        nodes
+    }
+}
+
+/// Joins the labels of `nodes` and of the elements under them.
+fn combined_label(nodes: &[AccessibilityNode]) -> Option<Str> {
+    let mut label = String::new();
+    for part in nodes
+        .iter()
+        .flat_map(AccessibilityNode::iter)
+        .filter_map(|node| node.label.as_deref())
+        .filter(|part| !part.is_empty())
+    {
+        if !label.is_empty() {
+            label.push_str(", ");
+        }
+        label.push_str(part);
+    }
+    (!label.is_empty()).then(|| label.into())
+}
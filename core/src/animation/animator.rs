@@ -0,0 +1,138 @@
+//! Frame-by-frame interpolation of animated computations.
+
+use alloc::rc::Rc;
+use core::{cell::RefCell, time::Duration};
+
+use waterui_reactive::{
+    watcher::{Metadata, Watcher, WatcherGuard, WatcherManager},
+    Compute,
+};
+
+use super::{Animatable, Animation};
+
+/// An animation in flight.
+#[derive(Debug)]
+struct Tween<T> {
+    from: T,
+    to: T,
+    animation: Animation,
+    start: Duration,
+}
+
+#[derive(Debug)]
+struct State<T> {
+    value: T,
+    tween: Option<Tween<T>>,
+    now: Duration,
+}
+
+/// Drives the animations of a computation, producing interpolated values over time.
+///
+/// When the source changes with an [`Animation`] attached, for instance through
+/// `.animated()`, the animator moves from its current value to the new one each time it
+/// is [ticked](Animator::tick). Changes without an animation apply immediately.
+///
+/// The animator is itself a computation, notifying its watchers with every new frame.
+/// An animation interrupted by another change restarts from the value it had reached.
+#[derive(Debug, Clone)]
+pub struct Animator<T> {
+    state: Rc<RefCell<State<T>>>,
+    watchers: WatcherManager<T>,
+    _guard: Rc<WatcherGuard>,
+}
+
+impl<T: Animatable> Animator<T> {
+    /// Creates an animator following `source`, starting at its current value.
+    pub fn new(source: impl Compute<Output = T>) -> Self {
+        let state = Rc::new(RefCell::new(State {
+            value: source.compute(),
+            tween: None,
+            now: Duration::ZERO,
+        }));
+        let watchers = WatcherManager::new();
+
+        let guard = source.add_watcher({
+            let state = Rc::downgrade(&state);
+            let watchers = watchers.clone();
+            move |value: T, metadata: Metadata| {
+                let Some(state) = state.upgrade() else {
+                    return;
+                };
+                let mut state = state.borrow_mut();
+                if let Some(animation) = metadata.try_get::<Animation>() {
+                    state.tween = Some(Tween {
+                        from: state.value.clone(),
+                        to: value,
+                        animation,
+                        start: state.now,
+                    });
+                } else {
+                    state.tween = None;
+                    state.value = value.clone();
+                    drop(state);
+                    watchers.notify(|| value.clone(), metadata);
+                }
+            }
+        });
+
+        Self {
+            state,
+            watchers,
+            _guard: Rc::new(guard),
+        }
+    }
+
+    /// Advances the animation to `now`, returning the value at that time.
+    ///
+    /// `now` is measured from an arbitrary origin, usually the start of the frame clock,
+    /// and should never go backwards.
+    pub fn tick(&self, now: Duration) -> T {
+        let mut state = self.state.borrow_mut();
+        state.now = now;
+        let Some(tween) = &state.tween else {
+            return state.value.clone();
+        };
+
+        let elapsed = now.saturating_sub(tween.start);
+        let value = if tween.animation.is_finished(elapsed) {
+            let value = tween.to.clone();
+            state.tween = None;
+            value
+        } else {
+            tween
+                .from
+                .interpolate(&tween.to, tween.animation.progress(elapsed))
+        };
+        state.value = value.clone();
+        drop(state);
+
+        self.watchers.notify(|| value.clone(), Metadata::new());
+        value
+    }
+
+    /// Returns whether an animation is in flight.
+    pub fn is_animating(&self) -> bool {
+        self.state.borrow().tween.is_some()
+    }
+
+    /// Returns the value the animation is heading to.
+    pub fn target(&self) -> T {
+        let state = self.state.borrow();
+        state
+            .tween
+            .as_ref()
+            .map_or_else(|| state.value.clone(), |tween| tween.to.clone())
+    }
+}
+
+impl<T: Animatable> Compute for Animator<T> {
+    type Output = T;
+
+    fn compute(&self) -> Self::Output {
+        self.state.borrow().value.clone()
+    }
+
+    fn add_watcher(&self, watcher: impl Watcher<Self::Output>) -> WatcherGuard {
+        WatcherGuard::from_id(&self.watchers, self.watchers.register(watcher))
+    }
+}
@@ -0,0 +1,264 @@
+//! Timing curves and keyframes.
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use libm::fabs;
+
+use super::{Animatable, Animation, Spring};
+
+/// A cubic Bézier timing curve from `(0, 0)` to `(1, 1)`, as in CSS `cubic-bezier()`.
+///
+/// The curve maps the fraction of elapsed time (x) to the progress of the animation (y).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    /// X coordinate of the first control point
+    pub x1: f64,
+    /// Y coordinate of the first control point
+    pub y1: f64,
+    /// X coordinate of the second control point
+    pub x2: f64,
+    /// Y coordinate of the second control point
+    pub y2: f64,
+}
+
+impl CubicBezier {
+    /// Constant velocity.
+    pub const LINEAR: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+    /// The CSS `ease` curve.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// Starts slow and accelerates.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// Starts fast and decelerates.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// Starts and ends slowly.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// Creates a curve from its two control points.
+    ///
+    /// The x coordinates are clamped between 0 and 1 when evaluating, so that the curve
+    /// stays a function of time.
+    pub const fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// Returns the progress at `time`, the fraction of the duration elapsed.
+    pub fn evaluate(&self, time: f64) -> f64 {
+        if time <= 0.0 {
+            return 0.0;
+        }
+        if time >= 1.0 {
+            return 1.0;
+        }
+        let x1 = self.x1.clamp(0.0, 1.0);
+        let x2 = self.x2.clamp(0.0, 1.0);
+        bezier(self.y1, self.y2, solve(x1, x2, time))
+    }
+}
+
+/// Evaluates one coordinate of the curve at parameter `t`.
+fn bezier(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    ((a * t + b) * t + c) * t
+}
+
+fn bezier_slope(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    (3.0 * a * t + 2.0 * b) * t + c
+}
+
+/// Finds the parameter at which the x coordinate of the curve is `x`.
+///
+/// Newton's method converges in a few steps for most curves; bisection takes over when
+/// the slope is too flat.
+fn solve(x1: f64, x2: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-7;
+
+    let mut t = x;
+    for _ in 0..8 {
+        let error = bezier(x1, x2, t) - x;
+        if fabs(error) < EPSILON {
+            return t;
+        }
+        let slope = bezier_slope(x1, x2, t);
+        if fabs(slope) < 1e-6 {
+            break;
+        }
+        t -= error / slope;
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    t = x;
+    for _ in 0..64 {
+        let value = bezier(x1, x2, t);
+        if fabs(value - x) < EPSILON {
+            break;
+        }
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.0;
+    }
+    t
+}
+
+impl Animation {
+    /// The duration of [`Animation::Default`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(250);
+
+    /// Returns how long the animation lasts.
+    ///
+    /// For springs, this is the time they take to settle, see [`Spring::settle_time`].
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Default => Self::DEFAULT_DURATION,
+            Self::Linear(duration)
+            | Self::EaseIn(duration)
+            | Self::EaseOut(duration)
+            | Self::EaseInOut(duration)
+            | Self::CubicBezier { duration, .. } => *duration,
+            Self::Spring { stiffness, damping } => {
+                Spring::new(*stiffness as f64, *damping as f64).settle_time()
+            }
+        }
+    }
+
+    /// Returns the progress of the animation after `elapsed`, from 0 at the start to 1
+    /// once it is finished.
+    ///
+    /// Springs and some Bézier curves overshoot, returning values beyond 1 in between.
+    /// The default animation eases in and out over [`Animation::DEFAULT_DURATION`].
+    pub fn progress(&self, elapsed: Duration) -> f64 {
+        if self.is_finished(elapsed) {
+            return 1.0;
+        }
+        let curve = match self {
+            Self::Default | Self::EaseInOut(_) => CubicBezier::EASE_IN_OUT,
+            Self::Linear(_) => CubicBezier::LINEAR,
+            Self::EaseIn(_) => CubicBezier::EASE_IN,
+            Self::EaseOut(_) => CubicBezier::EASE_OUT,
+            Self::CubicBezier { x1, y1, x2, y2, .. } => {
+                CubicBezier::new(*x1 as f64, *y1 as f64, *x2 as f64, *y2 as f64)
+            }
+            Self::Spring { stiffness, damping } => {
+                return Spring::new(*stiffness as f64, *damping as f64).progress(elapsed);
+            }
+        };
+        curve.evaluate(elapsed.as_secs_f64() / self.duration().as_secs_f64())
+    }
+
+    /// Returns whether the animation is over after `elapsed`.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration()
+    }
+}
+
+/// A value that an animation passes through.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe<T> {
+    /// When the value is reached, as a fraction of the duration between 0 and 1.
+    pub offset: f64,
+    /// The value reached.
+    pub value: T,
+    /// The timing curve leading to this keyframe from the previous one.
+    pub curve: CubicBezier,
+}
+
+/// An animation passing through several values.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use waterui_core::animation::{CubicBezier, Keyframes};
+///
+/// let shake = Keyframes::new(Duration::from_millis(400), 0.0)
+///     .frame(0.25, -10.0)
+///     .frame(0.75, 10.0)
+///     .frame_with(1.0, 0.0, CubicBezier::EASE_OUT);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T> {
+    duration: Duration,
+    frames: Vec<Keyframe<T>>,
+}
+
+impl<T: Animatable> Keyframes<T> {
+    /// Creates keyframes starting from `initial`.
+    pub fn new(duration: impl Into<Duration>, initial: T) -> Self {
+        Self {
+            duration: duration.into(),
+            frames: alloc::vec![Keyframe {
+                offset: 0.0,
+                value: initial,
+                curve: CubicBezier::LINEAR,
+            }],
+        }
+    }
+
+    /// Adds a keyframe reached linearly at `offset`.
+    pub fn frame(self, offset: f64, value: T) -> Self {
+        self.frame_with(offset, value, CubicBezier::LINEAR)
+    }
+
+    /// Adds a keyframe reached at `offset` following `curve`.
+    ///
+    /// Offsets are clamped between 0 and 1, and keyframes may be added in any order.
+    pub fn frame_with(mut self, offset: f64, value: T, curve: CubicBezier) -> Self {
+        let offset = offset.clamp(0.0, 1.0);
+        let index = self.frames.partition_point(|frame| frame.offset <= offset);
+        self.frames.insert(
+            index,
+            Keyframe {
+                offset,
+                value,
+                curve,
+            },
+        );
+        self
+    }
+
+    /// Returns how long the animation lasts.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the keyframes, ordered by offset.
+    pub fn frames(&self) -> &[Keyframe<T>] {
+        &self.frames
+    }
+
+    /// Returns whether the animation is over after `elapsed`.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration
+    }
+
+    /// Returns the value after `elapsed`.
+    ///
+    /// The value holds on the last keyframe once the animation is finished.
+    pub fn sample(&self, elapsed: Duration) -> T {
+        let time = if self.duration.is_zero() {
+            1.0
+        } else {
+            (elapsed.as_secs_f64() / self.duration.as_secs_f64()).min(1.0)
+        };
+
+        let next = self.frames.partition_point(|frame| frame.offset <= time);
+        if next == 0 {
+            return self.frames[0].value.clone();
+        }
+        let Some(to) = self.frames.get(next) else {
+            return self.frames[next - 1].value.clone();
+        };
+        let from = &self.frames[next - 1];
+        let span = to.offset - from.offset;
+        let progress = to.curve.evaluate((time - from.offset) / span);
+        from.value.interpolate(&to.value, progress)
+    }
+}
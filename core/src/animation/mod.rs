@@ -0,0 +1,472 @@
+//! # WaterUI Animation System
+//!
+//! A reactive animation system that seamlessly integrates with WaterUI's reactive state management.
+//!
+//! ## Overview
+//!
+//! The WaterUI animation system leverages the reactive framework to create smooth, declarative
+//! animations that automatically run when reactive values change. By attaching animation metadata
+//! to reactive values through convenient extension methods, the system can intelligently
+//! determine how to animate between different states without requiring explicit animation code.
+//!
+//! ```text
+//! ┌───────────────────┐      ┌───────────────────┐      ┌───────────────────┐
+//! │  Reactive Values  │─────>│ Change Propagation│─────>│  Animation System │
+//! │  (Binding/Compute)│      │ (With Animations) │      │  (Renderer)       │
+//! └───────────────────┘      └───────────────────┘      └───────────────────┘
+//! ```
+//!
+//! ## Core Concepts
+//!
+//! ### Animation Extension Methods
+//!
+//! WaterUI provides convenient extension methods on all `Compute` types to easily attach
+//! animation configurations:
+//!
+//! ```rust
+//! let color = binding(Color::RED);
+//!
+//! // Use the .animated() method to apply default animation
+//! view.background(color.animated());
+//!
+//! // Or specify a specific animation type
+//! view.background(color.with(Animation::ease_in_out(300)));
+//! ```
+//!
+//! ### Animation Types
+//!
+//! The system supports various animation types:
+//!
+//! - **Linear**: Constant velocity from start to finish
+//! - **EaseIn**: Starts slow and accelerates
+//! - **EaseOut**: Starts fast and decelerates
+//! - **EaseInOut**: Combines ease-in and ease-out for natural movement
+//! - **CubicBezier**: A custom timing curve, as in CSS `cubic-bezier()`
+//! - **Spring**: Physics-based animation with configurable stiffness and damping
+//!
+//! ### Integration with UI Components
+//!
+//! UI components automatically respect animation metadata when rendering:
+//!
+//! ```rust
+//! // Three different ways to animate properties:
+//!
+//! // 1. Default animation (uses system defaults)
+//! let view = View::new()
+//!     .background(color.animated());
+//!
+//! // 2. Custom animation using convenience methods
+//! let view = View::new()
+//!     .background(color.with(Animation::ease_in_out(300)));
+//!
+//! // 3. Spring animation using the convenience method
+//! let view = View::new()
+//!     .background(color.with(Animation::spring(100.0, 10.0)));
+//! ```
+//!
+//! ## Animation Pipeline
+//!
+//! 1. **Reactive Setup**: Reactive values are wrapped with animation metadata using extension methods
+//! 2. **State Change**: When the underlying value changes, the animation information is preserved
+//! 3. **Propagation**: The change and animation details are propagated through the reactive system
+//! 4. **Value Interpolation**: The renderer calculates intermediate values based on animation type
+//! 5. **Rendering**: The UI is continuously updated with interpolated values until animation completes
+//!
+//! ## Advanced Features
+//!
+//! ### Animation Choreography
+//!
+//! Complex animations can be created by coordinating multiple animated values:
+//!
+//! ```rust
+//! // Create a choreographed animation sequence
+//! let animated_color = color.with(Animation::ease_in_out(300));
+//!
+//! // Position animates with a spring physics model
+//! let animated_position = position.with(
+//!     Animation::spring(100.0, 10.0)
+//! );
+//!
+//! // Use both animated values in your view
+//! let view = View::new()
+//!     .background(animated_color)
+//!     .position(animated_position);
+//! ```
+//!
+//! ### Composition with Other Reactive Features
+//!
+//! Animation metadata seamlessly composes with other reactive features:
+//!
+//! ```rust
+//! // Combine mapping and animation
+//! let opacity = count
+//!     .map(|n| if n > 5 { 1.0 } else { 0.5 })
+//!     .animated();  // Apply animation to the mapped result
+//!
+//! // Combine multiple reactive values with animation
+//! let combined = value1
+//!     .zip(value2)
+//!     .map(|(a, b)| a + b)
+//!     .with(Animation::ease_in_out(250));
+//! ```
+//!
+//! ## Driving Animations
+//!
+//! Renderers that don't animate natively can use an [`Animator`]: it watches an animated
+//! computation and, on every frame, produces the value interpolated between the previous
+//! and the new state. Any type implementing [`Animatable`] can be animated this way.
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use waterui_core::animation::{Animation, Animator};
+//! use waterui_core::{Binding, ComputeExt};
+//!
+//! let opacity = Binding::container(0.0);
+//! let animation = Animation::linear(Duration::from_millis(300));
+//! let animator = Animator::new(opacity.clone().with(animation));
+//! opacity.set(1.0);
+//!
+//! // On every frame, with the time elapsed since an arbitrary origin:
+//! let value = animator.tick(Duration::from_millis(150));
+//! assert!(value > 0.0 && value < 1.0);
+//! ```
+//!
+//! [`Keyframes`] describe animations that pass through several values.
+//!
+//! ## Transitions
+//!
+//! A [`Transition`] describes how a view enters and leaves when a dynamic view swaps its
+//! content. The renderer plays it with the animation of the change that caused the swap.
+
+mod animator;
+mod curve;
+mod spring;
+mod transition;
+
+pub use animator::Animator;
+pub use curve::{CubicBezier, Keyframe, Keyframes};
+pub use spring::Spring;
+pub use transition::{Transition, TransitionEdge, TransitionEffect, TransitionPhase};
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::Color;
+
+/// An enumeration representing different types of animations
+///
+/// This enum provides various animation types for UI elements or graphics:
+/// - Linear: Constant speed from start to finish
+/// - EaseIn: Starts slow and accelerates
+/// - EaseOut: Starts fast and decelerates
+/// - EaseInOut: Starts and ends slowly with acceleration in the middle
+/// - CubicBezier: Follows a custom timing curve
+/// - Spring: Physics-based movement with configurable stiffness and damping
+///
+/// Each animation type (except Spring) takes a Duration parameter that specifies
+/// how long the animation should take to complete.
+#[derive(Debug, Default, Clone, PartialEq, uniffi::Enum)]
+pub enum Animation {
+    /// Default animation behavior (uses system defaults)
+    #[default]
+    Default,
+    /// Linear animation with constant velocity
+    Linear(Duration),
+    /// Ease-in animation that starts slow and accelerates
+    EaseIn(Duration),
+    /// Ease-out animation that starts fast and decelerates
+    EaseOut(Duration),
+    /// Ease-in-out animation that starts and ends slowly with acceleration in the middle
+    EaseInOut(Duration),
+    /// Animation following a cubic Bézier timing curve, as in CSS `cubic-bezier()`
+    CubicBezier {
+        /// How long the animation takes to complete
+        duration: Duration,
+        /// X coordinate of the first control point, between 0 and 1
+        x1: f32,
+        /// Y coordinate of the first control point
+        y1: f32,
+        /// X coordinate of the second control point, between 0 and 1
+        x2: f32,
+        /// Y coordinate of the second control point
+        y2: f32,
+    },
+    /// Spring animation with physics-based movement
+    Spring {
+        /// Stiffness of the spring (higher values create faster animations)
+        stiffness: f32,
+        /// Damping factor to control oscillation (higher values reduce bouncing)
+        damping: f32,
+    },
+}
+
+impl Animation {
+    /// Creates a new Linear animation with the specified duration
+    ///
+    /// This is an ergonomic constructor that accepts any type that can be converted
+    /// into a Duration (such as u64 milliseconds, etc.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let animation = Animation::linear(300); // 300ms
+    /// let animation = Animation::linear(Duration::from_secs(1)); // 1 second
+    /// ```
+    pub fn linear(duration: impl Into<Duration>) -> Self {
+        Animation::Linear(duration.into())
+    }
+
+    /// Creates a new EaseIn animation with the specified duration
+    ///
+    /// This is an ergonomic constructor that accepts any type that can be converted
+    /// into a Duration (such as u64 milliseconds, etc.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let animation = Animation::ease_in(300); // 300ms
+    /// let animation = Animation::ease_in(Duration::from_secs(1)); // 1 second
+    /// ```
+    pub fn ease_in(duration: impl Into<Duration>) -> Self {
+        Animation::EaseIn(duration.into())
+    }
+
+    /// Creates a new EaseOut animation with the specified duration
+    ///
+    /// This is an ergonomic constructor that accepts any type that can be converted
+    /// into a Duration (such as u64 milliseconds, etc.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let animation = Animation::ease_out(300); // 300ms
+    /// let animation = Animation::ease_out(Duration::from_secs(1)); // 1 second
+    /// ```
+    pub fn ease_out(duration: impl Into<Duration>) -> Self {
+        Animation::EaseOut(duration.into())
+    }
+
+    /// Creates a new EaseInOut animation with the specified duration
+    ///
+    /// This is an ergonomic constructor that accepts any type that can be converted
+    /// into a Duration (such as u64 milliseconds, etc.)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let animation = Animation::ease_in_out(300); // 300ms
+    /// let animation = Animation::ease_in_out(Duration::from_secs(1)); // 1 second
+    /// ```
+    pub fn ease_in_out(duration: impl Into<Duration>) -> Self {
+        Animation::EaseInOut(duration.into())
+    }
+
+    /// Creates a new Spring animation with the specified stiffness and damping
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let animation = Animation::spring(100.0, 10.0);
+    /// ```
+    pub fn spring(stiffness: f32, damping: f32) -> Self {
+        Animation::Spring { stiffness, damping }
+    }
+
+    /// Creates a new CubicBezier animation with the specified duration and control points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// # use waterui_core::animation::Animation;
+    /// // The CSS `ease` timing function
+    /// let animation = Animation::cubic_bezier(Duration::from_millis(300), 0.25, 0.1, 0.25, 1.0);
+    /// ```
+    pub fn cubic_bezier(duration: impl Into<Duration>, x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Animation::CubicBezier {
+            duration: duration.into(),
+            x1,
+            y1,
+            x2,
+            y2,
+        }
+    }
+}
+
+/// A value that can be smoothly interpolated between two states.
+///
+/// Implementations should extrapolate linearly when `progress` leaves the 0 to 1 range,
+/// so that springs can overshoot their target.
+pub trait Animatable: Clone + 'static {
+    /// Returns the value at `progress` between `self` (at 0) and `to` (at 1).
+    fn interpolate(&self, to: &Self, progress: f64) -> Self;
+}
+
+impl Animatable for f64 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress
+    }
+}
+
+impl Animatable for f32 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress as f32
+    }
+}
+
+impl Animatable for Color {
+    /// Interpolates in Oklab, see [`Color::mix`].
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self.mix(to, progress as f32)
+    }
+}
+
+impl<T: Animatable, const N: usize> Animatable for [T; N] {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        core::array::from_fn(|i| self[i].interpolate(&to[i], progress))
+    }
+}
+
+impl<T: Animatable> Animatable for Vec<T> {
+    /// Interpolates element by element.
+    ///
+    /// Vectors of different lengths can't be interpolated: the result jumps from one
+    /// vector to the other halfway through.
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        if self.len() != to.len() {
+            return if progress < 0.5 {
+                self.clone()
+            } else {
+                to.clone()
+            };
+        }
+        self.iter()
+            .zip(to)
+            .map(|(from, to)| from.interpolate(to, progress))
+            .collect()
+    }
+}
+
+macro_rules! impl_animatable_for_tuple {
+    ($($index:tt $ty:ident),*) => {
+        impl<$($ty: Animatable,)*> Animatable for ($($ty,)*) {
+            fn interpolate(&self, to: &Self, progress: f64) -> Self {
+                ($(self.$index.interpolate(&to.$index, progress),)*)
+            }
+        }
+    };
+}
+
+impl_animatable_for_tuple!(0 T0, 1 T1);
+impl_animatable_for_tuple!(0 T0, 1 T1, 2 T2);
+impl_animatable_for_tuple!(0 T0, 1 T1, 2 T2, 3 T3);
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use waterui_reactive::{binding, Compute, ComputeExt};
+
+    use super::{
+        Animation, Animator, CubicBezier, Keyframes, Spring, Transition, TransitionEdge,
+        TransitionEffect, TransitionPhase,
+    };
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn curves() {
+        assert!(close(CubicBezier::LINEAR.evaluate(0.3), 0.3));
+        assert!(close(CubicBezier::EASE_IN_OUT.evaluate(0.5), 0.5));
+        assert!(CubicBezier::EASE_IN.evaluate(0.25) < 0.25);
+        assert!(CubicBezier::EASE_OUT.evaluate(0.25) > 0.25);
+
+        let animation = Animation::linear(Duration::from_millis(200));
+        assert!(close(animation.progress(Duration::from_millis(50)), 0.25));
+        assert_eq!(animation.progress(Duration::from_millis(300)), 1.0);
+        assert_eq!(Animation::Default.duration(), Animation::DEFAULT_DURATION);
+    }
+
+    #[test]
+    fn springs_settle() {
+        let bouncy = Spring::new(100.0, 5.0);
+        let settle = bouncy.settle_time();
+        assert!(settle > Duration::ZERO && settle < Spring::MAX_SETTLE_TIME);
+        assert!(bouncy.displacement(settle.as_secs_f64()).abs() <= Spring::SETTLE_THRESHOLD);
+        // An underdamped spring overshoots its target.
+        assert!((0..100).any(|ms| bouncy.progress(Duration::from_millis(ms * 10)) > 1.0));
+
+        for spring in [Spring::new(100.0, 20.0), Spring::new(100.0, 40.0)] {
+            let settle = spring.settle_time().as_secs_f64();
+            assert!(close(spring.displacement(settle), Spring::SETTLE_THRESHOLD));
+            assert!(spring.progress(Duration::from_millis(100)) <= 1.0);
+        }
+
+        assert_eq!(
+            Spring::new(100.0, 0.0).settle_time(),
+            Spring::MAX_SETTLE_TIME
+        );
+    }
+
+    #[test]
+    fn keyframes() {
+        let keyframes = Keyframes::new(Duration::from_secs(1), 0.0)
+            .frame(1.0, 0.0)
+            .frame(0.5, 10.0);
+        assert!(close(keyframes.sample(Duration::from_millis(250)), 5.0));
+        assert!(close(keyframes.sample(Duration::from_millis(500)), 10.0));
+        assert!(close(keyframes.sample(Duration::from_millis(750)), 5.0));
+        assert!(close(keyframes.sample(Duration::from_secs(2)), 0.0));
+    }
+
+    #[test]
+    fn animator_interpolates_animated_changes() {
+        let source = binding(0.0_f64);
+        let animator = Animator::new(
+            source
+                .clone()
+                .with(Animation::linear(Duration::from_secs(1))),
+        );
+
+        source.set(10.0);
+        assert!(animator.is_animating());
+        assert!(close(animator.tick(Duration::from_millis(500)), 5.0));
+        assert!(close(animator.compute(), 5.0));
+        assert!(close(animator.target(), 10.0));
+        assert!(close(animator.tick(Duration::from_millis(1500)), 10.0));
+        assert!(!animator.is_animating());
+
+        let immediate = Animator::new(source.clone());
+        source.set(3.0);
+        assert!(!immediate.is_animating());
+        assert!(close(immediate.compute(), 3.0));
+    }
+
+    #[test]
+    fn transition_effects() {
+        let transition = Transition::asymmetric(
+            Transition::Opacity.combined(Transition::Slide(TransitionEdge::Leading)),
+            Transition::scale(0.5),
+        );
+
+        let insertion = transition.effect(TransitionPhase::Insertion, 0.25);
+        assert!(close(insertion.opacity, 0.25));
+        assert!(close(insertion.relative_offset[0], -0.75));
+        assert!(close(insertion.scale, 1.0));
+
+        let removal = transition.effect(TransitionPhase::Removal, 0.0);
+        assert!(close(removal.scale, 0.5));
+        assert!(close(removal.opacity, 1.0));
+
+        assert_eq!(
+            transition.effect(TransitionPhase::Removal, 1.0),
+            TransitionEffect::IDENTITY
+        );
+        assert_eq!(
+            transition.phase(TransitionPhase::Removal),
+            Transition::Scale(0.5)
+        );
+    }
+}
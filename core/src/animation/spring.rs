@@ -0,0 +1,125 @@
+//! A damped spring solver.
+
+use core::time::Duration;
+
+use libm::{cos, exp, fabs, log, sin, sqrt};
+
+/// A damped harmonic oscillator, used to drive spring animations.
+///
+/// The spring starts at rest at the initial value and is pulled towards the target; its
+/// [`displacement`](Spring::displacement) is the remaining fraction of the distance, which
+/// oscillates around zero when the spring is underdamped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls towards its target.
+    pub stiffness: f64,
+    /// How strongly the motion is slowed down.
+    pub damping: f64,
+    /// The mass attached to the spring.
+    pub mass: f64,
+}
+
+impl Spring {
+    /// The displacement below which a spring is considered at rest.
+    pub const SETTLE_THRESHOLD: f64 = 0.001;
+    /// The longest a spring may take to settle, for springs that barely lose energy.
+    pub const MAX_SETTLE_TIME: Duration = Duration::from_secs(10);
+
+    /// Creates a spring with a mass of 1.
+    pub const fn new(stiffness: f64, damping: f64) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Sets the mass attached to the spring.
+    pub const fn mass(mut self, mass: f64) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Returns the undamped angular frequency, in radians per second.
+    pub fn natural_frequency(&self) -> f64 {
+        sqrt(self.stiffness / self.mass)
+    }
+
+    /// Returns the damping ratio: below 1 the spring oscillates, at 1 it is critically
+    /// damped, and above 1 it slowly creeps towards its target.
+    pub fn damping_ratio(&self) -> f64 {
+        self.damping / (2.0 * sqrt(self.stiffness * self.mass))
+    }
+
+    /// Returns whether the spring can move at all.
+    fn is_valid(&self) -> bool {
+        self.stiffness > 0.0 && self.mass > 0.0 && self.damping >= 0.0
+    }
+
+    /// Returns the remaining fraction of the distance to the target after `time` seconds,
+    /// starting at 1.
+    pub fn displacement(&self, time: f64) -> f64 {
+        if !self.is_valid() {
+            return 0.0;
+        }
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+
+        if fabs(zeta - 1.0) < 1e-6 {
+            exp(-omega * time) * (1.0 + omega * time)
+        } else if zeta < 1.0 {
+            let damped = omega * sqrt(1.0 - zeta * zeta);
+            exp(-zeta * omega * time)
+                * (cos(damped * time) + zeta * omega / damped * sin(damped * time))
+        } else {
+            let root = sqrt(zeta * zeta - 1.0);
+            let slow = -omega * (zeta - root);
+            let fast = -omega * (zeta + root);
+            (fast * exp(slow * time) - slow * exp(fast * time)) / (fast - slow)
+        }
+    }
+
+    /// Returns the progress towards the target after `elapsed`, which overshoots 1 when
+    /// the spring oscillates.
+    pub fn progress(&self, elapsed: Duration) -> f64 {
+        1.0 - self.displacement(elapsed.as_secs_f64())
+    }
+
+    /// Returns the time after which the spring stays within
+    /// [`SETTLE_THRESHOLD`](Self::SETTLE_THRESHOLD) of its target, capped at
+    /// [`MAX_SETTLE_TIME`](Self::MAX_SETTLE_TIME).
+    pub fn settle_time(&self) -> Duration {
+        if !self.is_valid() {
+            return Duration::ZERO;
+        }
+        let max = Self::MAX_SETTLE_TIME.as_secs_f64();
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+
+        let time = if zeta < 1.0 && fabs(zeta - 1.0) >= 1e-6 {
+            // The oscillation stays within an exponentially decaying envelope.
+            if zeta == 0.0 {
+                max
+            } else {
+                let damped = omega * sqrt(1.0 - zeta * zeta);
+                let amplitude = sqrt(1.0 + (zeta * omega / damped) * (zeta * omega / damped));
+                log(amplitude / Self::SETTLE_THRESHOLD) / (zeta * omega)
+            }
+        } else if self.displacement(max) > Self::SETTLE_THRESHOLD {
+            max
+        } else {
+            // Without oscillation, the displacement decreases monotonically.
+            let (mut low, mut high) = (0.0, max);
+            for _ in 0..64 {
+                let mid = (low + high) / 2.0;
+                if self.displacement(mid) > Self::SETTLE_THRESHOLD {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            high
+        };
+        Duration::from_secs_f64(time.clamp(0.0, max))
+    }
+}
@@ -0,0 +1,216 @@
+//! How views enter and leave the hierarchy.
+
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The edge a view slides from or to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionEdge {
+    /// The top edge.
+    Top,
+    /// The bottom edge.
+    Bottom,
+    /// The leading edge, on the left in left-to-right layouts.
+    Leading,
+    /// The trailing edge, on the right in left-to-right layouts.
+    Trailing,
+}
+
+/// Whether a view is entering or leaving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionPhase {
+    /// The view is being inserted.
+    Insertion,
+    /// The view is being removed.
+    Removal,
+}
+
+/// Describes how a view is inserted into and removed from the hierarchy.
+///
+/// Transitions are attached with `ViewExt::transition` and played by the renderer when a
+/// dynamic view swaps its content, following the animation of the change.
+///
+/// Only the transition of the new content itself is used: a transition attached to a
+/// view that is wrapped again, for instance by a padding or a background set after the
+/// transition, is ignored. Attach the transition last.
+///
+/// # Examples
+///
+/// ```
+/// use waterui_core::animation::{Transition, TransitionEdge};
+///
+/// // Fade and slide from the bottom when inserted, fade out when removed
+/// let transition = Transition::asymmetric(
+///     Transition::Opacity.combined(Transition::Slide(TransitionEdge::Bottom)),
+///     Transition::Opacity,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Transition {
+    /// The view appears and disappears without any effect.
+    #[default]
+    Identity,
+    /// The view fades in and out.
+    Opacity,
+    /// The view grows from and shrinks to the given scale.
+    Scale(f64),
+    /// The view slides in from and out to an edge, by its own size.
+    Slide(TransitionEdge),
+    /// The view moves in from and out to an offset, in points.
+    Move {
+        /// The horizontal offset.
+        x: f64,
+        /// The vertical offset.
+        y: f64,
+    },
+    /// All the transitions are applied together.
+    Combined(Vec<Transition>),
+    /// Different transitions are used for insertion and removal.
+    Asymmetric {
+        /// The transition used when the view is inserted.
+        insertion: Box<Transition>,
+        /// The transition used when the view is removed.
+        removal: Box<Transition>,
+    },
+}
+
+impl Transition {
+    /// Creates a transition scaling the view from and to `scale`.
+    pub const fn scale(scale: f64) -> Self {
+        Self::Scale(scale)
+    }
+
+    /// Creates a transition sliding the view from and to `edge`.
+    pub const fn slide(edge: TransitionEdge) -> Self {
+        Self::Slide(edge)
+    }
+
+    /// Creates a transition moving the view from and to an offset, in points.
+    pub const fn move_by(x: f64, y: f64) -> Self {
+        Self::Move { x, y }
+    }
+
+    /// Creates a transition using `insertion` when the view enters and `removal` when it
+    /// leaves.
+    pub fn asymmetric(insertion: Self, removal: Self) -> Self {
+        Self::Asymmetric {
+            insertion: Box::new(insertion),
+            removal: Box::new(removal),
+        }
+    }
+
+    /// Combines this transition with `other`, applying both at once.
+    pub fn combined(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Identity, other) => other,
+            (this, Self::Identity) => this,
+            (Self::Combined(mut transitions), Self::Combined(others)) => {
+                transitions.extend(others);
+                Self::Combined(transitions)
+            }
+            (Self::Combined(mut transitions), other) => {
+                transitions.push(other);
+                Self::Combined(transitions)
+            }
+            (this, other) => Self::Combined(vec![this, other]),
+        }
+    }
+
+    /// Returns the transition played during `phase`, resolving asymmetric transitions.
+    pub fn phase(&self, phase: TransitionPhase) -> Self {
+        match self {
+            Self::Asymmetric { insertion, .. } if phase == TransitionPhase::Insertion => {
+                insertion.phase(phase)
+            }
+            Self::Asymmetric { removal, .. } => removal.phase(phase),
+            Self::Combined(transitions) => Self::Combined(
+                transitions
+                    .iter()
+                    .map(|transition| transition.phase(phase))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// Returns the visual effect of the transition during `phase`, where `visibility`
+    /// goes from 0 when the view is hidden to 1 when it is fully shown.
+    ///
+    /// Renderers use it to compute the state of a view at each frame of the transition.
+    pub fn effect(&self, phase: TransitionPhase, visibility: f64) -> TransitionEffect {
+        let hidden = 1.0 - visibility;
+        match self {
+            Self::Identity => TransitionEffect::IDENTITY,
+            Self::Opacity => TransitionEffect {
+                opacity: visibility,
+                ..TransitionEffect::IDENTITY
+            },
+            Self::Scale(scale) => TransitionEffect {
+                scale: scale + (1.0 - scale) * visibility,
+                ..TransitionEffect::IDENTITY
+            },
+            Self::Slide(edge) => {
+                let (x, y) = match edge {
+                    TransitionEdge::Top => (0.0, -hidden),
+                    TransitionEdge::Bottom => (0.0, hidden),
+                    TransitionEdge::Leading => (-hidden, 0.0),
+                    TransitionEdge::Trailing => (hidden, 0.0),
+                };
+                TransitionEffect {
+                    relative_offset: [x, y],
+                    ..TransitionEffect::IDENTITY
+                }
+            }
+            Self::Move { x, y } => TransitionEffect {
+                offset: [x * hidden, y * hidden],
+                ..TransitionEffect::IDENTITY
+            },
+            Self::Combined(transitions) => transitions
+                .iter()
+                .map(|transition| transition.effect(phase, visibility))
+                .fold(TransitionEffect::IDENTITY, TransitionEffect::then),
+            Self::Asymmetric { insertion, removal } => match phase {
+                TransitionPhase::Insertion => insertion.effect(phase, visibility),
+                TransitionPhase::Removal => removal.effect(phase, visibility),
+            },
+        }
+    }
+}
+
+/// The visual state of a view during a transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitionEffect {
+    /// The opacity, multiplied with the opacity of the view.
+    pub opacity: f64,
+    /// The scale, around the center of the view.
+    pub scale: f64,
+    /// The offset, in points.
+    pub offset: [f64; 2],
+    /// The offset, as a fraction of the size of the view.
+    pub relative_offset: [f64; 2],
+}
+
+impl TransitionEffect {
+    /// The effect of a fully shown view.
+    pub const IDENTITY: Self = Self {
+        opacity: 1.0,
+        scale: 1.0,
+        offset: [0.0, 0.0],
+        relative_offset: [0.0, 0.0],
+    };
+
+    /// Applies `other` on top of this effect.
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            opacity: self.opacity * other.opacity,
+            scale: self.scale * other.scale,
+            offset: [
+                self.offset[0] + other.offset[0],
+                self.offset[1] + other.offset[1],
+            ],
+            relative_offset: [
+                self.relative_offset[0] + other.relative_offset[0],
+                self.relative_offset[1] + other.relative_offset[1],
+            ],
+        }
+    }
+}
@@ -0,0 +1,460 @@
+//! # Color Module
+//!
+//! This module provides types for working with colors in different color spaces.
+//! It supports both sRGB and P3 color spaces, with utilities for conversion and
+//! manipulation of color values.
+//!
+//! The primary type is `Color`, which can represent colors in either sRGB or P3
+//! color spaces, with conversion methods from various tuple formats.
+//!
+//! Colors can also be:
+//! - Parsed from hex and CSS strings, like `#ff8800` or `oklch(70% 0.15 60)`
+//! - Converted between sRGB, Display P3, linear RGB, HSL and OKLCH
+//! - Interpolated perceptually, for gradients and animations
+//! - Checked for WCAG contrast, to make sure text stays readable
+//!
+//! A color may also be semantic, like "primary" or "error". Semantic colors are
+//! resolved against the [`Palettes`] of the environment and the current [`ColorScheme`]
+//! when the view is rendered, so they follow theme and light/dark mode changes.
+
+mod parse;
+mod space;
+pub use parse::ParseColorError;
+pub use space::{ColorSpace, Hsl, LinearRgb, Oklab, Oklch, Rgb, WcagLevel};
+
+use crate::{Computed, Environment};
+use waterui_reactive::{ffi_computed, impl_constant, ComputeExt};
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, uniffi::Record)]
+
+/// Represents an sRGB color with red, yellow, and blue components.
+pub struct Srgb {
+    red: u8,
+    yellow: u8,
+    blue: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, uniffi::Record)]
+
+/// Represents a P3 color with red, yellow, and blue components.
+pub struct P3 {
+    red: f32,
+    yellow: f32,
+    blue: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, uniffi::Enum)]
+enum ColorInner {
+    Srgb(Srgb),
+    P3(P3),
+    Semantic(SemanticColor),
+}
+
+impl Default for ColorInner {
+    fn default() -> Self {
+        Self::Srgb(Srgb::default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default, uniffi::Record)]
+/// Represents a color, either in sRGB or P3 color space.
+pub struct Color {
+    color: ColorInner,
+    opacity: f32,
+}
+
+impl_constant!(Color);
+
+ffi_computed!(Color);
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, yellow, blue): (u8, u8, u8)) -> Self {
+        Self {
+            color: ColorInner::Srgb(Srgb { red, yellow, blue }),
+            opacity: 1.0,
+        }
+    }
+}
+
+impl From<(f32, f32, f32)> for Color {
+    fn from((red, yellow, blue): (f32, f32, f32)) -> Self {
+        Self {
+            color: ColorInner::P3(P3 { red, yellow, blue }),
+            opacity: 1.0,
+        }
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Color {
+    fn from((red, yellow, blue, opacity): (f32, f32, f32, f32)) -> Self {
+        Self {
+            color: ColorInner::P3(P3 { red, yellow, blue }),
+            opacity,
+        }
+    }
+}
+
+impl From<SemanticColor> for Color {
+    fn from(value: SemanticColor) -> Self {
+        Self::semantic(value)
+    }
+}
+
+impl Color {
+    /// Creates an sRGB color from 8-bit components.
+    pub const fn srgb(red: u8, green: u8, blue: u8) -> Self {
+        Self {
+            color: ColorInner::Srgb(Srgb {
+                red,
+                yellow: green,
+                blue,
+            }),
+            opacity: 1.0,
+        }
+    }
+
+    /// Creates a Display P3 color from gamma-encoded components between 0 and 1.
+    ///
+    /// Components outside this range describe colors beyond the P3 gamut.
+    pub const fn display_p3(red: f32, green: f32, blue: f32) -> Self {
+        Self {
+            color: ColorInner::P3(P3 {
+                red,
+                yellow: green,
+                blue,
+            }),
+            opacity: 1.0,
+        }
+    }
+
+    /// Returns the opacity of this color, between 0 and 1.
+    pub const fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// Returns this color with the given opacity, between 0 and 1.
+    #[must_use]
+    pub const fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Creates a color referring to a role of the current theme.
+    pub const fn semantic(color: SemanticColor) -> Self {
+        Self {
+            color: ColorInner::Semantic(color),
+            opacity: 1.0,
+        }
+    }
+
+    /// Returns the role this color refers to, if it is semantic.
+    pub const fn as_semantic(&self) -> Option<SemanticColor> {
+        match self.color {
+            ColorInner::Semantic(color) => Some(color),
+            _ => None,
+        }
+    }
+
+    /// Resolves this color against the environment.
+    ///
+    /// A semantic color is looked up in the [`Palettes`] of the environment, using the
+    /// palette of the current [`ColorScheme`], and updates when the scheme changes.
+    /// Any other color is returned as is.
+    pub fn resolve(&self, env: &Environment) -> Computed<Color> {
+        let Some(semantic) = self.as_semantic() else {
+            return Computed::constant(self.clone());
+        };
+        let palettes = env.get::<Palettes>().cloned().unwrap_or_default();
+        let opacity = self.opacity;
+        ColorScheme::current(env)
+            .map(move |scheme| {
+                let mut color = palettes.get(scheme).get(semantic).clone();
+                color.opacity *= opacity;
+                color
+            })
+            .computed()
+    }
+}
+
+raw_view!(Color);
+
+/// Resolves a color against the environment for the renderer.
+#[uniffi::export]
+fn resolve_color(env: Environment, color: Color) -> Computed<Color> {
+    color.resolve(&env)
+}
+
+/// The appearance of the interface, either light or dark.
+///
+/// The renderer usually stores a reactive `ColorScheme` in the environment with
+/// `Environment::insert_computed`, following the system setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, uniffi::Enum)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColorScheme {
+    /// Dark content on light backgrounds.
+    #[default]
+    Light,
+    /// Light content on dark backgrounds.
+    Dark,
+}
+
+impl_constant!(ColorScheme);
+
+impl ColorScheme {
+    /// Returns the color scheme of the environment, falling back to `Light`.
+    pub fn current(env: &Environment) -> Computed<Self> {
+        env.get_computed::<Self>()
+            .unwrap_or_else(|| Computed::constant(Self::default()))
+    }
+}
+
+macro_rules! semantic_colors {
+    ($($(#[$doc:meta])* $variant:ident => $field:ident),* $(,)?) => {
+        /// A role of a color in the interface, resolved against the current theme.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, uniffi::Enum)]
+        pub enum SemanticColor {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl SemanticColor {
+            /// Every semantic color.
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// Returns the name of this color, in snake case.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($field),)*
+                }
+            }
+
+            /// Returns the semantic color with the given snake case name.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(stringify!($field) => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        /// The colors of a theme for one color scheme, one for each [`SemanticColor`].
+        #[derive(Debug, Clone, PartialEq, uniffi::Record)]
+        pub struct Palette {
+            $($(#[$doc])* pub $field: Color,)*
+        }
+
+        impl Palette {
+            /// Returns the color of the given role.
+            pub const fn get(&self, color: SemanticColor) -> &Color {
+                match color {
+                    $(SemanticColor::$variant => &self.$field,)*
+                }
+            }
+
+            /// Returns a mutable reference to the color of the given role.
+            pub fn get_mut(&mut self, color: SemanticColor) -> &mut Color {
+                match color {
+                    $(SemanticColor::$variant => &mut self.$field,)*
+                }
+            }
+        }
+    };
+}
+
+semantic_colors! {
+    /// The main brand color, used for prominent controls.
+    Primary => primary,
+    /// A complementary brand color.
+    Secondary => secondary,
+    /// A color highlighting interactive or selected elements.
+    Accent => accent,
+    /// The background of the window.
+    Background => background,
+    /// The background of elements raised above the window, like cards.
+    Surface => surface,
+    /// The color of primary text.
+    Label => label,
+    /// The color of secondary text.
+    SecondaryLabel => secondary_label,
+    /// The color of separators and borders.
+    Separator => separator,
+    /// The color of errors and destructive actions.
+    Error => error,
+    /// The color of warnings.
+    Warning => warning,
+    /// The color of successful states.
+    Success => success,
+}
+
+impl Palette {
+    /// The default palette for the light color scheme.
+    pub fn light() -> Self {
+        Self {
+            primary: Color::from((0, 122, 255)),
+            secondary: Color::from((88, 86, 214)),
+            accent: Color::from((255, 149, 0)),
+            background: Color::from((255, 255, 255)),
+            surface: Color::from((242, 242, 247)),
+            label: Color::from((0, 0, 0)),
+            secondary_label: Color::from((138, 138, 142)),
+            separator: Color::from((198, 198, 200)),
+            error: Color::from((255, 59, 48)),
+            warning: Color::from((255, 204, 0)),
+            success: Color::from((52, 199, 89)),
+        }
+    }
+
+    /// The default palette for the dark color scheme.
+    pub fn dark() -> Self {
+        Self {
+            primary: Color::from((10, 132, 255)),
+            secondary: Color::from((94, 92, 230)),
+            accent: Color::from((255, 159, 10)),
+            background: Color::from((0, 0, 0)),
+            surface: Color::from((28, 28, 30)),
+            label: Color::from((255, 255, 255)),
+            secondary_label: Color::from((142, 142, 147)),
+            separator: Color::from((56, 56, 58)),
+            error: Color::from((255, 69, 58)),
+            warning: Color::from((255, 214, 10)),
+            success: Color::from((48, 209, 88)),
+        }
+    }
+}
+
+/// The palettes used to resolve semantic colors, one for each [`ColorScheme`].
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct Palettes {
+    /// The palette of the light color scheme.
+    pub light: Palette,
+    /// The palette of the dark color scheme.
+    pub dark: Palette,
+}
+
+impl Default for Palettes {
+    fn default() -> Self {
+        Self {
+            light: Palette::light(),
+            dark: Palette::dark(),
+        }
+    }
+}
+
+impl Palettes {
+    /// Returns the palette of the given color scheme.
+    pub const fn get(&self, scheme: ColorScheme) -> &Palette {
+        match scheme {
+            ColorScheme::Light => &self.light,
+            ColorScheme::Dark => &self.dark,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_reactive::{binding, Compute};
+
+    use alloc::string::ToString;
+
+    use super::{Color, ColorScheme, ColorSpace, Oklch, Palette, SemanticColor, WcagLevel};
+    use crate::Environment;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn semantic_colors_follow_scheme() {
+        let scheme = binding(ColorScheme::Light);
+        let env = Environment::new().with_computed::<ColorScheme>(scheme.clone());
+        let primary = Color::semantic(SemanticColor::Primary).resolve(&env);
+        assert_eq!(primary.compute(), Palette::light().primary);
+
+        scheme.set(ColorScheme::Dark);
+        assert_eq!(primary.compute(), Palette::dark().primary);
+
+        let red = Color::from((255, 0, 0));
+        assert_eq!(red.resolve(&env).compute(), red);
+    }
+
+    #[test]
+    fn parse_and_format() {
+        let orange = Color::srgb(255, 136, 0);
+        assert_eq!("#ff8800".parse(), Ok(orange.clone()));
+        assert_eq!("#F80".parse(), Ok(orange.clone()));
+        assert_eq!("rgb(255 136 0)".parse(), Ok(orange.clone()));
+        assert_eq!(
+            "rgba(255, 136, 0, 50%)".parse(),
+            Ok(orange.clone().with_opacity(0.5))
+        );
+        assert_eq!("Orange".parse(), Ok(Color::ORANGE));
+        assert_eq!(
+            "primary / 0.5".parse(),
+            Ok(Color::semantic(SemanticColor::Primary).with_opacity(0.5))
+        );
+        assert!("#12345".parse::<Color>().is_err());
+        assert!("lab(50% 0 0)".parse::<Color>().is_err());
+
+        assert_eq!(orange.to_string(), "#ff8800");
+        let translucent = Color::display_p3(1.0, 0.5, 0.0).with_opacity(0.5);
+        assert_eq!(translucent.to_string().parse(), Ok(translucent));
+    }
+
+    #[test]
+    fn conversions() {
+        let hsl = "hsl(120deg 100% 25%)".parse::<Color>().unwrap();
+        let srgb = hsl.to_srgb().unwrap();
+        assert!(close(srgb.red, 0.0) && close(srgb.green, 0.5) && close(srgb.blue, 0.0));
+
+        let oklch = Color::WHITE.to_oklch().unwrap();
+        assert!(close(oklch.lightness, 1.0) && close(oklch.chroma, 0.0));
+
+        let color = Color::from(Oklch {
+            lightness: 0.7,
+            chroma: 0.1,
+            hue: 200.0,
+        });
+        let back = color.to_oklch().unwrap();
+        assert!(close(back.lightness, 0.7) && close(back.chroma, 0.1) && close(back.hue, 200.0));
+
+        let p3 = Color::RED.to_display_p3().unwrap();
+        assert!(p3.red < 1.0 && p3.green > 0.0);
+    }
+
+    #[test]
+    fn mix_and_contrast() {
+        let gray = Color::BLACK.mix(&Color::WHITE, 0.5).to_oklch().unwrap();
+        assert!(close(gray.lightness, 0.5));
+
+        assert!(close(
+            Color::BLACK.contrast_ratio(&Color::WHITE).unwrap(),
+            21.0
+        ));
+        assert!(Color::BLACK.meets_contrast(&Color::WHITE, WcagLevel::Aaa));
+        assert!(!Color::GRAY.meets_contrast(&Color::WHITE, WcagLevel::Aa));
+        assert!(!Color::semantic(SemanticColor::Label)
+            .meets_contrast(&Color::WHITE, WcagLevel::AaLarge));
+    }
+
+    #[test]
+    fn mix_in_color_spaces() {
+        let purple = Color::RED.mix_in(&Color::BLUE, 0.5, ColorSpace::Srgb);
+        let rgb = purple.to_srgb().unwrap();
+        assert!(close(rgb.red, 0.5) && close(rgb.green, 0.0) && close(rgb.blue, 0.5));
+
+        // Oklch goes the short way around the hue wheel, from red (~29°) to blue (~264°).
+        let from = Color::RED.to_oklch().unwrap().hue;
+        let mixed = Color::RED
+            .mix_in(&Color::BLUE, 0.5, ColorSpace::Oklch)
+            .to_oklch()
+            .unwrap()
+            .hue;
+        assert!(mixed < from || mixed > 264.0);
+
+        let semantic = Color::semantic(SemanticColor::Accent);
+        assert_eq!(
+            semantic.mix_in(&Color::WHITE, 0.25, ColorSpace::LinearSrgb),
+            semantic
+        );
+    }
+}
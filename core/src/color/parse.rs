@@ -0,0 +1,344 @@
+//! Parsing and formatting colors as hex and CSS strings.
+//!
+//! Supported formats:
+//! - Hex: `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`
+//! - Functions: `rgb()`, `rgba()`, `hsl()`, `hsla()`, `oklab()`, `oklch()` and
+//!   `color()` with the `srgb`, `srgb-linear` and `display-p3` spaces
+//! - The CSS basic color keywords, `transparent`, and semantic color names like `primary`
+//!
+//! Colors are formatted back to the most compact of these formats.
+
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use alloc::vec::Vec;
+
+use super::{
+    space::{Hsl, LinearRgb, Oklab, Oklch, Rgb},
+    Color, ColorInner, SemanticColor,
+};
+
+/// An error raised when parsing a color from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The hex notation is malformed.
+    InvalidHex,
+    /// The color function isn't supported.
+    UnknownFunction,
+    /// The components of a color function are missing or malformed.
+    InvalidComponents,
+    /// The name isn't a known color.
+    UnknownName,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidHex => "Invalid hex color",
+            Self::UnknownFunction => "Unknown color function",
+            Self::InvalidComponents => "Invalid color components",
+            Self::UnknownName => "Unknown color name",
+        })
+    }
+}
+
+impl core::error::Error for ParseColorError {}
+
+/// The CSS basic color keywords, with a few common aliases.
+const NAMED_COLORS: &[(&str, Color)] = &[
+    ("black", Color::BLACK),
+    ("silver", Color::srgb(192, 192, 192)),
+    ("gray", Color::GRAY),
+    ("grey", Color::GRAY),
+    ("white", Color::WHITE),
+    ("maroon", Color::srgb(128, 0, 0)),
+    ("red", Color::RED),
+    ("purple", Color::PURPLE),
+    ("fuchsia", Color::srgb(255, 0, 255)),
+    ("magenta", Color::srgb(255, 0, 255)),
+    ("green", Color::GREEN),
+    ("lime", Color::srgb(0, 255, 0)),
+    ("olive", Color::srgb(128, 128, 0)),
+    ("yellow", Color::YELLOW),
+    ("navy", Color::srgb(0, 0, 128)),
+    ("blue", Color::BLUE),
+    ("teal", Color::srgb(0, 128, 128)),
+    ("aqua", Color::srgb(0, 255, 255)),
+    ("cyan", Color::srgb(0, 255, 255)),
+    ("orange", Color::ORANGE),
+    ("rebeccapurple", Color::srgb(102, 51, 153)),
+    ("transparent", Color::TRANSPARENT),
+];
+
+impl Color {
+    /// Opaque black.
+    pub const BLACK: Self = Self::srgb(0, 0, 0);
+    /// Opaque white.
+    pub const WHITE: Self = Self::srgb(255, 255, 255);
+    /// The CSS `gray` color.
+    pub const GRAY: Self = Self::srgb(128, 128, 128);
+    /// The CSS `red` color.
+    pub const RED: Self = Self::srgb(255, 0, 0);
+    /// The CSS `orange` color.
+    pub const ORANGE: Self = Self::srgb(255, 165, 0);
+    /// The CSS `yellow` color.
+    pub const YELLOW: Self = Self::srgb(255, 255, 0);
+    /// The CSS `green` color.
+    pub const GREEN: Self = Self::srgb(0, 128, 0);
+    /// The CSS `blue` color.
+    pub const BLUE: Self = Self::srgb(0, 0, 255);
+    /// The CSS `purple` color.
+    pub const PURPLE: Self = Self::srgb(128, 0, 128);
+    /// Fully transparent black.
+    pub const TRANSPARENT: Self = Self::srgb(0, 0, 0).with_opacity(0.0);
+
+    /// Parses a color from its hex notation, with or without the leading `#`.
+    ///
+    /// Accepts 3, 4, 6 or 8 digits, the optional last ones being the opacity.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(ParseColorError::InvalidHex);
+        }
+
+        let digit = |index: usize| u8::from_str_radix(&hex[index..=index], 16).unwrap();
+        let pair = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).unwrap();
+        let [red, green, blue, alpha] = match hex.len() {
+            3 | 4 => {
+                let short = |index: usize| {
+                    if index < hex.len() {
+                        digit(index) * 17
+                    } else {
+                        255
+                    }
+                };
+                [short(0), short(1), short(2), short(3)]
+            }
+            6 | 8 => {
+                let alpha = if hex.len() == 8 { pair(6) } else { 255 };
+                [pair(0), pair(2), pair(4), alpha]
+            }
+            _ => return Err(ParseColorError::InvalidHex),
+        };
+
+        Ok(Self::srgb(red, green, blue).with_opacity(f32::from(alpha) / 255.0))
+    }
+}
+
+/// Parses a number, scaling percentages so that `100%` equals `full`.
+fn number(token: &str, full: f32) -> Result<f32, ParseColorError> {
+    let (token, scale) = match token.strip_suffix('%') {
+        Some(token) => (token, full / 100.0),
+        None => (token, 1.0),
+    };
+    token
+        .parse::<f32>()
+        .map(|value| value * scale)
+        .map_err(|_| ParseColorError::InvalidComponents)
+}
+
+/// Parses an angle in degrees, accepting the `deg`, `rad` and `turn` units.
+fn angle(token: &str) -> Result<f32, ParseColorError> {
+    let parse = |token: &str| {
+        token
+            .parse::<f32>()
+            .map_err(|_| ParseColorError::InvalidComponents)
+    };
+    if let Some(token) = token.strip_suffix("deg") {
+        parse(token)
+    } else if let Some(token) = token.strip_suffix("rad") {
+        parse(token).map(f32::to_degrees)
+    } else if let Some(token) = token.strip_suffix("turn") {
+        parse(token).map(|turns| turns * 360.0)
+    } else {
+        parse(token)
+    }
+}
+
+/// Converts sRGB components between 0 and 1, keeping 8-bit colors exact.
+fn srgb(red: f32, green: f32, blue: f32) -> Color {
+    let exact = |value: f32| {
+        let scaled = value * 255.0;
+        ((0.0..=255.0).contains(&scaled) && scaled == f32::from(scaled as u8))
+            .then_some(scaled as u8)
+    };
+    match (exact(red), exact(green), exact(blue)) {
+        (Some(red), Some(green), Some(blue)) => Color::srgb(red, green, blue),
+        _ => Color::from_srgb(Rgb::new(red, green, blue)),
+    }
+}
+
+fn parse_function(name: &str, arguments: &str) -> Result<Color, ParseColorError> {
+    let tokens: Vec<&str> = arguments
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .collect();
+    let name = name.to_ascii_lowercase();
+
+    let (color, alpha) = if name == "color" {
+        let [space, red, green, blue, alpha @ ..] = tokens.as_slice() else {
+            return Err(ParseColorError::InvalidComponents);
+        };
+        let [red, green, blue] = [number(red, 1.0)?, number(green, 1.0)?, number(blue, 1.0)?];
+        let color = match space.to_ascii_lowercase().as_str() {
+            "srgb" => srgb(red, green, blue),
+            "srgb-linear" => Color::from(LinearRgb::new(red, green, blue)),
+            "display-p3" => Color::display_p3(red, green, blue),
+            _ => return Err(ParseColorError::UnknownFunction),
+        };
+        (color, alpha)
+    } else {
+        let [first, second, third, alpha @ ..] = tokens.as_slice() else {
+            return Err(ParseColorError::InvalidComponents);
+        };
+        let color = match name.as_str() {
+            "rgb" | "rgba" => srgb(
+                number(first, 255.0)? / 255.0,
+                number(second, 255.0)? / 255.0,
+                number(third, 255.0)? / 255.0,
+            ),
+            "hsl" | "hsla" => Color::from(Hsl {
+                hue: angle(first)?,
+                saturation: number(second.trim_end_matches('%'), 100.0)? / 100.0,
+                lightness: number(third.trim_end_matches('%'), 100.0)? / 100.0,
+            }),
+            "oklab" => Color::from(Oklab {
+                lightness: number(first, 1.0)?,
+                a: number(second, 0.4)?,
+                b: number(third, 0.4)?,
+            }),
+            "oklch" => Color::from(Oklch {
+                lightness: number(first, 1.0)?,
+                chroma: number(second, 0.4)?,
+                hue: angle(third)?,
+            }),
+            _ => return Err(ParseColorError::UnknownFunction),
+        };
+        (color, alpha)
+    };
+
+    match alpha {
+        [] => Ok(color),
+        [alpha] => Ok(color.with_opacity(number(alpha, 1.0)?)),
+        _ => Err(ParseColorError::InvalidComponents),
+    }
+}
+
+fn parse_name(name: &str) -> Result<Color, ParseColorError> {
+    let (name, alpha) = match name.split_once('/') {
+        Some((name, alpha)) => (name.trim(), Some(number(alpha.trim(), 1.0)?)),
+        None => (name, None),
+    };
+
+    let color = NAMED_COLORS
+        .iter()
+        .find(|(named, _)| named.eq_ignore_ascii_case(name))
+        .map(|(_, color)| color.clone())
+        .or_else(|| SemanticColor::from_name(&name.to_ascii_lowercase()).map(Color::semantic))
+        .ok_or(ParseColorError::UnknownName)?;
+
+    Ok(match alpha {
+        Some(alpha) => color.with_opacity(alpha),
+        None => color,
+    })
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses a color from a hex or CSS string.
+    ///
+    /// Semantic color names like `primary` are accepted too, optionally followed by
+    /// an opacity, as in `primary / 50%`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            Self::from_hex(s)
+        } else if let Some((name, arguments)) = s.split_once('(') {
+            let arguments = arguments
+                .strip_suffix(')')
+                .ok_or(ParseColorError::InvalidComponents)?;
+            parse_function(name.trim(), arguments)
+        } else {
+            parse_name(s)
+        }
+    }
+}
+
+impl Display for Color {
+    /// Formats this color as a CSS string, which can be parsed back.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.color {
+            ColorInner::Srgb(srgb) => {
+                write!(f, "#{:02x}{:02x}{:02x}", srgb.red, srgb.yellow, srgb.blue)?;
+                if self.opacity < 1.0 {
+                    write!(
+                        f,
+                        "{:02x}",
+                        (self.opacity.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+                    )?;
+                }
+                Ok(())
+            }
+            ColorInner::P3(p3) => {
+                write!(f, "color(display-p3 {} {} {}", p3.red, p3.yellow, p3.blue)?;
+                if self.opacity < 1.0 {
+                    write!(f, " / {}", self.opacity)?;
+                }
+                f.write_str(")")
+            }
+            ColorInner::Semantic(color) => {
+                f.write_str(color.name())?;
+                if self.opacity < 1.0 {
+                    write!(f, " / {}", self.opacity)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use alloc::string::ToString;
+    use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
+
+    use super::Color;
+
+    struct ColorVisitor;
+
+    impl Serialize for Color {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    impl Visitor<'_> for ColorVisitor {
+        type Value = Color;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a hex or CSS color")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Color {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(ColorVisitor)
+        }
+    }
+}
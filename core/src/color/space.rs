@@ -0,0 +1,535 @@
+//! Conversions between color spaces, interpolation and contrast.
+//!
+//! Every concrete color converts to linear sRGB, which connects the other spaces:
+//! - `Rgb`: Gamma-encoded components, in the sRGB or Display P3 space
+//! - `LinearRgb`: Linear-light components with sRGB primaries
+//! - `Hsl`: Hue, saturation and lightness of an sRGB color
+//! - `Oklab` and `Oklch`: Perceptually uniform spaces, used for interpolation
+//!
+//! Semantic colors have no components until they are resolved, so conversions return `None`.
+
+use libm::{atan2f, cbrtf, copysignf, cosf, fabsf, powf, sinf, sqrtf};
+
+use super::{Color, ColorInner};
+
+/// Gamma-encoded red, green and blue components, usually between 0 and 1.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rgb {
+    /// The red component.
+    pub red: f32,
+    /// The green component.
+    pub green: f32,
+    /// The blue component.
+    pub blue: f32,
+}
+
+/// Linear-light red, green and blue components, using the sRGB primaries.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LinearRgb {
+    /// The red component.
+    pub red: f32,
+    /// The green component.
+    pub green: f32,
+    /// The blue component.
+    pub blue: f32,
+}
+
+/// A color in the HSL space, derived from sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    /// The hue, in degrees between 0 and 360.
+    pub hue: f32,
+    /// The saturation, between 0 and 1.
+    pub saturation: f32,
+    /// The lightness, between 0 and 1.
+    pub lightness: f32,
+}
+
+/// A color in the perceptually uniform Oklab space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    /// The perceived lightness, between 0 and 1.
+    pub lightness: f32,
+    /// The green-red axis.
+    pub a: f32,
+    /// The blue-yellow axis.
+    pub b: f32,
+}
+
+/// A color in the Oklch space, the polar form of Oklab.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklch {
+    /// The perceived lightness, between 0 and 1.
+    pub lightness: f32,
+    /// The chroma, from 0 for grays to about 0.4 for the most vivid colors.
+    pub chroma: f32,
+    /// The hue, in degrees between 0 and 360.
+    pub hue: f32,
+}
+
+/// A color space in which colors are interpolated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, uniffi::Enum)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ColorSpace {
+    /// Gamma-encoded sRGB, as most web browsers do by default.
+    Srgb,
+    /// Linear-light sRGB, physically accurate light mixing.
+    LinearSrgb,
+    /// Gamma-encoded Display P3.
+    DisplayP3,
+    /// Oklab, perceptually uniform.
+    #[default]
+    Oklab,
+    /// Oklch, perceptually uniform, going around the hue wheel by the shortest path.
+    Oklch,
+}
+
+/// A conformance level of the WCAG contrast requirements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WcagLevel {
+    /// Level AA for large text, requiring a ratio of 3:1.
+    AaLarge,
+    /// Level AA for normal text, requiring a ratio of 4.5:1.
+    #[default]
+    Aa,
+    /// Level AAA for large text, requiring a ratio of 4.5:1.
+    AaaLarge,
+    /// Level AAA for normal text, requiring a ratio of 7:1.
+    Aaa,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio of this level.
+    pub const fn min_ratio(self) -> f32 {
+        match self {
+            Self::AaLarge => 3.0,
+            Self::Aa | Self::AaaLarge => 4.5,
+            Self::Aaa => 7.0,
+        }
+    }
+}
+
+type Matrix = [[f32; 3]; 3];
+
+const P3_TO_SRGB: Matrix = [
+    [1.224_940_1, -0.224_940_4, 0.0],
+    [-0.042_056_9, 1.042_057_1, 0.0],
+    [-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+const SRGB_TO_P3: Matrix = [
+    [0.822_462_1, 0.177_538, 0.0],
+    [0.033_194_1, 0.966_805_8, 0.0],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+const SRGB_TO_LMS: Matrix = [
+    [0.412_221_47, 0.536_332_55, 0.051_445_995],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_85, 0.629_978_7],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_346, -0.063_854_17],
+    [1.0, -0.089_484_18, -1.291_485_5],
+];
+
+const LMS_TO_SRGB: Matrix = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_94],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+fn multiply(matrix: &Matrix, [x, y, z]: [f32; 3]) -> [f32; 3] {
+    matrix.map(|[a, b, c]| a * x + b * y + c * z)
+}
+
+/// Decodes a gamma-encoded component, mirroring negative values.
+fn decode(value: f32) -> f32 {
+    let magnitude = fabsf(value);
+    let linear = if magnitude <= 0.040_45 {
+        magnitude / 12.92
+    } else {
+        powf((magnitude + 0.055) / 1.055, 2.4)
+    };
+    copysignf(linear, value)
+}
+
+/// Encodes a linear component, mirroring negative values.
+fn encode(value: f32) -> f32 {
+    let magnitude = fabsf(value);
+    let encoded = if magnitude <= 0.003_130_8 {
+        magnitude * 12.92
+    } else {
+        1.055 * powf(magnitude, 1.0 / 2.4) - 0.055
+    };
+    copysignf(encoded, value)
+}
+
+fn normalize_hue(hue: f32) -> f32 {
+    let hue = hue % 360.0;
+    if hue < 0.0 {
+        hue + 360.0
+    } else {
+        hue
+    }
+}
+
+impl Rgb {
+    /// Creates gamma-encoded components.
+    pub const fn new(red: f32, green: f32, blue: f32) -> Self {
+        Self { red, green, blue }
+    }
+
+    const fn to_array(self) -> [f32; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    const fn from_array([red, green, blue]: [f32; 3]) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Returns `true` if every component is between 0 and 1.
+    pub fn in_gamut(&self) -> bool {
+        self.to_array()
+            .iter()
+            .all(|value| (-1e-4..=1.0 + 1e-4).contains(value))
+    }
+}
+
+impl LinearRgb {
+    /// Creates linear components.
+    pub const fn new(red: f32, green: f32, blue: f32) -> Self {
+        Self { red, green, blue }
+    }
+
+    const fn to_array(self) -> [f32; 3] {
+        [self.red, self.green, self.blue]
+    }
+
+    const fn from_array([red, green, blue]: [f32; 3]) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Encodes these components as sRGB.
+    pub fn to_srgb(self) -> Rgb {
+        Rgb::from_array(self.to_array().map(encode))
+    }
+
+    /// Decodes sRGB components.
+    pub fn from_srgb(rgb: Rgb) -> Self {
+        Self::from_array(rgb.to_array().map(decode))
+    }
+
+    /// Converts these components to the Display P3 space.
+    pub fn to_display_p3(self) -> Rgb {
+        Rgb::from_array(multiply(&SRGB_TO_P3, self.to_array()).map(encode))
+    }
+
+    /// Converts Display P3 components.
+    pub fn from_display_p3(rgb: Rgb) -> Self {
+        Self::from_array(multiply(&P3_TO_SRGB, rgb.to_array().map(decode)))
+    }
+
+    /// Returns the relative luminance of these components, as defined by WCAG.
+    pub fn luminance(self) -> f32 {
+        0.2126 * self.red + 0.7152 * self.green + 0.0722 * self.blue
+    }
+}
+
+impl From<Oklab> for LinearRgb {
+    fn from(value: Oklab) -> Self {
+        let lms = multiply(&OKLAB_TO_LMS, [value.lightness, value.a, value.b]);
+        Self::from_array(multiply(
+            &LMS_TO_SRGB,
+            lms.map(|value| value * value * value),
+        ))
+    }
+}
+
+impl From<LinearRgb> for Oklab {
+    fn from(value: LinearRgb) -> Self {
+        let lms = multiply(&SRGB_TO_LMS, value.to_array()).map(cbrtf);
+        let [lightness, a, b] = multiply(&LMS_TO_OKLAB, lms);
+        Self { lightness, a, b }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(value: Oklch) -> Self {
+        let hue = value.hue.to_radians();
+        Self {
+            lightness: value.lightness,
+            a: value.chroma * cosf(hue),
+            b: value.chroma * sinf(hue),
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(value: Oklab) -> Self {
+        let chroma = sqrtf(value.a * value.a + value.b * value.b);
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            normalize_hue(atan2f(value.b, value.a).to_degrees())
+        };
+        Self {
+            lightness: value.lightness,
+            chroma,
+            hue,
+        }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(value: Hsl) -> Self {
+        let Hsl {
+            hue,
+            saturation,
+            lightness,
+        } = value;
+        let channel = |n: f32| {
+            let k = (n + normalize_hue(hue) / 30.0) % 12.0;
+            let a = saturation * lightness.min(1.0 - lightness);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Self::new(channel(0.0), channel(8.0), channel(4.0))
+    }
+}
+
+impl From<Rgb> for Hsl {
+    fn from(value: Rgb) -> Self {
+        let Rgb { red, green, blue } = value;
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+        let lightness = (max + min) / 2.0;
+
+        if delta < 1e-6 {
+            return Self {
+                hue: 0.0,
+                saturation: 0.0,
+                lightness,
+            };
+        }
+
+        let saturation = delta / (1.0 - fabsf(2.0 * lightness - 1.0));
+        let hue = if max == red {
+            (green - blue) / delta
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+
+        Self {
+            hue: normalize_hue(hue * 60.0),
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl From<LinearRgb> for Color {
+    fn from(value: LinearRgb) -> Self {
+        let Rgb { red, green, blue } = value.to_display_p3();
+        Self::display_p3(red, green, blue)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(value: Hsl) -> Self {
+        Self::from_srgb(Rgb::from(value))
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(value: Oklab) -> Self {
+        Self::from(LinearRgb::from(value))
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(value: Oklch) -> Self {
+        Self::from(Oklab::from(value))
+    }
+}
+
+impl Color {
+    /// Creates a color from gamma-encoded sRGB components between 0 and 1.
+    pub fn from_srgb(rgb: Rgb) -> Self {
+        Self::from(LinearRgb::from_srgb(rgb))
+    }
+
+    /// Returns the linear sRGB components of this color, or `None` if it is semantic.
+    ///
+    /// Colors outside of the sRGB gamut have components below 0 or above 1.
+    pub fn to_linear_srgb(&self) -> Option<LinearRgb> {
+        match &self.color {
+            ColorInner::Srgb(srgb) => Some(LinearRgb::from_srgb(Rgb::new(
+                f32::from(srgb.red) / 255.0,
+                f32::from(srgb.yellow) / 255.0,
+                f32::from(srgb.blue) / 255.0,
+            ))),
+            ColorInner::P3(p3) => Some(LinearRgb::from_display_p3(Rgb::new(
+                p3.red, p3.yellow, p3.blue,
+            ))),
+            ColorInner::Semantic(_) => None,
+        }
+    }
+
+    /// Returns the gamma-encoded sRGB components of this color, or `None` if it is semantic.
+    pub fn to_srgb(&self) -> Option<Rgb> {
+        self.to_linear_srgb().map(LinearRgb::to_srgb)
+    }
+
+    /// Returns the Display P3 components of this color, or `None` if it is semantic.
+    pub fn to_display_p3(&self) -> Option<Rgb> {
+        match &self.color {
+            ColorInner::P3(p3) => Some(Rgb::new(p3.red, p3.yellow, p3.blue)),
+            _ => self.to_linear_srgb().map(LinearRgb::to_display_p3),
+        }
+    }
+
+    /// Returns this color in the HSL space, or `None` if it is semantic.
+    pub fn to_hsl(&self) -> Option<Hsl> {
+        self.to_srgb().map(Hsl::from)
+    }
+
+    /// Returns this color in the Oklab space, or `None` if it is semantic.
+    pub fn to_oklab(&self) -> Option<Oklab> {
+        self.to_linear_srgb().map(Oklab::from)
+    }
+
+    /// Returns this color in the Oklch space, or `None` if it is semantic.
+    pub fn to_oklch(&self) -> Option<Oklch> {
+        self.to_oklab().map(Oklch::from)
+    }
+
+    /// Interpolates between this color and `other` in the Oklab space.
+    ///
+    /// Oklab is perceptually uniform, so the intermediate colors keep an even brightness
+    /// instead of going through the muddy tones of sRGB interpolation. Opacity is
+    /// interpolated linearly.
+    ///
+    /// Semantic colors can't be interpolated: the result jumps from one color to the
+    /// other halfway through.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The color reached when `progress` is 1
+    /// * `progress` - The position between the colors, usually between 0 and 1
+    pub fn mix(&self, other: &Self, progress: f32) -> Self {
+        self.mix_in(other, progress, ColorSpace::Oklab)
+    }
+
+    /// Interpolates between this color and `other` in the given color space.
+    ///
+    /// Like [`Color::mix`], opacity is interpolated linearly and semantic colors jump
+    /// halfway through.
+    pub fn mix_in(&self, other: &Self, progress: f32, space: ColorSpace) -> Self {
+        let lerp = |from: f32, to: f32| from + (to - from) * progress;
+        let lerp3 = |from: [f32; 3], to: [f32; 3]| {
+            [
+                lerp(from[0], to[0]),
+                lerp(from[1], to[1]),
+                lerp(from[2], to[2]),
+            ]
+        };
+
+        let mixed = match space {
+            ColorSpace::Srgb => self.to_srgb().zip(other.to_srgb()).map(|(from, to)| {
+                Self::from_srgb(Rgb::from_array(lerp3(from.to_array(), to.to_array())))
+            }),
+            ColorSpace::LinearSrgb => {
+                self.to_linear_srgb()
+                    .zip(other.to_linear_srgb())
+                    .map(|(from, to)| {
+                        Self::from(LinearRgb::from_array(lerp3(from.to_array(), to.to_array())))
+                    })
+            }
+            ColorSpace::DisplayP3 => {
+                self.to_display_p3()
+                    .zip(other.to_display_p3())
+                    .map(|(from, to)| {
+                        let [red, green, blue] = lerp3(from.to_array(), to.to_array());
+                        Self::display_p3(red, green, blue)
+                    })
+            }
+            ColorSpace::Oklab => self.to_oklab().zip(other.to_oklab()).map(|(from, to)| {
+                Self::from(Oklab {
+                    lightness: lerp(from.lightness, to.lightness),
+                    a: lerp(from.a, to.a),
+                    b: lerp(from.b, to.b),
+                })
+            }),
+            ColorSpace::Oklch => self.to_oklch().zip(other.to_oklch()).map(|(from, to)| {
+                // The hue of a gray is meaningless, so take the hue of the other color.
+                const ACHROMATIC: f32 = 1e-4;
+                let (from_hue, to_hue) = match (from.chroma < ACHROMATIC, to.chroma < ACHROMATIC) {
+                    (true, false) => (to.hue, to.hue),
+                    (false, true) => (from.hue, from.hue),
+                    _ => (from.hue, to.hue),
+                };
+                let mut delta = to_hue - from_hue;
+                if delta > 180.0 {
+                    delta -= 360.0;
+                } else if delta < -180.0 {
+                    delta += 360.0;
+                }
+                Self::from(Oklch {
+                    lightness: lerp(from.lightness, to.lightness),
+                    chroma: lerp(from.chroma, to.chroma),
+                    hue: normalize_hue(from_hue + delta * progress),
+                })
+            }),
+        };
+
+        match mixed {
+            Some(color) => color.with_opacity(lerp(self.opacity, other.opacity)),
+            None if progress < 0.5 => self.clone(),
+            None => other.clone(),
+        }
+    }
+
+    /// Returns the relative luminance of this color as defined by WCAG,
+    /// or `None` if it is semantic.
+    ///
+    /// Opacity is ignored.
+    pub fn relative_luminance(&self) -> Option<f32> {
+        self.to_linear_srgb().map(|linear| {
+            LinearRgb::from_array(linear.to_array().map(|value| value.clamp(0.0, 1.0))).luminance()
+        })
+    }
+
+    /// Returns the WCAG contrast ratio between this color and `other`, from 1 to 21.
+    ///
+    /// Returns `None` if one of the colors is semantic. Opacity is ignored.
+    pub fn contrast_ratio(&self, other: &Self) -> Option<f32> {
+        let first = self.relative_luminance()?;
+        let second = other.relative_luminance()?;
+        let (lighter, darker) = if first > second {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        Some((lighter + 0.05) / (darker + 0.05))
+    }
+
+    /// Returns `true` if the contrast between this color and `other` meets the WCAG `level`.
+    ///
+    /// Semantic colors never meet any level, since their contrast is unknown.
+    pub fn meets_contrast(&self, other: &Self, level: WcagLevel) -> bool {
+        self.contrast_ratio(other)
+            .is_some_and(|ratio| ratio >= level.min_ratio())
+    }
+}
@@ -0,0 +1,324 @@
+//! Keys, key events and key bindings.
+
+use core::{
+    fmt::{self, Display},
+    ops::{BitOr, BitOrAssign},
+    str::FromStr,
+};
+
+/// A key of the keyboard, independent of the layout modifiers produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key producing a character, stored in lowercase.
+    Character(char),
+    /// The enter or return key.
+    Enter,
+    /// The escape key.
+    Escape,
+    /// The tab key.
+    Tab,
+    /// The space bar.
+    Space,
+    /// The backspace key, deleting backwards.
+    Backspace,
+    /// The delete key, deleting forwards.
+    Delete,
+    /// The up arrow.
+    Up,
+    /// The down arrow.
+    Down,
+    /// The left arrow.
+    Left,
+    /// The right arrow.
+    Right,
+    /// The home key.
+    Home,
+    /// The end key.
+    End,
+    /// The page up key.
+    PageUp,
+    /// The page down key.
+    PageDown,
+    /// A function key, from 1 to 24.
+    Function(u8),
+}
+
+impl Key {
+    /// Creates the key producing `character`, ignoring its case.
+    pub fn character(character: char) -> Self {
+        match character {
+            ' ' => Self::Space,
+            character => Self::Character(character.to_lowercase().next().unwrap_or(character)),
+        }
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Character(_) | Self::Function(_) => return None,
+            Self::Enter => "Enter",
+            Self::Escape => "Escape",
+            Self::Tab => "Tab",
+            Self::Space => "Space",
+            Self::Backspace => "Backspace",
+            Self::Delete => "Delete",
+            Self::Up => "Up",
+            Self::Down => "Down",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::Home => "Home",
+            Self::End => "End",
+            Self::PageUp => "PageUp",
+            Self::PageDown => "PageDown",
+        })
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        const NAMED: [Key; 14] = [
+            Key::Enter,
+            Key::Escape,
+            Key::Tab,
+            Key::Space,
+            Key::Backspace,
+            Key::Delete,
+            Key::Up,
+            Key::Down,
+            Key::Left,
+            Key::Right,
+            Key::Home,
+            Key::End,
+            Key::PageUp,
+            Key::PageDown,
+        ];
+
+        let mut chars = name.chars();
+        if let (Some(character), None) = (chars.next(), chars.next()) {
+            return Some(Self::character(character));
+        }
+        if name.eq_ignore_ascii_case("return") {
+            return Some(Self::Enter);
+        }
+        if name.eq_ignore_ascii_case("esc") {
+            return Some(Self::Escape);
+        }
+        if let Some(number) = name.strip_prefix(['F', 'f']) {
+            return number
+                .parse()
+                .ok()
+                .filter(|number| (1..=24).contains(number))
+                .map(Self::Function);
+        }
+        NAMED
+            .into_iter()
+            .find(|key| key.name().is_some_and(|key| key.eq_ignore_ascii_case(name)))
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Character(character) => {
+                for character in character.to_uppercase() {
+                    write!(f, "{character}")?;
+                }
+                Ok(())
+            }
+            Self::Function(number) => write!(f, "F{number}"),
+            key => f.write_str(key.name().unwrap_or_default()),
+        }
+    }
+}
+
+/// A set of modifier keys, combined with `|`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeyModifiers(u8);
+
+impl KeyModifiers {
+    /// No modifier.
+    pub const NONE: Self = Self(0);
+    /// The shift key.
+    pub const SHIFT: Self = Self(1);
+    /// The control key.
+    pub const CONTROL: Self = Self(1 << 1);
+    /// The option key, or alt outside Apple platforms.
+    pub const OPTION: Self = Self(1 << 2);
+    /// The command key, or the Windows and super keys outside Apple platforms.
+    pub const COMMAND: Self = Self(1 << 3);
+
+    const NAMES: [(Self, &'static str); 4] = [
+        (Self::CONTROL, "Ctrl"),
+        (Self::OPTION, "Alt"),
+        (Self::SHIFT, "Shift"),
+        (Self::COMMAND, "Cmd"),
+    ];
+
+    /// Returns whether every modifier of `other` is in this set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the modifiers of both sets.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns whether the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        const ALIASES: [(KeyModifiers, &str); 8] = [
+            (KeyModifiers::SHIFT, "shift"),
+            (KeyModifiers::CONTROL, "ctrl"),
+            (KeyModifiers::CONTROL, "control"),
+            (KeyModifiers::OPTION, "alt"),
+            (KeyModifiers::OPTION, "option"),
+            (KeyModifiers::COMMAND, "cmd"),
+            (KeyModifiers::COMMAND, "command"),
+            (KeyModifiers::COMMAND, "meta"),
+        ];
+        ALIASES
+            .into_iter()
+            .find(|(_, alias)| alias.eq_ignore_ascii_case(name))
+            .map(|(modifiers, _)| modifiers)
+    }
+}
+
+impl BitOr for KeyModifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for KeyModifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+/// A key press, normalized by renderers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    /// The key pressed.
+    pub key: Key,
+    /// The modifiers held down.
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyEvent {
+    /// Creates a key event.
+    pub const fn new(key: Key, modifiers: KeyModifiers) -> Self {
+        Self { key, modifiers }
+    }
+}
+
+/// A key with modifiers, triggering a command.
+///
+/// Key bindings are written as the modifiers then the key, joined with `+`, such as
+/// `"Cmd+Shift+S"` or `"Ctrl+F5"`. Names are case-insensitive, and `Alt`/`Option`,
+/// `Ctrl`/`Control` and `Cmd`/`Command`/`Meta` are synonyms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    /// The key to press.
+    pub key: Key,
+    /// The modifiers to hold down, exactly.
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Creates a key binding.
+    pub const fn new(key: Key, modifiers: KeyModifiers) -> Self {
+        Self { key, modifiers }
+    }
+
+    /// Returns whether `event` presses this key binding.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        let key = match event.key {
+            Key::Character(character) => Key::character(character),
+            key => key,
+        };
+        key == self.key && event.modifiers == self.modifiers
+    }
+}
+
+impl From<Key> for KeyBinding {
+    fn from(key: Key) -> Self {
+        Self::new(key, KeyModifiers::NONE)
+    }
+}
+
+impl From<char> for KeyBinding {
+    fn from(character: char) -> Self {
+        Key::character(character).into()
+    }
+}
+
+impl Display for KeyBinding {
+    /// Formats the key binding so it can be parsed back, such as `Ctrl+Shift+S`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in KeyModifiers::NAMES {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// An error returned when parsing a key binding fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseKeyBindingError {
+    /// The key binding has no key.
+    MissingKey,
+    /// A modifier isn't known, at the given byte offset.
+    UnknownModifier(usize),
+    /// The key isn't known, at the given byte offset.
+    UnknownKey(usize),
+}
+
+impl Display for ParseKeyBindingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingKey => f.write_str("Key binding has no key"),
+            Self::UnknownModifier(position) => write!(f, "Unknown modifier at {position}"),
+            Self::UnknownKey(position) => write!(f, "Unknown key at {position}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseKeyBindingError {}
+
+impl FromStr for KeyBinding {
+    type Err = ParseKeyBindingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The plus key itself ends the binding with a doubled separator, as in `Cmd++`.
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (Some(modifiers), "+"),
+            None if s == "+" => (None, "+"),
+            None => match s.rsplit_once('+') {
+                Some((modifiers, key)) => (Some(modifiers), key),
+                None => (None, s),
+            },
+        };
+        if key.is_empty() {
+            return Err(ParseKeyBindingError::MissingKey);
+        }
+        let key_position = s.len() - key.len();
+        let key = Key::parse(key).ok_or(ParseKeyBindingError::UnknownKey(key_position))?;
+
+        let mut binding = Self::from(key);
+        let mut position = 0;
+        for name in modifiers
+            .into_iter()
+            .flat_map(|modifiers| modifiers.split('+'))
+        {
+            binding.modifiers |= KeyModifiers::parse(name.trim())
+                .ok_or(ParseKeyBindingError::UnknownModifier(position))?;
+            position += name.len() + 1;
+        }
+        Ok(binding)
+    }
+}
@@ -0,0 +1,387 @@
+//! # Commands
+//!
+//! Actions users invoke from keyboard shortcuts or menus, routed to the views that handle
+//! them.
+//!
+//! A [`Command`] describes an action: its identifier, its title, its key binding and
+//! whether it is enabled. Views handle commands by registering a handler in a
+//! [`CommandScope`] of the environment when they are built. Scopes form a tree mirroring
+//! the views, so a handler registered by a view in its own scope only applies while the
+//! focus is inside that view, while handlers registered in the root scope apply
+//! everywhere.
+//!
+//! Renderers normalize the key presses of their platform into [`KeyEvent`]s and forward
+//! them to the [`CommandDispatcher`], with the environment of the focused view. The
+//! dispatcher walks the scopes from the focused one up to the root, and performs the
+//! first enabled command bound to the key:
+//!
+//! ```rust
+//! use waterui_core::Environment;
+//! use waterui_core::command::{CommandDispatcher, Key, KeyEvent, KeyModifiers};
+//!
+//! let dispatcher = CommandDispatcher::new();
+//! let env = Environment::new().install(dispatcher.clone());
+//! // In the renderer, on every key press, with the environment of the focused view:
+//! let event = KeyEvent::new(Key::Character('s'), KeyModifiers::COMMAND);
+//! let handled = dispatcher.dispatch(&event, Some(&env));
+//! assert!(!handled);
+//! ```
+//!
+//! The same walk lists the commands available from the focus, to build menus.
+
+mod key;
+
+pub use key::{Key, KeyBinding, KeyEvent, KeyModifiers, ParseKeyBindingError};
+
+use alloc::{
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+use core::cell::{Cell, RefCell};
+
+use waterui_reactive::{compute::IntoComputed, Compute, Computed};
+
+use crate::{handler::Handler, plugin::Plugin, Environment, Str};
+
+/// An action users can invoke, described for shortcuts and menus.
+#[derive(Debug, Clone)]
+pub struct Command {
+    /// The identifier of the command, such as `"document.save"`.
+    pub id: Str,
+    /// The title of the command, shown in menus.
+    pub title: Str,
+    /// The key binding performing the command.
+    pub shortcut: Option<KeyBinding>,
+    /// Whether the command can be performed.
+    pub enabled: Computed<bool>,
+}
+
+impl Command {
+    /// Creates an enabled command without key binding.
+    pub fn new(id: impl Into<Str>, title: impl Into<Str>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            shortcut: None,
+            enabled: Computed::constant(true),
+        }
+    }
+
+    /// Sets the key binding performing the command.
+    pub fn shortcut(mut self, shortcut: impl Into<KeyBinding>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets whether the command can be performed.
+    pub fn enabled(mut self, enabled: impl IntoComputed<bool>) -> Self {
+        self.enabled = enabled.into_computed();
+        self
+    }
+
+    /// Returns whether the command can currently be performed.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.compute()
+    }
+}
+
+struct Entry {
+    id: u64,
+    command: Command,
+    handler: Rc<dyn Handler<()>>,
+    env: Environment,
+}
+
+#[derive(Default)]
+struct Scope {
+    parent: Option<CommandScope>,
+    entries: RefCell<Vec<Entry>>,
+    next_id: Cell<u64>,
+}
+
+/// A set of command handlers, nested in the scope of an enclosing view.
+///
+/// The scope of a view is found in its environment. Handlers registered later in a
+/// scope take precedence over earlier ones.
+#[derive(Clone, Default)]
+pub struct CommandScope(Rc<Scope>);
+
+impl_debug!(CommandScope);
+
+impl CommandScope {
+    /// Creates a root scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the scope of the environment, if a [`CommandDispatcher`] is installed.
+    pub fn of(env: &Environment) -> Option<Self> {
+        env.get::<Self>().cloned()
+    }
+
+    /// Creates a scope nested in this one.
+    pub fn child(&self) -> Self {
+        Self(Rc::new(Scope {
+            parent: Some(self.clone()),
+            ..Scope::default()
+        }))
+    }
+
+    /// Registers `handler` to perform `command` in this scope, until the returned
+    /// registration is dropped.
+    ///
+    /// The handler is called with `env`, the environment of the view handling the command.
+    #[must_use = "The command is unregistered when the registration is dropped"]
+    pub fn register(
+        &self,
+        command: Command,
+        handler: impl Handler<()>,
+        env: Environment,
+    ) -> CommandRegistration {
+        self.register_shared(command, Rc::new(handler), env)
+    }
+
+    /// Registers a shared handler, like [`register`](CommandScope::register).
+    #[must_use = "The command is unregistered when the registration is dropped"]
+    pub fn register_shared(
+        &self,
+        command: Command,
+        handler: Rc<dyn Handler<()>>,
+        env: Environment,
+    ) -> CommandRegistration {
+        let id = self.0.next_id.get();
+        self.0.next_id.set(id + 1);
+        self.0.entries.borrow_mut().push(Entry {
+            id,
+            command,
+            handler,
+            env,
+        });
+        CommandRegistration {
+            scope: Rc::downgrade(&self.0),
+            id,
+        }
+    }
+
+    /// Returns this scope and its ancestors, innermost first.
+    fn ancestors(&self) -> impl Iterator<Item = &CommandScope> {
+        core::iter::successors(Some(self), |scope| scope.0.parent.as_ref())
+    }
+
+    /// Performs the first enabled command matching `predicate`, from this scope up.
+    fn perform(&self, predicate: impl Fn(&Command) -> bool) -> bool {
+        for scope in self.ancestors() {
+            let found = scope
+                .0
+                .entries
+                .borrow()
+                .iter()
+                .rev()
+                .find(|entry| predicate(&entry.command) && entry.command.is_enabled())
+                .map(|entry| (entry.handler.clone(), entry.env.clone()));
+            // The handler runs once the scope is released, as it may register commands.
+            if let Some((handler, env)) = found {
+                handler.handle(&env);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The registration of a command handler, unregistering it when dropped.
+#[derive(Debug)]
+pub struct CommandRegistration {
+    scope: Weak<Scope>,
+    id: u64,
+}
+
+impl Drop for CommandRegistration {
+    fn drop(&mut self) {
+        if let Some(scope) = self.scope.upgrade() {
+            scope
+                .entries
+                .borrow_mut()
+                .retain(|entry| entry.id != self.id);
+        }
+    }
+}
+
+/// Routes key presses and menu selections to the handlers of commands.
+///
+/// Installing the dispatcher as a plugin sets its root scope in the environment.
+#[derive(Debug, Clone, Default)]
+pub struct CommandDispatcher {
+    root: CommandScope,
+}
+
+impl CommandDispatcher {
+    /// Creates a dispatcher with an empty root scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the root scope, whose commands apply everywhere.
+    pub fn root(&self) -> &CommandScope {
+        &self.root
+    }
+
+    fn scope(&self, focus: Option<&Environment>) -> CommandScope {
+        focus
+            .and_then(CommandScope::of)
+            .unwrap_or_else(|| self.root.clone())
+    }
+
+    /// Performs the command bound to the key of `event`, returning whether one was found.
+    ///
+    /// `focus` is the environment of the focused view, or `None` if no view is focused.
+    /// Key presses not handled by a command should be delivered to the focused view.
+    pub fn dispatch(&self, event: &KeyEvent, focus: Option<&Environment>) -> bool {
+        self.scope(focus).perform(|command| {
+            command
+                .shortcut
+                .is_some_and(|shortcut| shortcut.matches(event))
+        })
+    }
+
+    /// Performs the command identified by `id`, for instance selected in a menu,
+    /// returning whether it was found and enabled.
+    pub fn perform(&self, id: &str, focus: Option<&Environment>) -> bool {
+        self.scope(focus).perform(|command| &*command.id == id)
+    }
+
+    /// Returns the commands available from the focus, innermost first, to build menus.
+    ///
+    /// Commands handled in several scopes are listed once, as handled by the innermost
+    /// scope. Disabled commands are included, so menus can show them as disabled.
+    pub fn commands(&self, focus: Option<&Environment>) -> Vec<Command> {
+        let mut commands: Vec<Command> = Vec::new();
+        for scope in self.scope(focus).ancestors() {
+            for entry in scope.0.entries.borrow().iter().rev() {
+                if !commands
+                    .iter()
+                    .any(|command| command.id == entry.command.id)
+                {
+                    commands.push(entry.command.clone());
+                }
+            }
+        }
+        commands
+    }
+}
+
+impl Plugin for CommandDispatcher {
+    fn install(self, env: &mut Environment) {
+        env.insert(self.root.clone());
+        env.insert(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::RefCell;
+
+    use waterui_reactive::Binding;
+
+    use super::{
+        Command, CommandDispatcher, CommandScope, Key, KeyBinding, KeyEvent, KeyModifiers,
+        ParseKeyBindingError,
+    };
+    use crate::{handler::into_handler, Environment};
+
+    #[test]
+    fn key_bindings() {
+        let binding: KeyBinding = "cmd+Shift+s".parse().unwrap();
+        assert_eq!(
+            binding,
+            KeyBinding::new(
+                Key::Character('s'),
+                KeyModifiers::COMMAND | KeyModifiers::SHIFT
+            )
+        );
+        assert_eq!(binding.to_string(), "Shift+Cmd+S");
+        assert!(binding.matches(&KeyEvent::new(
+            Key::Character('S'),
+            KeyModifiers::SHIFT | KeyModifiers::COMMAND
+        )));
+        assert!(!binding.matches(&KeyEvent::new(Key::Character('s'), KeyModifiers::COMMAND)));
+
+        for source in ["Ctrl++", "Alt+F12", "Esc", "Option+PageDown"] {
+            let binding: KeyBinding = source.parse().unwrap();
+            assert_eq!(binding.to_string().parse::<KeyBinding>(), Ok(binding));
+        }
+        assert_eq!(
+            "Ctrl++".parse::<KeyBinding>().unwrap().key,
+            Key::Character('+')
+        );
+        assert_eq!(
+            "Ctrl+".parse::<KeyBinding>(),
+            Err(ParseKeyBindingError::MissingKey)
+        );
+        assert_eq!(
+            "Ctrl+Hyper+K".parse::<KeyBinding>(),
+            Err(ParseKeyBindingError::UnknownModifier(5))
+        );
+        assert_eq!(
+            "Cmd+F30".parse::<KeyBinding>(),
+            Err(ParseKeyBindingError::UnknownKey(4))
+        );
+    }
+
+    #[test]
+    fn dispatch_walks_scopes() {
+        let dispatcher = CommandDispatcher::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let record = |name: &'static str| {
+            let log = log.clone();
+            into_handler(move || log.borrow_mut().push(name))
+        };
+        let save = || {
+            Command::new("save", "Save")
+                .shortcut(KeyBinding::new(Key::Character('s'), KeyModifiers::COMMAND))
+        };
+        let root_env = Environment::new().install(dispatcher.clone());
+
+        let _app = dispatcher
+            .root()
+            .register(save(), record("app"), root_env.clone());
+        let editor = dispatcher.root().child();
+        let editor_env = root_env.clone().with(editor.clone());
+        let enabled = Binding::container(true);
+        let _editor = editor.register(
+            save().enabled(enabled.clone()),
+            record("editor"),
+            editor_env.clone(),
+        );
+        let close = editor.register(
+            Command::new("close", "Close").shortcut(Key::Escape),
+            record("close"),
+            editor_env.clone(),
+        );
+
+        let event = KeyEvent::new(Key::Character('s'), KeyModifiers::COMMAND);
+        assert!(dispatcher.dispatch(&event, Some(&editor_env)));
+        assert!(dispatcher.dispatch(&event, Some(&root_env)));
+        assert!(dispatcher.dispatch(&event, None));
+        enabled.set(false);
+        assert!(dispatcher.dispatch(&event, Some(&editor_env)));
+        assert_eq!(*log.borrow(), ["editor", "app", "app", "app"]);
+
+        let ids: Vec<_> = dispatcher
+            .commands(Some(&editor_env))
+            .into_iter()
+            .map(|command| command.id.to_string())
+            .collect();
+        assert_eq!(ids, ["close", "save"]);
+        assert!(dispatcher.perform("close", Some(&editor_env)));
+        assert!(!dispatcher.perform("close", Some(&root_env)));
+        drop(close);
+        assert!(!dispatcher.dispatch(
+            &KeyEvent::new(Key::Escape, KeyModifiers::NONE),
+            Some(&editor_env)
+        ));
+        assert_eq!(log.borrow().last(), Some(&"close"));
+        assert!(CommandScope::of(&Environment::new()).is_none());
+    }
+}
@@ -156,7 +156,7 @@ mod ffi {
 
     use waterui_task::OnceValue;
 
-    use crate::{Environment, View};
+    use crate::Environment;
 
     #[derive(uniffi::Object)]
     pub struct FFIAnyView(OnceValue<super::AnyView>);
@@ -180,8 +180,7 @@ mod ffi {
         }
 
         pub fn body(&self, env: Environment) -> super::AnyView {
-            let view = self.0.take().body(&env);
-            super::AnyView::new(view)
+            crate::error::body_or_report(self.0.take(), &env)
         }
     }
 }
@@ -6,6 +6,9 @@
 //! - `Dynamic` - A view that can be updated through a `DynamicHandler`
 //! - `watch` - Helper function to create views that respond to reactive state changes
 //!
+//! Every update carries a [`Replacement`] in its metadata, describing how the previous
+//! content leaves and the new content enters.
+//!
 //! # Examples
 //!
 //! ```
@@ -19,13 +22,13 @@
 //! // Create a view that watches a reactive value
 //! let count = Mutable::new(0);
 //! let counter_view = watch(count, |value| text(format!("Count: {}", value)));
-use crate::components::With;
+use crate::animation::{Animation, Transition, TransitionPhase};
+use crate::components::{IgnorableMetadata, With};
 use crate::{raw_view, AnyView, View};
 use alloc::rc::Rc;
 use core::cell::RefCell;
 use waterui_reactive::watcher::{Metadata, WatcherGuard};
 use waterui_reactive::Compute;
-use waterui_reactive::ComputeExt;
 use waterui_reactive::Computed;
 
 /// A dynamic view that can be updated.
@@ -74,16 +77,64 @@ raw_view!(Dynamic);
 ///
 /// Provides methods to set new content for the associated Dynamic view.
 #[derive(Clone)]
-pub struct DynamicHandler(Rc<RefCell<Receiver>>);
+pub struct DynamicHandler(Rc<RefCell<DynamicState>>);
 
 type Receiver = Box<dyn Fn(AnyView, Metadata)>;
 
+struct DynamicState {
+    receiver: Option<Receiver>,
+    /// The last update made before a receiver was connected.
+    pending: Option<(AnyView, Metadata)>,
+    /// The transition of the current content.
+    transition: Transition,
+}
+
 impl_debug!(Dynamic);
 impl_debug!(DynamicHandler);
 
+/// Describes how the content of a [`Dynamic`] is replaced.
+///
+/// It is attached to the metadata of every update.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replacement {
+    /// The transition of the outgoing content, for its removal.
+    pub removal: Transition,
+    /// The transition of the incoming content, for its insertion.
+    pub insertion: Transition,
+    /// The animation of the change that triggered the replacement, if any.
+    pub animation: Option<Animation>,
+}
+
 impl DynamicHandler {
+    /// Sets the content of the view, along with the metadata of the change.
+    ///
+    /// The transitions of the outgoing and incoming content, and the animation found in
+    /// `metadata`, are attached to it as a [`Replacement`]. Until the view is connected,
+    /// only the last content set is kept, and it is passed to the receiver on connection.
     pub fn set_with_metadata(&self, view: impl View, metadata: Metadata) {
-        (self.0.borrow())(AnyView::new(view), metadata)
+        let view = AnyView::new(view);
+        let transition = view
+            .downcast_ref::<IgnorableMetadata<Transition>>()
+            .map(|metadata| metadata.value.clone())
+            .unwrap_or_default();
+
+        let mut state = self.0.borrow_mut();
+        let previous = core::mem::replace(&mut state.transition, transition);
+        let replacement = Replacement {
+            removal: previous.phase(TransitionPhase::Removal),
+            insertion: state.transition.phase(TransitionPhase::Insertion),
+            animation: metadata.try_get::<Animation>(),
+        };
+        let metadata = metadata.with(replacement);
+        if state.receiver.is_none() {
+            state.pending = Some((view, metadata));
+            return;
+        }
+        drop(state);
+
+        if let Some(receiver) = &self.0.borrow().receiver {
+            receiver(view, metadata);
+        }
     }
 
     pub fn set(&self, view: impl View) {
@@ -101,14 +152,19 @@ impl Dynamic {
     ///
     /// A tuple containing the DynamicHandler and Dynamic view
     pub fn new() -> (DynamicHandler, Self) {
-        let handler = DynamicHandler(Rc::new(RefCell::new(Box::new(|_, _| {}))));
+        let handler = DynamicHandler(Rc::new(RefCell::new(DynamicState {
+            receiver: None,
+            pending: None,
+            transition: Transition::Identity,
+        })));
         (handler.clone(), Self(handler))
     }
 
     /// Creates a Dynamic view that watches a reactive value.
     ///
     /// The provided function is used to convert the value to a view.
-    /// Whenever the watched value changes, the view will update automatically.
+    /// Whenever the watched value changes, the view will update automatically,
+    /// keeping the metadata of the change, such as its animation.
     ///
     /// # Arguments
     ///
@@ -125,7 +181,9 @@ impl Dynamic {
         let (handle, dyanmic) = Self::new();
         handle.set(f(value.compute()));
 
-        let guard = value.watch(move |value| handle.set(f(value)));
+        let guard = value.add_watcher(move |value, metadata| {
+            handle.set_with_metadata(f(value), metadata);
+        });
 
         With::new(dyanmic, guard)
     }
@@ -140,9 +198,14 @@ impl Dynamic {
     ///
     /// * `receiver` - A function that receives view updates
     pub fn connect(self, receiver: impl Fn(AnyView, Metadata) + 'static) {
-        #[allow(unused_must_use)]
-        // It would be used on swift side
-        self.0 .0.replace(Box::new(receiver));
+        let pending = {
+            let mut state = self.0 .0.borrow_mut();
+            state.receiver = Some(Box::new(receiver));
+            state.pending.take()
+        };
+        if let (Some((view, metadata)), Some(receiver)) = (pending, &self.0 .0.borrow().receiver) {
+            receiver(view, metadata);
+        }
     }
 }
 
@@ -170,3 +233,113 @@ impl<V: View> View for Computed<V> {
         Dynamic::watch(self, |view| view)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::{cell::RefCell, time::Duration};
+
+    use waterui_reactive::{binding, ComputeExt};
+
+    use super::{Dynamic, Replacement};
+    use crate::{
+        animation::{Animation, Transition, TransitionEdge},
+        components::{IgnorableMetadata, With},
+        AnyView,
+    };
+
+    #[test]
+    fn replacements_carry_transitions() {
+        let visible = binding(false);
+        let animation = Animation::ease_in(Duration::from_millis(200));
+        let dynamic = Dynamic::watch(visible.clone().with(animation.clone()), |visible| {
+            let transition = if visible {
+                Transition::asymmetric(
+                    Transition::Slide(TransitionEdge::Bottom),
+                    Transition::Opacity,
+                )
+            } else {
+                Transition::Identity
+            };
+            IgnorableMetadata::new((), transition)
+        });
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let With {
+            view,
+            value: _guard,
+        } = dynamic;
+        view.connect({
+            let received = received.clone();
+            move |_, metadata| received.borrow_mut().push(metadata.get::<Replacement>())
+        });
+
+        // Only the replacements made after connecting are checked.
+        received.borrow_mut().clear();
+
+        visible.set(true);
+        visible.set(false);
+        let received = received.borrow();
+        assert_eq!(
+            received[0],
+            Replacement {
+                removal: Transition::Identity,
+                insertion: Transition::Slide(TransitionEdge::Bottom),
+                animation: Some(animation.clone()),
+            }
+        );
+        assert_eq!(received[1].removal, Transition::Opacity);
+        assert_eq!(received[1].insertion, Transition::Identity);
+    }
+
+    #[test]
+    fn watch_passes_its_initial_content_on_connect() {
+        let label = binding("first");
+        let With {
+            view,
+            value: _guard,
+        } = Dynamic::watch(label.clone(), |label| label);
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        view.connect({
+            let received = received.clone();
+            move |view: AnyView, metadata| {
+                received.borrow_mut().push((
+                    *view.downcast_ref::<&'static str>().unwrap(),
+                    metadata.get::<Replacement>().insertion,
+                ))
+            }
+        });
+        assert_eq!(*received.borrow(), [("first", Transition::Identity)]);
+
+        label.set("second");
+        assert_eq!(
+            *received.borrow(),
+            [
+                ("first", Transition::Identity),
+                ("second", Transition::Identity)
+            ]
+        );
+    }
+
+    #[test]
+    fn content_set_before_connecting_is_passed_on_connect() {
+        let (handler, dynamic) = Dynamic::new();
+        handler.set("first");
+        handler.set("latest");
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        dynamic.connect({
+            let received = received.clone();
+            move |view: AnyView, _| {
+                received
+                    .borrow_mut()
+                    .push(*view.downcast_ref::<&'static str>().unwrap())
+            }
+        });
+        assert_eq!(*received.borrow(), ["latest"]);
+
+        handler.set("after");
+        assert_eq!(*received.borrow(), ["latest", "after"]);
+    }
+}
@@ -11,7 +11,8 @@
 //!
 //! ## Metadata
 //!
-//! The module also provides facilities for attaching metadata to views.
+//! The module also provides facilities for attaching metadata to views, and for keeping
+//! values alive as long as a view with [`Retain`].
 pub mod anyview;
 pub use anyview::AnyView;
 pub mod dynamic;
@@ -19,6 +20,10 @@ mod label;
 pub use dynamic::Dynamic;
 pub mod metadata;
 pub use metadata::{IgnorableMetadata, Metadata};
+pub mod native;
+pub use native::Native;
+pub mod retain;
+pub use retain::Retain;
 
 use crate::View;
 
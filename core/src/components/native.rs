@@ -2,7 +2,10 @@
 
 use core::any::type_name;
 
-use crate::{Environment, View};
+use crate::{
+    accessibility::{Inspecting, Opaque},
+    AnyView, Environment, View,
+};
 
 /// A wrapper for platform-specific native UI components.
 ///
@@ -12,12 +15,16 @@ use crate::{Environment, View};
 /// # Panics
 ///
 /// Attempting to render a `Native<T>` view directly will panic. This type is intended
-/// to be handled by platform-specific rendering backends.
+/// to be handled by platform-specific rendering backends. Accessibility trees show it as
+/// an element without a label.
 #[derive(Debug)]
 pub struct Native<T>(pub T);
 
 impl<T: 'static> View for Native<T> {
-    fn body(self, _env: &Environment) -> impl View {
+    fn body(self, env: &Environment) -> impl View {
+        if env.get::<Inspecting>().is_some() {
+            return AnyView::new(Opaque::of::<Self>());
+        }
         panic!("Native view ({})", type_name::<T>())
     }
 }
@@ -0,0 +1,83 @@
+//! Keeping values alive as long as a view.
+//!
+//! A view is consumed when its body is built, so the values that must live as long as the
+//! view stays on screen, such as watcher guards or registrations, can't be kept in the view
+//! itself. [`Retain`] moves them into the environment of its content instead.
+
+use alloc::{boxed::Box, rc::Rc};
+use core::any::Any;
+
+use crate::{env::With, Environment, View};
+
+/// A view keeping a value alive as long as the renderer keeps its content.
+///
+/// The value is moved into the environment of the content, which renderers keep for as
+/// long as they show the content, since its actions are handled with it. Renderers don't
+/// need to know about `Retain`: it builds to an environment change, which they all support.
+#[derive(Debug)]
+#[must_use]
+pub struct Retain<V> {
+    content: V,
+    value: Box<dyn Any>,
+}
+
+impl<V> Retain<V> {
+    /// Creates a view showing `content` and keeping `value` alive as long as it.
+    pub fn new(content: V, value: impl Any) -> Self {
+        Self {
+            content,
+            value: Box::new(value),
+        }
+    }
+}
+
+/// The values retained for an environment, linked to those of its enclosing views.
+struct Retained {
+    _value: Box<dyn Any>,
+    _enclosing: Option<Rc<Retained>>,
+}
+
+impl<V: View> View for Retain<V> {
+    fn body(self, env: &Environment) -> impl View {
+        let retained = Retained {
+            _value: self.value,
+            _enclosing: env.get::<Rc<Retained>>().cloned(),
+        };
+        With::new(self.content, Rc::new(retained))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+
+    use super::Retain;
+    use crate::{components::Metadata, AnyView, Environment, View};
+
+    /// Builds `view` until it changes the environment, as a renderer does.
+    fn build(mut view: AnyView, env: &Environment) -> (AnyView, Environment) {
+        loop {
+            match view.downcast::<Metadata<Environment>>() {
+                Ok(metadata) => return (metadata.content, metadata.value),
+                Err(other) => view = AnyView::new(other.body(env)),
+            }
+        }
+    }
+
+    #[test]
+    fn values_live_as_long_as_the_environment() {
+        let (outer, inner) = (Rc::new("outer"), Rc::new("inner"));
+        let view = Retain::new(Retain::new((), inner.clone()), outer.clone());
+
+        let (content, enclosing) = build(AnyView::new(view), &Environment::new());
+        assert_eq!(Rc::strong_count(&outer), 2);
+        let (content, env) = build(content, &enclosing);
+        assert_eq!(Rc::strong_count(&inner), 2);
+
+        // The innermost environment keeps the values of the enclosing views as well.
+        drop((content, enclosing));
+        assert_eq!((Rc::strong_count(&outer), Rc::strong_count(&inner)), (2, 2));
+        drop(env);
+        assert_eq!((Rc::strong_count(&outer), Rc::strong_count(&inner)), (1, 1));
+    }
+}
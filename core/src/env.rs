@@ -5,7 +5,8 @@
 //! key-value store where types serve as unique keys.
 //!
 //! The main components are:
-//! - `Environment`: A store for typed values that can be passed between views
+//! - `Environment`: A store for typed values, plain or reactive, that can be passed between views
+//! - `EnvKey`: A typed key with a default value, allowing several values of the same type
 //! - `UseEnv`: A view that allows consuming environment values
 //! - `With`: A view that extends the environment with additional values
 //!
@@ -31,13 +32,22 @@ use core::{
     marker::PhantomData,
 };
 
-use alloc::{collections::BTreeMap, rc::Rc};
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+use waterui_reactive::{compute::IntoComputed, Computed};
 
 /// An `Environment` stores a map of types to values.
 ///
 /// Each type can have at most one value in the environment. The environment
 /// is used to pass contextual information from parent views to child views.
 ///
+/// Values may also be reactive: a `Computed<T>` inserted with
+/// [`Environment::insert_computed`] can be read with [`Environment::get_computed`],
+/// so views depending on it update in place instead of being rebuilt.
+///
+/// Cloning an environment is cheap. Values are stored in a chain of layers shared
+/// between clones, and modifying a shared environment pushes a new layer on top of it
+/// instead of copying every value.
+///
 /// # Examples
 ///
 /// ```
@@ -53,14 +63,74 @@ use alloc::{collections::BTreeMap, rc::Rc};
 /// ```
 #[derive(Debug, Clone, Default)]
 pub struct Environment {
-    map: BTreeMap<TypeId, Rc<dyn Any>>,
+    layer: Rc<Layer>,
+}
+
+/// The maximum number of layers in an environment before they are merged into one.
+const MAX_LAYER_DEPTH: usize = 32;
+
+/// A set of values stacked on top of a parent layer.
+///
+/// A `None` entry marks a value removed from this layer while still present in a parent.
+#[derive(Debug, Default)]
+struct Layer {
+    values: BTreeMap<TypeId, Option<Rc<dyn Any>>>,
+    parent: Option<Rc<Layer>>,
+    depth: usize,
+}
+
+impl Layer {
+    fn child(parent: Rc<Self>) -> Self {
+        Self {
+            values: BTreeMap::new(),
+            depth: parent.depth + 1,
+            parent: Some(parent),
+        }
+    }
+
+    fn get(&self, id: &TypeId) -> Option<&Rc<dyn Any>> {
+        let mut layer = self;
+        loop {
+            if let Some(value) = layer.values.get(id) {
+                return value.as_ref();
+            }
+            layer = layer.parent.as_deref()?;
+        }
+    }
+
+    /// Merges this layer and all of its parents into a single layer.
+    fn flatten(&self) -> Self {
+        let mut layers = Vec::with_capacity(self.depth + 1);
+        let mut layer = Some(self);
+        while let Some(current) = layer {
+            layers.push(current);
+            layer = current.parent.as_deref();
+        }
+
+        let mut values = BTreeMap::new();
+        for layer in layers.into_iter().rev() {
+            for (id, value) in &layer.values {
+                if let Some(value) = value {
+                    values.insert(*id, Some(value.clone()));
+                } else {
+                    values.remove(id);
+                }
+            }
+        }
+
+        Self {
+            values,
+            parent: None,
+            depth: 0,
+        }
+    }
 }
 
 use crate::{
     components::Metadata,
     handler::{HandlerFnOnce, HandlerOnce, IntoHandlerOnce},
-    plugin::Plugin,
-    view::{ConfigurableView, Modifier},
+    plugin::{self, Plugin, PluginError, PluginId, PluginInfo, Plugins},
+    view::{ConfigurableView, Modifier, Modifiers},
     View,
 };
 
@@ -70,31 +140,145 @@ impl Environment {
         Self::default()
     }
 
+    /// Returns the top layer, ready to be modified.
+    ///
+    /// If the layer is shared with other environments, a new layer is pushed on top of it.
+    fn layer_mut(&mut self) -> &mut Layer {
+        if Rc::get_mut(&mut self.layer).is_none() {
+            let layer = if self.layer.depth >= MAX_LAYER_DEPTH {
+                self.layer.flatten()
+            } else {
+                Layer::child(self.layer.clone())
+            };
+            self.layer = Rc::new(layer);
+        }
+        Rc::get_mut(&mut self.layer).expect("The top layer must be unique")
+    }
+
+    fn remove_by_id(&mut self, id: TypeId) {
+        if self.layer.get(&id).is_none() {
+            return;
+        }
+        let layer = self.layer_mut();
+        if layer.parent.is_some() {
+            layer.values.insert(id, None);
+        } else {
+            layer.values.remove(&id);
+        }
+    }
+
+    /// Returns every value of the environment, keyed by type.
+    pub(crate) fn snapshot(&self) -> BTreeMap<TypeId, Rc<dyn Any>> {
+        self.layer
+            .flatten()
+            .values
+            .into_iter()
+            .filter_map(|(id, value)| Some((id, value?)))
+            .collect()
+    }
+
+    /// Returns the value stored under `id`, without downcasting it.
+    pub(crate) fn get_raw(&self, id: TypeId) -> Option<Rc<dyn Any>> {
+        self.layer.get(&id).cloned()
+    }
+
+    /// Stores `value` under `id`, or removes the entry if `value` is `None`.
+    pub(crate) fn set_raw(&mut self, id: TypeId, value: Option<Rc<dyn Any>>) {
+        match value {
+            Some(value) => {
+                self.layer_mut().values.insert(id, Some(value));
+            }
+            None => self.remove_by_id(id),
+        }
+    }
+
     /// Installs a plugin into the environment.
     ///
     /// Plugins can register values or modifiers that will be available to all views.
+    /// Installing a plugin that is already installed does nothing, keeping the first one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dependency of the plugin isn't installed. Use
+    /// [`Environment::try_install`] to handle the error instead.
     pub fn install(mut self, plugin: impl Plugin) -> Self {
-        plugin.install(&mut self);
+        match self.try_install(plugin) {
+            Ok(()) | Err(PluginError::AlreadyInstalled(_)) => {}
+            Err(error) => panic!("{error}"),
+        }
         self
     }
 
+    /// Installs a plugin into the environment, recording it in the plugin registry.
+    ///
+    /// Installation fails if the plugin is already installed, or if one of its dependencies
+    /// is missing. Dependencies are never installed or reordered automatically: they must
+    /// be installed first.
+    pub fn try_install<P: Plugin>(&mut self, plugin: P) -> Result<(), PluginError> {
+        plugin::install(self, plugin)
+    }
+
+    /// Uninstalls the plugin `P`, restoring every value it replaced.
+    ///
+    /// Fails if the plugin isn't installed or if another installed plugin depends on it.
+    pub fn uninstall<P: Plugin>(&mut self) -> Result<(), PluginError> {
+        plugin::uninstall(self, PluginId::of::<P>())
+    }
+
+    /// Returns `true` if the plugin `P` is installed.
+    pub fn is_installed<P: Plugin>(&self) -> bool {
+        self.plugins()
+            .any(|plugin| plugin.id() == PluginId::of::<P>())
+    }
+
+    /// Returns the installed plugins, in installation order.
+    pub fn plugins(&self) -> impl Iterator<Item = &PluginInfo> {
+        self.get::<Plugins>()
+            .map(|plugins| plugins.entries.as_slice())
+            .unwrap_or_default()
+            .iter()
+    }
+
     /// Inserts a value into the environment.
     ///
     /// If a value of the same type already exists, it will be replaced.
+    /// A reactive value of the same type inserted by [`Environment::insert_computed`]
+    /// is replaced as well.
     pub fn insert<T: 'static>(&mut self, value: T) {
-        self.map.insert(TypeId::of::<T>(), Rc::new(value));
+        self.remove_by_id(TypeId::of::<Computed<T>>());
+        self.layer_mut()
+            .values
+            .insert(TypeId::of::<T>(), Some(Rc::new(value)));
+    }
+
+    /// Inserts a reactive value into the environment.
+    ///
+    /// The value is stored as a `Computed<T>` and replaces any plain value of type `T`.
+    /// Read it back with [`Environment::get_computed`] or by extracting `Use<Computed<T>>`.
+    pub fn insert_computed<T: 'static>(&mut self, value: impl IntoComputed<T>) {
+        self.remove::<T>();
+        self.insert(value.into_computed());
     }
 
     /// Inserts a view modifier into the environment.
     ///
-    /// Modifiers can be retrieved and applied to views of the specified type.
-    pub fn insert_modifier<V: ConfigurableView>(&mut self, modifier: Modifier<V>) {
-        self.insert(modifier);
+    /// The modifier is pushed onto the stack of modifiers for views of type `V`,
+    /// and runs after the modifiers inserted before it.
+    pub fn insert_modifier<V: ConfigurableView>(&mut self, modifier: impl Into<Modifier<V>>) {
+        let mut modifiers = self.get::<Modifiers<V>>().cloned().unwrap_or_default();
+        modifiers.push(modifier.into());
+        self.insert(modifiers);
+    }
+
+    /// Adds a view modifier and returns the modified environment.
+    pub fn with_modifier<V: ConfigurableView>(mut self, modifier: impl Into<Modifier<V>>) -> Self {
+        self.insert_modifier(modifier);
+        self
     }
 
     /// Removes a value from the environment by its type.
     pub fn remove<T: 'static>(&mut self) {
-        self.map.remove(&TypeId::of::<T>());
+        self.remove_by_id(TypeId::of::<T>());
     }
 
     /// Adds a value to the environment and returns the modified environment.
@@ -105,14 +289,114 @@ impl Environment {
         self
     }
 
+    /// Adds a reactive value to the environment and returns the modified environment.
+    pub fn with_computed<T: 'static>(mut self, value: impl IntoComputed<T>) -> Self {
+        self.insert_computed(value);
+        self
+    }
+
     /// Retrieves a reference to a value from the environment by its type.
     ///
     /// Returns `None` if no value of the requested type exists.
     pub fn get<T: 'static>(&self) -> Option<&T> {
-        self.map
+        self.layer
             .get(&TypeId::of::<T>())
             .map(|v| v.downcast_ref::<T>().unwrap())
     }
+
+    /// Inserts a value into the environment under the key `K`.
+    ///
+    /// Values stored under different keys never collide, even if they share a type.
+    pub fn insert_key<K: EnvKey>(&mut self, value: K::Value) {
+        self.insert(EnvValue::<K>(value));
+    }
+
+    /// Adds a value under the key `K` and returns the modified environment.
+    pub fn with_key<K: EnvKey>(mut self, value: K::Value) -> Self {
+        self.insert_key::<K>(value);
+        self
+    }
+
+    /// Removes the value stored under the key `K`.
+    pub fn remove_key<K: EnvKey>(&mut self) {
+        self.remove::<EnvValue<K>>();
+    }
+
+    /// Retrieves the value stored under the key `K`, if it was set.
+    pub fn try_get_key<K: EnvKey>(&self) -> Option<&K::Value> {
+        self.get::<EnvValue<K>>().map(|value| &value.0)
+    }
+
+    /// Retrieves the value stored under the key `K`.
+    ///
+    /// Falls back to [`EnvKey::default`] if no value was set.
+    pub fn get_key<K: EnvKey>(&self) -> K::Value {
+        self.try_get_key::<K>()
+            .cloned()
+            .unwrap_or_else(<K as EnvKey>::default)
+    }
+
+    /// Retrieves a value from the environment as a reactive computation.
+    ///
+    /// A value inserted with [`Environment::insert_computed`] is returned as is,
+    /// while a plain value is wrapped into a constant computation.
+    /// Returns `None` if no value of the requested type exists.
+    pub fn get_computed<T: 'static + Clone>(&self) -> Option<Computed<T>> {
+        if let Some(computed) = self.get::<Computed<T>>() {
+            Some(computed.clone())
+        } else {
+            self.get::<T>().cloned().map(Computed::constant)
+        }
+    }
+}
+
+/// A typed key for values stored in the environment.
+///
+/// Keys are usually empty marker types. Unlike plain values, which are keyed by
+/// their own type, several values of the same type can coexist under different keys,
+/// and reading a key never fails because it falls back to a default.
+///
+/// # Examples
+///
+/// ```
+/// use waterui_core::{Color, Environment, env::EnvKey};
+///
+/// struct AccentColor;
+///
+/// impl EnvKey for AccentColor {
+///     type Value = Color;
+///
+///     fn default() -> Color {
+///         Color::from((0, 122, 255))
+///     }
+/// }
+///
+/// let env = Environment::new().with_key::<AccentColor>(Color::from((255, 45, 85)));
+/// let accent = env.get_key::<AccentColor>();
+/// ```
+pub trait EnvKey: 'static {
+    /// The type of the value stored under this key.
+    type Value: Clone + 'static;
+
+    /// The value used when no value was set for this key.
+    fn default() -> Self::Value;
+}
+
+/// A value stored in the environment under the key `K`.
+///
+/// This wrapper keeps values of different keys apart in the type-based storage.
+pub struct EnvValue<K: EnvKey>(pub K::Value);
+
+impl<K: EnvKey> Debug for EnvValue<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(core::any::type_name::<Self>())
+    }
+}
+
+impl<K: EnvKey> Clone for EnvValue<K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
 }
 
 /// A view that provides access to the environment.
@@ -184,6 +468,62 @@ where
     }
 }
 
+#[cfg(test)]
+mod test {
+    use waterui_reactive::{binding, Compute};
+
+    use super::{EnvKey, Environment};
+
+    #[test]
+    pub fn shared_layers() {
+        let parent = Environment::new().with(1i32).with("parent");
+        let mut child = parent.clone().with(2i32);
+        child.remove::<&str>();
+
+        assert_eq!(parent.get::<i32>(), Some(&1));
+        assert_eq!(parent.get::<&str>(), Some(&"parent"));
+        assert_eq!(child.get::<i32>(), Some(&2));
+        assert_eq!(child.get::<&str>(), None);
+    }
+
+    #[test]
+    pub fn computed_values() {
+        let value = binding(1i32);
+        let env = Environment::new().with_computed::<i32>(value.clone());
+        let computed = env.get_computed::<i32>().unwrap();
+        value.set(2);
+        assert_eq!(computed.compute(), 2);
+
+        let env = env.with(3i32);
+        assert_eq!(env.get_computed::<i32>().unwrap().compute(), 3);
+    }
+
+    #[test]
+    pub fn keys() {
+        struct Primary;
+        struct Accent;
+
+        impl EnvKey for Primary {
+            type Value = i32;
+            fn default() -> i32 {
+                1
+            }
+        }
+
+        impl EnvKey for Accent {
+            type Value = i32;
+            fn default() -> i32 {
+                2
+            }
+        }
+
+        let env = Environment::new().with_key::<Primary>(10);
+        assert_eq!(env.get_key::<Primary>(), 10);
+        assert_eq!(env.get_key::<Accent>(), 2);
+        assert_eq!(env.get::<i32>(), None);
+    }
+}
+
 mod ffi {
     use std::sync::Arc;
 
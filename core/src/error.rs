@@ -0,0 +1,134 @@
+//! # Error reporting
+//!
+//! Views report failures to the [`ErrorSink`] of their environment, set by an error
+//! boundary, which replaces its content with a fallback.
+//!
+//! With the standard library, panics in the `body` of views are failures too: renderers
+//! resolve views with [`body_or_report`], which catches the panics of views inside a
+//! boundary and reports them as [`PanicError`]s.
+
+use alloc::{boxed::Box, rc::Rc, string::String};
+use core::fmt::{self, Display};
+
+use crate::{AnyView, Environment, View};
+
+/// A boxed error, as reported to an [`ErrorSink`].
+pub type BoxedError = Box<dyn core::error::Error>;
+
+/// Reports errors to the nearest error boundary, which replaces its content with its
+/// fallback.
+///
+/// The sink is found in the environment, and can be extracted by handlers.
+#[derive(Clone)]
+pub struct ErrorSink(Rc<dyn Fn(BoxedError)>);
+
+impl_debug!(ErrorSink);
+impl_extractor!(ErrorSink);
+
+impl ErrorSink {
+    /// Creates a sink calling `report` with every error.
+    pub fn new(report: impl Fn(BoxedError) + 'static) -> Self {
+        Self(Rc::new(report))
+    }
+
+    /// Returns the sink of the environment, if a view is in an error boundary.
+    pub fn of(env: &Environment) -> Option<Self> {
+        env.get::<Self>().cloned()
+    }
+
+    /// Reports `error`.
+    pub fn report(&self, error: impl Into<BoxedError>) {
+        (self.0)(error.into())
+    }
+}
+
+/// An error raised by a panic while building a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicError {
+    message: String,
+}
+
+impl PanicError {
+    /// Creates an error from the message of a panic.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error from the payload of a caught panic.
+    pub fn from_payload(payload: &(dyn core::any::Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| String::from(*message))
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("Unknown panic"));
+        Self { message }
+    }
+
+    /// Returns the message of the panic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for PanicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "View panicked: {}", self.message)
+    }
+}
+
+impl core::error::Error for PanicError {}
+
+/// Returns the body of `view`, reporting a panic to the [`ErrorSink`] of `env` instead of
+/// unwinding, if there is one.
+///
+/// A view that panicked is replaced by an empty view, until its boundary shows its fallback.
+#[cfg(feature = "std")]
+pub fn body_or_report(view: AnyView, env: &Environment) -> AnyView {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let Some(sink) = ErrorSink::of(env) else {
+        return AnyView::new(view.body(env));
+    };
+    match catch_unwind(AssertUnwindSafe(|| AnyView::new(view.body(env)))) {
+        Ok(body) => body,
+        Err(payload) => {
+            sink.report(PanicError::from_payload(&*payload));
+            AnyView::new(())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{rc::Rc, string::ToString, vec::Vec};
+    use core::cell::RefCell;
+
+    use super::{body_or_report, ErrorSink};
+    use crate::{AnyView, Environment, View};
+
+    struct Failing;
+
+    impl View for Failing {
+        fn body(self, _env: &Environment) -> impl View {
+            panic!("no data");
+        }
+    }
+
+    #[test]
+    fn panics_are_reported() {
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let env = Environment::new().with(ErrorSink::new({
+            let errors = errors.clone();
+            move |error| errors.borrow_mut().push(error.to_string())
+        }));
+
+        let body = body_or_report(AnyView::new(Failing), &env);
+        assert!(body.is::<()>());
+        let body = body_or_report(AnyView::new(|| Failing), &env);
+        assert!(body.is::<Failing>());
+        ErrorSink::of(&env).unwrap().report("offline");
+        assert_eq!(*errors.borrow(), ["View panicked: no data", "offline"]);
+    }
+}
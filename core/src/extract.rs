@@ -3,12 +3,17 @@
 //! It defines the `Extractor` trait for types that can be extracted from an
 //! Environment, along with implementations for common types.
 //! The `Use<T>` wrapper provides a convenient way to extract specific types
-//! from the environment.
+//! from the environment, and `Computed<T>` extracts a value that keeps
+//! tracking the environment when it is reactive.
+//!
+//! Tuples of extractors are extractors themselves, and structs whose fields are all
+//! extractors can implement the trait with `#[derive(Extractor)]`. Both report every
+//! field that failed to extract, not only the first one.
 
 use core::any::type_name;
 
-use crate::Environment;
-use alloc::format;
+use crate::{env::EnvKey, Computed, Environment};
+use alloc::{format, vec::Vec};
 use anyhow::Error;
 /// A trait for extracting values from an Environment.
 ///
@@ -30,6 +35,11 @@ pub trait Extractor: 'static + Sized {
 /// This wrapper enables extracting values by type from an Environment.
 pub struct Use<T: 'static>(pub T);
 
+/// Wrapper struct for values stored in the Environment under a typed key.
+///
+/// Extraction never fails: the key's default value is used if none was set.
+pub struct UseKey<K: EnvKey>(pub K::Value);
+
 impl Extractor for Environment {
     /// Extracts the Environment itself by creating a clone.
     fn extract(env: &Environment) -> Result<Self, Error> {
@@ -47,6 +57,56 @@ impl<T: Extractor> Extractor for Option<T> {
     }
 }
 
+impl<T: Extractor> Extractor for Result<T, Error> {
+    /// Returns the result of the extraction without failing.
+    ///
+    /// This lets handlers deal with missing dependencies themselves.
+    fn extract(env: &Environment) -> Result<Self, Error> {
+        Ok(Extractor::extract(env))
+    }
+}
+
+/// Combines the errors of several failed extractions into a single error.
+///
+/// The message names the extracted type and lists every failure, so that all missing
+/// dependencies are reported at once.
+///
+/// # Parameters
+/// * `target` - The name of the type being extracted
+/// * `errors` - The errors of the fields that failed to extract
+pub fn combine_errors(target: &str, errors: impl IntoIterator<Item = Error>) -> Error {
+    let mut message = format!("Failed to extract `{target}`:");
+    for error in errors {
+        message.push_str("\n  - ");
+        message.push_str(&format!("{error}"));
+    }
+    Error::msg(message)
+}
+
+macro_rules! impl_extractor_for_tuple {
+    ($($ty:ident),*) => {
+        #[allow(non_snake_case)]
+        #[allow(unused_mut)]
+        #[allow(unused_variables)]
+        impl<$($ty: Extractor,)*> Extractor for ($($ty,)*) {
+            /// Extracts every element of the tuple, reporting all failures together.
+            fn extract(env: &Environment) -> Result<Self, Error> {
+                let mut errors: Vec<Error> = Vec::new();
+                $(
+                    let $ty = <$ty as Extractor>::extract(env).map_err(|error| errors.push(error)).ok();
+                )*
+                if errors.is_empty() {
+                    Ok(($($ty.unwrap(),)*))
+                } else {
+                    Err(combine_errors(type_name::<Self>(), errors))
+                }
+            }
+        }
+    };
+}
+
+tuples!(impl_extractor_for_tuple);
+
 impl<T: 'static + Clone> Extractor for Use<T> {
     /// Extracts a value of type T from the Environment.
     ///
@@ -63,3 +123,49 @@ impl<T: 'static + Clone> Extractor for Use<T> {
         }
     }
 }
+
+impl<T: 'static + Clone> Extractor for Computed<T> {
+    /// Extracts a value of type T from the Environment as a reactive computation.
+    ///
+    /// Unlike `Use<Computed<T>>`, which only finds values inserted with
+    /// `Environment::insert_computed`, this falls back to a constant computation
+    /// of a plain value.
+    ///
+    /// # Errors
+    /// Returns an error if the requested type is not present in the Environment.
+    fn extract(env: &Environment) -> Result<Self, Error> {
+        env.get_computed::<T>().ok_or_else(|| {
+            Error::msg(format!(
+                "Environment value `{}` not found",
+                type_name::<T>()
+            ))
+        })
+    }
+}
+
+impl<K: EnvKey> Extractor for UseKey<K> {
+    /// Extracts the value stored under the key `K`, or its default.
+    fn extract(env: &Environment) -> Result<Self, Error> {
+        Ok(Self(env.get_key::<K>()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tuple_reports_all_failures() {
+        let env = Environment::new().with(1i32);
+        let Ok((Use(value), missing)) = <(Use<i32>, Option<Use<u8>>)>::extract(&env) else {
+            panic!("extraction failed");
+        };
+        assert_eq!(value, 1);
+        assert!(missing.is_none());
+
+        let Err(error) = <(Use<i32>, Use<u8>, Use<u16>)>::extract(&env) else {
+            panic!("extraction succeeded");
+        };
+        assert_eq!(format!("{error}").matches("\n  - ").count(), 2);
+    }
+}
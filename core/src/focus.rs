@@ -0,0 +1,424 @@
+//! # Focus
+//!
+//! Ordered keyboard focus, computed from the view tree.
+//!
+//! Focusable views register a [`FocusElement`] in the [`FocusScope`] of their environment
+//! as they are built, so the elements of a scope are in the order their views were built.
+//! This is the tree order for views built together, but views built later, such as the
+//! new content of a dynamic view, join the end of the sequence. Elements can be given an
+//! explicit order, which takes precedence over the build order, like a tab index.
+//! Scopes nest: a nested scope takes the place of its elements in the sequence of its
+//! parent, while navigating inside it stays inside it, as for a sheet.
+//!
+//! Renderers move the focus with the scope of the focused view, for instance when Tab is
+//! pressed, and handlers extract the scope of their view to do the same:
+//!
+//! ```rust
+//! use waterui_core::Binding;
+//! use waterui_core::focus::{FocusElement, FocusScope};
+//!
+//! let scope = FocusScope::new();
+//! let (username, password) = (Binding::container(false), Binding::container(false));
+//! let _username = scope.register(FocusElement::new(username.clone()));
+//! let _password = scope.register(FocusElement::new(password.clone()));
+//!
+//! // When Tab is pressed, or in the action of a "Next" button extracting the `FocusScope`:
+//! scope.focus_next();
+//! assert!(username.get());
+//! scope.focus_next();
+//! assert!(password.get() && !username.get());
+//! ```
+//!
+//! When a nested scope goes away, the focus returns to the element that was focused when
+//! the scope was created.
+
+use alloc::{
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+use core::cell::{Cell, RefCell};
+
+use waterui_reactive::Binding;
+
+use crate::{plugin::Plugin, Environment};
+
+/// A focusable element, registered in a [`FocusScope`].
+#[derive(Debug, Clone)]
+pub struct FocusElement {
+    /// Whether the element is focused. The engine sets it to move the focus.
+    pub focused: Binding<bool>,
+    /// The position of the element in the focus sequence, lower first. Elements default
+    /// to 0, and keep their registration order among equal orders.
+    pub order: i32,
+    /// Whether the element is focused when its scope appears without focus.
+    pub default: bool,
+}
+
+impl FocusElement {
+    /// Creates an element focused when `focused` is `true`.
+    pub fn new(focused: Binding<bool>) -> Self {
+        Self {
+            focused,
+            order: 0,
+            default: false,
+        }
+    }
+
+    /// Sets the position of the element in the focus sequence.
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Focuses the element when its scope appears without focus.
+    pub fn default_focus(mut self) -> Self {
+        self.default = true;
+        self
+    }
+}
+
+enum Target {
+    Element(FocusElement),
+    Scope(Weak<Node>),
+}
+
+struct Slot {
+    id: u64,
+    order: i32,
+    target: Target,
+}
+
+#[derive(Default)]
+struct Node {
+    /// The parent scope, with the identifier of this scope in it.
+    parent: Option<(FocusScope, u64)>,
+    slots: RefCell<Vec<Slot>>,
+    next_id: Cell<u64>,
+    /// The element focused when this scope was created, focused again when it goes away.
+    restore: Option<Binding<bool>>,
+}
+
+impl Node {
+    fn insert(&self, order: i32, target: Target) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.slots.borrow_mut().push(Slot { id, order, target });
+        id
+    }
+
+    fn remove(&self, id: u64) {
+        self.slots.borrow_mut().retain(|slot| slot.id != id);
+    }
+
+    /// Returns the elements of this scope and of the scopes nested in it, in focus order.
+    fn sequence(&self) -> Vec<FocusElement> {
+        let mut slots: Vec<(i32, Option<FocusElement>, Option<Rc<Node>>)> = self
+            .slots
+            .borrow()
+            .iter()
+            .map(|slot| match &slot.target {
+                Target::Element(element) => (slot.order, Some(element.clone()), None),
+                Target::Scope(scope) => (slot.order, None, scope.upgrade()),
+            })
+            .collect();
+        slots.sort_by_key(|(order, _, _)| *order);
+
+        let mut elements = Vec::new();
+        for (_, element, scope) in slots {
+            elements.extend(element);
+            if let Some(scope) = scope {
+                elements.extend(scope.sequence());
+            }
+        }
+        elements
+    }
+
+    fn focused(&self) -> Option<Binding<bool>> {
+        self.sequence()
+            .into_iter()
+            .map(|element| element.focused)
+            .find(Binding::get)
+    }
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        let Some((parent, id)) = self.parent.take() else {
+            return;
+        };
+        parent.0.remove(id);
+
+        let focused = self.focused();
+        if let Some(focused) = &focused {
+            focused.set(false);
+        }
+        // The focus only returns if it was inside this scope, or lost.
+        if focused.is_some() || parent.root().0.focused().is_none() {
+            if let Some(restore) = &self.restore {
+                restore.set(true);
+            }
+        }
+    }
+}
+
+/// A sequence of focusable elements, navigated with the keyboard.
+///
+/// The scope of a view is found in its environment, and can be extracted by handlers.
+#[derive(Clone, Default)]
+pub struct FocusScope(Rc<Node>);
+
+impl_debug!(FocusScope);
+impl_extractor!(FocusScope);
+
+impl FocusScope {
+    /// Creates a root scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the scope of the environment, if a [`FocusEngine`] is installed.
+    pub fn of(env: &Environment) -> Option<Self> {
+        env.get::<Self>().cloned()
+    }
+
+    fn root(&self) -> FocusScope {
+        let mut scope = self.clone();
+        while let Some((parent, _)) = scope.0.parent.clone() {
+            scope = parent;
+        }
+        scope
+    }
+
+    /// Creates a scope nested in this one, at the current end of its sequence.
+    ///
+    /// The scope stays in the sequence until it is dropped, and the focus then returns to
+    /// the element focused when it was created.
+    pub fn child(&self) -> Self {
+        let restore = self.root().0.focused();
+        let node = Rc::new_cyclic(|node| Node {
+            parent: Some((self.clone(), self.0.insert(0, Target::Scope(node.clone())))),
+            slots: RefCell::default(),
+            next_id: Cell::default(),
+            restore,
+        });
+        Self(node)
+    }
+
+    /// Registers `element` at the current end of the sequence, until the returned
+    /// registration is dropped.
+    ///
+    /// A default element is focused right away if nothing in this scope is focused, taking
+    /// the focus from the element focused outside of it.
+    #[must_use = "The element is unregistered when the registration is dropped"]
+    pub fn register(&self, element: FocusElement) -> FocusRegistration {
+        if element.default && !self.has_focus() {
+            // The focus moves here from wherever it is outside this scope.
+            if let Some(focused) = self.root().0.focused() {
+                focused.set(false);
+            }
+            element.focused.set(true);
+        }
+        let id = self
+            .0
+            .insert(element.order, Target::Element(element.clone()));
+        FocusRegistration {
+            scope: Rc::downgrade(&self.0),
+            id,
+        }
+    }
+
+    /// Returns whether an element of this scope is focused.
+    pub fn has_focus(&self) -> bool {
+        self.0.focused().is_some()
+    }
+
+    /// Returns the number of elements in this scope and its nested scopes.
+    pub fn len(&self) -> usize {
+        self.0.sequence().len()
+    }
+
+    /// Returns whether this scope has no element.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Moves the focus to the element at `index` of the sequence given its current index.
+    fn move_focus(&self, target: impl FnOnce(Option<usize>, usize) -> Option<usize>) -> bool {
+        let sequence = self.0.sequence();
+        let current = sequence.iter().position(|element| element.focused.get());
+        let Some(target) = target(current, sequence.len()) else {
+            return false;
+        };
+        if current == Some(target) {
+            return true;
+        }
+        if let Some(current) = current {
+            sequence[current].focused.set(false);
+        }
+        sequence[target].focused.set(true);
+        true
+    }
+
+    /// Focuses the next element, wrapping around, or the first one if nothing is focused.
+    ///
+    /// Returns whether an element is focused.
+    pub fn focus_next(&self) -> bool {
+        self.move_focus(|current, len| {
+            (len > 0).then(|| current.map_or(0, |current| (current + 1) % len))
+        })
+    }
+
+    /// Focuses the previous element, wrapping around, or the last one if nothing is
+    /// focused.
+    ///
+    /// Returns whether an element is focused.
+    pub fn focus_previous(&self) -> bool {
+        self.move_focus(|current, len| {
+            (len > 0).then(|| current.unwrap_or(0).checked_sub(1).unwrap_or(len - 1))
+        })
+    }
+
+    /// Focuses the first element.
+    ///
+    /// Returns whether an element is focused.
+    pub fn focus_first(&self) -> bool {
+        self.move_focus(|_, len| (len > 0).then_some(0))
+    }
+
+    /// Focuses the default element, or the first one if there is none.
+    ///
+    /// Returns whether an element is focused.
+    pub fn focus_default(&self) -> bool {
+        let default = self.0.sequence().iter().position(|element| element.default);
+        self.move_focus(|_, len| default.or((len > 0).then_some(0)))
+    }
+
+    /// Removes the focus from this scope.
+    pub fn unfocus(&self) {
+        if let Some(focused) = self.0.focused() {
+            focused.set(false);
+        }
+    }
+}
+
+/// The registration of a focusable element, unregistering it when dropped.
+#[derive(Debug)]
+pub struct FocusRegistration {
+    scope: Weak<Node>,
+    id: u64,
+}
+
+impl Drop for FocusRegistration {
+    fn drop(&mut self) {
+        if let Some(scope) = self.scope.upgrade() {
+            scope.remove(self.id);
+        }
+    }
+}
+
+/// The focus of a window, moved by renderers.
+///
+/// Installing the engine as a plugin sets its root scope in the environment.
+#[derive(Debug, Clone, Default)]
+pub struct FocusEngine {
+    root: FocusScope,
+}
+
+impl FocusEngine {
+    /// Creates an engine with an empty root scope.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the root scope.
+    pub fn root(&self) -> &FocusScope {
+        &self.root
+    }
+
+    /// Returns the scope to navigate from the focused view, given its environment.
+    ///
+    /// Without a focused view, this is the root scope.
+    pub fn scope(&self, focus: Option<&Environment>) -> FocusScope {
+        focus
+            .and_then(FocusScope::of)
+            .unwrap_or_else(|| self.root.clone())
+    }
+}
+
+impl Plugin for FocusEngine {
+    fn install(self, env: &mut Environment) {
+        env.insert(self.root.clone());
+        env.insert(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use waterui_reactive::Binding;
+
+    use super::{FocusElement, FocusScope};
+
+    fn fields(count: usize) -> Vec<Binding<bool>> {
+        (0..count).map(|_| Binding::container(false)).collect()
+    }
+
+    fn focused(fields: &[Binding<bool>]) -> Vec<usize> {
+        (0..fields.len()).filter(|&i| fields[i].get()).collect()
+    }
+
+    #[test]
+    fn navigation() {
+        let root = FocusScope::new();
+        let fields = fields(4);
+        let _registrations: Vec<_> = [0, 0, -1, 0]
+            .into_iter()
+            .zip(&fields)
+            .map(|(order, field)| root.register(FocusElement::new(field.clone()).order(order)))
+            .collect();
+
+        // The element ordered first comes first, then the others in registration order.
+        assert!(root.focus_next());
+        assert_eq!(focused(&fields), [2]);
+        root.focus_next();
+        root.focus_next();
+        assert_eq!(focused(&fields), [1]);
+        root.focus_next();
+        root.focus_next();
+        assert_eq!(focused(&fields), [2]);
+        root.focus_previous();
+        assert_eq!(focused(&fields), [3]);
+        root.focus_first();
+        assert_eq!(focused(&fields), [2]);
+        root.unfocus();
+        assert!(!root.has_focus());
+        root.focus_previous();
+        assert_eq!(focused(&fields), [3]);
+        assert!(!FocusScope::new().focus_next());
+    }
+
+    #[test]
+    fn scopes_default_focus_and_restoration() {
+        let root = FocusScope::new();
+        let fields = fields(4);
+        let _first = root.register(FocusElement::new(fields[0].clone()));
+        let _second = root.register(FocusElement::new(fields[1].clone()));
+        root.focus_next();
+        root.focus_next();
+        assert_eq!(focused(&fields), [1]);
+
+        let sheet = root.child();
+        let _name = sheet.register(FocusElement::new(fields[2].clone()));
+        let _email = sheet.register(FocusElement::new(fields[3].clone()).default_focus());
+        // The sheet had no focus, so its default element takes the focus.
+        assert_eq!(focused(&fields), [3]);
+        sheet.focus_next();
+        assert_eq!(focused(&fields), [2]);
+        assert_eq!(root.len(), 4);
+
+        drop(sheet);
+        assert_eq!(focused(&fields), [1]);
+        assert_eq!(root.len(), 2);
+    }
+}
@@ -0,0 +1,329 @@
+//! Gestures composed of other gestures.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::time::Duration;
+
+use super::{Gesture, GesturePhase, PointerEvent, PointerId, PointerPhase};
+use crate::{shape::Point, Environment};
+
+/// Returns `Began` the first time a composed gesture becomes active, then `Changed`.
+const fn activate(previous: GesturePhase) -> GesturePhase {
+    if previous.is_active() {
+        GesturePhase::Changed
+    } else {
+        GesturePhase::Began
+    }
+}
+
+/// Two gestures recognized at the same time, like a magnification and a rotation.
+///
+/// Both gestures receive every event. The composed gesture is active as soon as either
+/// is recognized, and ends once both are finished, if either of them ended.
+///
+/// Created by [`GestureExt::simultaneously`](super::GestureExt::simultaneously).
+#[derive(Debug, Clone)]
+pub struct Simultaneous<A, B> {
+    first: A,
+    second: B,
+    phase: GesturePhase,
+}
+
+impl<A: Gesture, B: Gesture> Simultaneous<A, B> {
+    /// Composes two gestures recognized at the same time.
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            phase: GesturePhase::Possible,
+        }
+    }
+
+    fn update(&mut self) -> GesturePhase {
+        let phases = [self.first.phase(), self.second.phase()];
+        self.phase = if phases.iter().all(|phase| phase.is_finished()) {
+            if phases.contains(&GesturePhase::Ended) {
+                GesturePhase::Ended
+            } else if phases.contains(&GesturePhase::Cancelled) {
+                GesturePhase::Cancelled
+            } else {
+                GesturePhase::Failed
+            }
+        } else if phases.iter().any(|phase| phase.is_recognized()) {
+            activate(self.phase)
+        } else {
+            GesturePhase::Possible
+        };
+        self.phase
+    }
+}
+
+impl<A: Gesture, B: Gesture> Gesture for Simultaneous<A, B> {
+    type Value = (Option<A::Value>, Option<B::Value>);
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        self.first.handle(event, env);
+        self.second.handle(event, env);
+        self.update()
+    }
+
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        self.first.tick(now, env);
+        self.second.tick(now, env);
+        self.update()
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        match (self.first.value(), self.second.value()) {
+            (None, None) => None,
+            values => Some(values),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.phase = GesturePhase::Possible;
+    }
+}
+
+/// The value of a [`Sequence`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceValue<A, B> {
+    /// The first gesture is in progress.
+    First(A),
+    /// The first gesture ended with the given value, and the second one started.
+    Second(A, Option<B>),
+}
+
+/// A gesture recognized once another one ends, like a drag after a long press.
+///
+/// When the first gesture ends, the pointers still down are handed over to the second
+/// one, as if they were just pressed. The composed gesture fails if either gesture fails.
+///
+/// Created by [`GestureExt::sequenced`](super::GestureExt::sequenced).
+#[derive(Debug, Clone)]
+pub struct Sequence<A: Gesture, B> {
+    first: A,
+    second: B,
+    phase: GesturePhase,
+    /// The final value of the first gesture, once it ended.
+    first_value: Option<A::Value>,
+    pointers: BTreeMap<PointerId, Point>,
+}
+
+impl<A: Gesture, B: Gesture> Sequence<A, B> {
+    /// Composes a gesture recognized once `first` ends.
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            phase: GesturePhase::Possible,
+            first_value: None,
+            pointers: BTreeMap::new(),
+        }
+    }
+
+    /// Starts the second gesture if the first one just ended.
+    fn hand_over(&mut self, time: Duration, env: &Environment) {
+        if self.first_value.is_some() || self.first.phase() != GesturePhase::Ended {
+            return;
+        }
+        self.first_value = self.first.value();
+        if self.first_value.is_none() {
+            return;
+        }
+        for (&pointer, &location) in &self.pointers {
+            let down = PointerEvent::new(pointer, PointerPhase::Down, location, time);
+            self.second.handle(&down, env);
+        }
+    }
+
+    fn update(&mut self) -> GesturePhase {
+        let phase = if self.first_value.is_some() {
+            self.second.phase()
+        } else {
+            self.first.phase()
+        };
+        self.phase = match (self.first_value.is_some(), phase) {
+            (false, GesturePhase::Possible) => GesturePhase::Possible,
+            (true, GesturePhase::Ended) => GesturePhase::Ended,
+            // A first gesture ending without a value can't be continued.
+            (false, GesturePhase::Ended) | (_, GesturePhase::Failed) => GesturePhase::Failed,
+            (_, GesturePhase::Cancelled) => GesturePhase::Cancelled,
+            _ => activate(self.phase),
+        };
+        self.phase
+    }
+}
+
+impl<A: Gesture, B: Gesture> Gesture for Sequence<A, B> {
+    type Value = SequenceValue<A::Value, B::Value>;
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        match event.phase {
+            PointerPhase::Down | PointerPhase::Move => {
+                self.pointers.insert(event.pointer, event.location);
+            }
+            PointerPhase::Up | PointerPhase::Cancel => {
+                self.pointers.remove(&event.pointer);
+            }
+        }
+
+        if self.first_value.is_some() {
+            self.second.handle(event, env);
+        } else {
+            self.first.handle(event, env);
+            self.hand_over(event.time, env);
+        }
+        self.update()
+    }
+
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        if self.first_value.is_some() {
+            self.second.tick(now, env);
+        } else {
+            self.first.tick(now, env);
+            self.hand_over(now, env);
+        }
+        self.update()
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        match &self.first_value {
+            Some(first) => Some(SequenceValue::Second(first.clone(), self.second.value())),
+            None => self.first.value().map(SequenceValue::First),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.phase = GesturePhase::Possible;
+        self.first_value = None;
+        self.pointers.clear();
+    }
+}
+
+/// The value of an [`Exclusive`] gesture.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExclusiveValue<A, B> {
+    /// The first gesture was recognized.
+    First(A),
+    /// The first gesture failed, and the second one was recognized.
+    Second(B),
+}
+
+/// One of two gestures, the first taking precedence, like a double tap over a single tap.
+///
+/// The second gesture only receives events once the first one fails, starting with those
+/// it missed, so its handlers are never called if the first gesture is recognized.
+///
+/// Created by [`GestureExt::exclusively`](super::GestureExt::exclusively).
+#[derive(Debug, Clone)]
+pub struct Exclusive<A, B> {
+    first: A,
+    second: B,
+    phase: GesturePhase,
+    /// The events received while the first gesture is possible, replayed to the second
+    /// one if it fails.
+    pending: Vec<PointerEvent>,
+    /// Whether the second gesture receives events.
+    second_started: bool,
+}
+
+impl<A: Gesture, B: Gesture> Exclusive<A, B> {
+    /// Composes two gestures, `first` taking precedence.
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            phase: GesturePhase::Possible,
+            pending: Vec::new(),
+            second_started: false,
+        }
+    }
+
+    /// Starts the second gesture if the first one just failed.
+    fn fall_back(&mut self, now: Duration, env: &Environment) {
+        match self.first.phase() {
+            GesturePhase::Possible => {}
+            GesturePhase::Failed if !self.second_started => {
+                self.second_started = true;
+                for event in core::mem::take(&mut self.pending) {
+                    self.second.handle(&event, env);
+                }
+                self.second.tick(now, env);
+            }
+            _ => self.pending.clear(),
+        }
+    }
+
+    fn update(&mut self) -> GesturePhase {
+        let phase = if self.second_started {
+            self.second.phase()
+        } else {
+            self.first.phase()
+        };
+        self.phase = if phase.is_active() {
+            activate(self.phase)
+        } else {
+            phase
+        };
+        self.phase
+    }
+}
+
+impl<A: Gesture, B: Gesture> Gesture for Exclusive<A, B> {
+    type Value = ExclusiveValue<A::Value, B::Value>;
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        if self.second_started {
+            self.second.handle(event, env);
+        } else {
+            if self.first.phase() == GesturePhase::Possible {
+                self.pending.push(*event);
+            }
+            self.first.handle(event, env);
+            self.fall_back(event.time, env);
+        }
+        self.update()
+    }
+
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        if self.second_started {
+            self.second.tick(now, env);
+        } else {
+            self.first.tick(now, env);
+            self.fall_back(now, env);
+        }
+        self.update()
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        if self.second_started {
+            self.second.value().map(ExclusiveValue::Second)
+        } else {
+            self.first.value().map(ExclusiveValue::First)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.phase = GesturePhase::Possible;
+        self.pending.clear();
+        self.second_started = false;
+    }
+}
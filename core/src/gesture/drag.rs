@@ -0,0 +1,177 @@
+//! Drags.
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use super::{Gesture, GesturePhase, PointerEvent, PointerId, PointerPhase};
+use crate::{
+    shape::{Point, Size},
+    Environment,
+};
+
+/// The value of a drag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragValue {
+    /// Where the pointer was pressed.
+    pub start_location: Point,
+    /// Where the pointer is.
+    pub location: Point,
+    /// The distance the pointer moved since it was pressed.
+    pub translation: Size,
+    /// The speed of the pointer, in points per second.
+    pub velocity: Size,
+    /// The time of the last event of the drag.
+    pub time: Duration,
+}
+
+impl_extractor!(DragValue);
+
+/// The window of recent samples the velocity of a drag is estimated over.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+
+/// Recognizes a pointer moving while down.
+///
+/// The drag begins once the pointer moves far enough from where it was pressed, and
+/// ends when the pointer is released.
+#[derive(Debug, Clone)]
+pub struct DragGesture {
+    min_distance: f64,
+    phase: GesturePhase,
+    pointer: Option<PointerId>,
+    start_location: Point,
+    /// Recent locations of the pointer, used to estimate its velocity.
+    samples: VecDeque<(Point, Duration)>,
+}
+
+impl Default for DragGesture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DragGesture {
+    /// The distance the pointer must move before the drag begins, in points.
+    pub const DEFAULT_MIN_DISTANCE: f64 = 10.0;
+
+    /// Creates a gesture recognizing drags.
+    pub const fn new() -> Self {
+        Self {
+            min_distance: Self::DEFAULT_MIN_DISTANCE,
+            phase: GesturePhase::Possible,
+            pointer: None,
+            start_location: Point::ZERO,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Sets the distance the pointer must move before the drag begins, in points.
+    ///
+    /// With a distance of zero, the drag begins with the first move.
+    pub const fn min_distance(mut self, distance: f64) -> Self {
+        self.min_distance = distance;
+        self
+    }
+
+    fn record(&mut self, location: Point, time: Duration) {
+        self.samples.push_back((location, time));
+        while self.samples.len() > 2
+            && self
+                .samples
+                .front()
+                .is_some_and(|&(_, sample)| time.saturating_sub(sample) > VELOCITY_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    fn velocity(&self) -> Size {
+        match (self.samples.front(), self.samples.back()) {
+            (Some(&(from, start)), Some(&(to, end))) if end > start => {
+                let seconds = (end - start).as_secs_f64();
+                Size::new((to.x - from.x) / seconds, (to.y - from.y) / seconds)
+            }
+            _ => Size::ZERO,
+        }
+    }
+}
+
+impl Gesture for DragGesture {
+    type Value = DragValue;
+
+    fn handle(&mut self, event: &PointerEvent, _env: &Environment) -> GesturePhase {
+        if self.phase.is_finished() {
+            return self.phase;
+        }
+
+        let Some(pointer) = self.pointer else {
+            if event.phase == PointerPhase::Down {
+                self.pointer = Some(event.pointer);
+                self.start_location = event.location;
+                self.record(event.location, event.time);
+            }
+            return self.phase;
+        };
+        if pointer != event.pointer {
+            return self.phase;
+        }
+
+        match event.phase {
+            PointerPhase::Down => {}
+            PointerPhase::Move => {
+                self.record(event.location, event.time);
+                self.phase = match self.phase {
+                    GesturePhase::Possible
+                        if self.start_location.distance(event.location) < self.min_distance =>
+                    {
+                        GesturePhase::Possible
+                    }
+                    GesturePhase::Possible => GesturePhase::Began,
+                    _ => GesturePhase::Changed,
+                };
+            }
+            PointerPhase::Up => {
+                self.record(event.location, event.time);
+                self.phase = if self.phase.is_active() {
+                    GesturePhase::Ended
+                } else {
+                    GesturePhase::Failed
+                };
+            }
+            PointerPhase::Cancel => {
+                self.phase = if self.phase.is_active() {
+                    GesturePhase::Cancelled
+                } else {
+                    GesturePhase::Failed
+                };
+            }
+        }
+        self.phase
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        if !self.phase.is_recognized() {
+            return None;
+        }
+        let &(location, time) = self.samples.back()?;
+        Some(DragValue {
+            start_location: self.start_location,
+            location,
+            translation: Size::new(
+                location.x - self.start_location.x,
+                location.y - self.start_location.y,
+            ),
+            velocity: self.velocity(),
+            time,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.phase = GesturePhase::Possible;
+        self.pointer = None;
+        self.samples.clear();
+    }
+}
@@ -0,0 +1,568 @@
+//! # Gestures
+//!
+//! Gesture recognizers turning a stream of pointer events into taps, long presses, drags,
+//! magnifications and rotations.
+//!
+//! Renderers normalize the touch, mouse and pen input of their platform into
+//! [`PointerEvent`]s and feed them to the [`GestureHandler`] attached to a view, so that
+//! gestures behave the same on every backend. Recognizers are plain state machines,
+//! which makes them testable with synthetic events.
+//!
+//! Every [`Gesture`] goes through [`GesturePhase`]s. Discrete gestures, like taps, go
+//! straight from [`Possible`](GesturePhase::Possible) to [`Ended`](GesturePhase::Ended),
+//! while continuous ones, like drags, begin, change, then end. Gestures are composed with
+//! [`GestureExt`], which also attaches the handlers called as they are recognized:
+//!
+//! ```rust
+//! use waterui_core::Binding;
+//! use waterui_core::gesture::{GestureExt, MagnifyGesture, MagnifyValue, RotateGesture, RotateValue};
+//!
+//! let (scale, angle) = (Binding::container(1.0), Binding::container(0.0));
+//! let zoom = MagnifyGesture::new()
+//!     .on_changed(move |value: MagnifyValue| scale.set(value.scale))
+//!     .simultaneously(RotateGesture::new().on_changed(move |value: RotateValue| angle.set(value.angle)));
+//! // The gesture is attached to a view with `view.gesture(zoom)`.
+//! ```
+//!
+//! Handlers are handler functions, extracting their parameters from the environment,
+//! where the current value of their gesture is inserted.
+
+mod compose;
+mod drag;
+mod pinch;
+mod tap;
+
+pub use compose::{Exclusive, ExclusiveValue, Sequence, SequenceValue, Simultaneous};
+pub use drag::{DragGesture, DragValue};
+pub use pinch::{MagnifyGesture, MagnifyValue, RotateGesture, RotateValue};
+pub use tap::{LongPressGesture, TapGesture, TapValue};
+
+use alloc::{boxed::Box, collections::BTreeSet};
+use core::{
+    any::type_name,
+    fmt::{self, Debug},
+    time::Duration,
+};
+
+use crate::{
+    handler::{into_handler, BoxHandler, HandlerFn},
+    shape::Point,
+    Environment,
+};
+
+/// Identifies a pointer, such as a finger, for the duration of its contact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PointerId(pub u64);
+
+/// What happened to a pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerPhase {
+    /// The pointer started touching the view, or a button was pressed.
+    Down,
+    /// The pointer moved while down.
+    Move,
+    /// The pointer was lifted, or the button released.
+    Up,
+    /// The system took the pointer over, for instance to scroll.
+    Cancel,
+}
+
+/// A normalized pointer event, delivered by renderers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointerEvent {
+    /// The pointer the event is about.
+    pub pointer: PointerId,
+    /// What happened to the pointer.
+    pub phase: PointerPhase,
+    /// The location of the pointer, in the coordinate space of the view.
+    pub location: Point,
+    /// The time of the event, from an arbitrary origin that never goes backwards.
+    pub time: Duration,
+}
+
+impl PointerEvent {
+    /// Creates a pointer event.
+    pub const fn new(
+        pointer: PointerId,
+        phase: PointerPhase,
+        location: Point,
+        time: Duration,
+    ) -> Self {
+        Self {
+            pointer,
+            phase,
+            location,
+            time,
+        }
+    }
+}
+
+/// The state of a gesture recognizer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GesturePhase {
+    /// The gesture isn't recognized yet.
+    #[default]
+    Possible,
+    /// The continuous gesture was just recognized.
+    Began,
+    /// The continuous gesture changed.
+    Changed,
+    /// The gesture was recognized and is over.
+    Ended,
+    /// The gesture can't be recognized from the current events.
+    Failed,
+    /// The gesture was recognized, then cancelled.
+    Cancelled,
+}
+
+impl GesturePhase {
+    /// Returns whether the gesture is recognized and in progress.
+    pub const fn is_active(self) -> bool {
+        matches!(self, Self::Began | Self::Changed)
+    }
+
+    /// Returns whether the gesture is over, until it is reset.
+    pub const fn is_finished(self) -> bool {
+        matches!(self, Self::Ended | Self::Failed | Self::Cancelled)
+    }
+
+    /// Returns whether the gesture was recognized.
+    pub const fn is_recognized(self) -> bool {
+        matches!(self, Self::Began | Self::Changed | Self::Ended)
+    }
+}
+
+/// A gesture recognizer, fed with pointer events.
+///
+/// A recognizer stays in a finished phase until it is [reset](Gesture::reset), which
+/// [`GestureHandler`] does when a new gesture starts.
+pub trait Gesture: 'static {
+    /// The value of the gesture, such as the translation of a drag.
+    type Value: Clone + PartialEq + 'static;
+
+    /// Handles a pointer event, returning the new phase.
+    ///
+    /// `env` is the environment of the view, passed to the handlers of the gesture.
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase;
+
+    /// Advances time to `now` without any pointer event, returning the new phase.
+    ///
+    /// Renderers call it on every frame while a gesture is in progress, so that
+    /// recognizers depending on time, like long presses, are recognized in time.
+    fn tick(&mut self, _now: Duration, _env: &Environment) -> GesturePhase {
+        self.phase()
+    }
+
+    /// Returns the current phase.
+    fn phase(&self) -> GesturePhase;
+
+    /// Returns the current value, once the gesture is recognized.
+    fn value(&self) -> Option<Self::Value>;
+
+    /// Returns to the [`Possible`](GesturePhase::Possible) phase, ready for a new gesture.
+    fn reset(&mut self);
+}
+
+/// Extension methods composing gestures and attaching handlers to them.
+pub trait GestureExt: Gesture + Sized {
+    /// Calls `handler` when the gesture begins and every time it changes.
+    ///
+    /// The value of the gesture can be extracted by the handler. Values of composed
+    /// gestures, such as the tuple of a [`Simultaneous`] gesture, are extracted with
+    /// [`Use`](crate::extract::Use).
+    fn on_changed<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> OnChanged<Self> {
+        OnChanged {
+            gesture: self,
+            handler: Box::new(into_handler(handler)),
+        }
+    }
+
+    /// Calls `handler` when the gesture ends successfully.
+    ///
+    /// The final value of the gesture can be extracted by the handler.
+    fn on_ended<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> OnEnded<Self> {
+        OnEnded {
+            gesture: self,
+            handler: Box::new(into_handler(handler)),
+        }
+    }
+
+    /// Recognizes this gesture and `other` at the same time.
+    fn simultaneously<G: Gesture>(self, other: G) -> Simultaneous<Self, G> {
+        Simultaneous::new(self, other)
+    }
+
+    /// Recognizes `next` once this gesture ends, like a drag after a long press.
+    fn sequenced<G: Gesture>(self, next: G) -> Sequence<Self, G> {
+        Sequence::new(self, next)
+    }
+
+    /// Recognizes this gesture, or `other` if this one fails, like a double tap taking
+    /// precedence over a single tap.
+    fn exclusively<G: Gesture>(self, other: G) -> Exclusive<Self, G> {
+        Exclusive::new(self, other)
+    }
+}
+
+impl<G: Gesture> GestureExt for G {}
+
+/// Calls the handlers of a gesture with its value inserted in the environment.
+fn call_with<T: 'static>(handler: &BoxHandler<()>, env: &Environment, value: Option<T>) {
+    let mut env = env.clone();
+    if let Some(value) = value {
+        env.insert(value);
+    }
+    handler.handle(&env);
+}
+
+/// A gesture calling a handler when it begins and changes.
+///
+/// Created by [`GestureExt::on_changed`].
+pub struct OnChanged<G> {
+    gesture: G,
+    handler: BoxHandler<()>,
+}
+
+/// A gesture calling a handler when it ends.
+///
+/// Created by [`GestureExt::on_ended`].
+pub struct OnEnded<G> {
+    gesture: G,
+    handler: BoxHandler<()>,
+}
+
+impl<G> Debug for OnChanged<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(type_name::<Self>())
+    }
+}
+
+impl<G> Debug for OnEnded<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(type_name::<Self>())
+    }
+}
+
+impl<G: Gesture> OnChanged<G> {
+    /// Calls the handler if the gesture is active and its phase or value changed.
+    fn notify(
+        &self,
+        previous: (GesturePhase, Option<G::Value>),
+        env: &Environment,
+    ) -> GesturePhase {
+        let phase = self.gesture.phase();
+        let value = self.gesture.value();
+        if phase.is_active() && (phase, &value) != (previous.0, &previous.1) {
+            call_with(&self.handler, env, value);
+        }
+        phase
+    }
+}
+
+impl<G: Gesture> Gesture for OnChanged<G> {
+    type Value = G::Value;
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        let previous = (self.gesture.phase(), self.gesture.value());
+        self.gesture.handle(event, env);
+        self.notify(previous, env)
+    }
+
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        let previous = (self.gesture.phase(), self.gesture.value());
+        self.gesture.tick(now, env);
+        self.notify(previous, env)
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.gesture.phase()
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        self.gesture.value()
+    }
+
+    fn reset(&mut self) {
+        self.gesture.reset();
+    }
+}
+
+impl<G: Gesture> OnEnded<G> {
+    fn notify(&self, previous: GesturePhase, phase: GesturePhase, env: &Environment) {
+        if previous != GesturePhase::Ended && phase == GesturePhase::Ended {
+            call_with(&self.handler, env, self.gesture.value());
+        }
+    }
+}
+
+impl<G: Gesture> Gesture for OnEnded<G> {
+    type Value = G::Value;
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        let previous = self.gesture.phase();
+        let phase = self.gesture.handle(event, env);
+        self.notify(previous, phase, env);
+        phase
+    }
+
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        let previous = self.gesture.phase();
+        let phase = self.gesture.tick(now, env);
+        self.notify(previous, phase, env);
+        phase
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.gesture.phase()
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        self.gesture.value()
+    }
+
+    fn reset(&mut self) {
+        self.gesture.reset();
+    }
+}
+
+trait AnyGesture {
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase;
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase;
+    fn phase(&self) -> GesturePhase;
+    fn reset(&mut self);
+}
+
+impl<G: Gesture> AnyGesture for G {
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        Gesture::handle(self, event, env)
+    }
+
+    fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        Gesture::tick(self, now, env)
+    }
+
+    fn phase(&self) -> GesturePhase {
+        Gesture::phase(self)
+    }
+
+    fn reset(&mut self) {
+        Gesture::reset(self);
+    }
+}
+
+/// The gesture attached to a view, driven by the renderer.
+///
+/// The handler tracks the pointers down on the view, and resets the gesture when a new
+/// one starts after it finished.
+pub struct GestureHandler {
+    gesture: Box<dyn AnyGesture>,
+    pointers: BTreeSet<PointerId>,
+    /// Whether the gesture received events since it was last reset.
+    started: bool,
+}
+
+impl_debug!(GestureHandler);
+
+impl GestureHandler {
+    /// Creates a handler driving `gesture`.
+    pub fn new(gesture: impl Gesture) -> Self {
+        Self {
+            gesture: Box::new(gesture),
+            pointers: BTreeSet::new(),
+            started: false,
+        }
+    }
+
+    /// Handles a pointer event on the view, returning the new phase of the gesture.
+    pub fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        match event.phase {
+            PointerPhase::Down => {
+                if self.pointers.is_empty() && self.gesture.phase().is_finished() {
+                    self.gesture.reset();
+                }
+                self.pointers.insert(event.pointer);
+                self.started = true;
+            }
+            PointerPhase::Up | PointerPhase::Cancel => {
+                self.pointers.remove(&event.pointer);
+            }
+            PointerPhase::Move => {}
+        }
+        self.gesture.handle(event, env)
+    }
+
+    /// Advances time to `now`, returning the new phase of the gesture.
+    pub fn tick(&mut self, now: Duration, env: &Environment) -> GesturePhase {
+        self.gesture.tick(now, env)
+    }
+
+    /// Returns the current phase of the gesture.
+    pub fn phase(&self) -> GesturePhase {
+        self.gesture.phase()
+    }
+
+    /// Returns whether the gesture needs [ticks](GestureHandler::tick), because pointers
+    /// are down or it is waiting for time to pass, like a tap waiting for a second tap.
+    pub fn needs_ticks(&self) -> bool {
+        !self.pointers.is_empty() || self.started && !self.gesture.phase().is_finished()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::{cell::RefCell, f64::consts::FRAC_PI_2, time::Duration};
+
+    use super::{
+        DragGesture, DragValue, Gesture, GestureExt, GestureHandler, GesturePhase,
+        LongPressGesture, MagnifyGesture, MagnifyValue, PointerEvent, PointerId, PointerPhase,
+        RotateGesture, RotateValue, SequenceValue, TapGesture, TapValue,
+    };
+    use crate::{
+        extract::Use,
+        shape::{Point, Size},
+        Environment,
+    };
+
+    fn event(pointer: u64, phase: PointerPhase, x: f64, y: f64, millis: u64) -> PointerEvent {
+        PointerEvent::new(
+            PointerId(pointer),
+            phase,
+            Point::new(x, y),
+            Duration::from_millis(millis),
+        )
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn double_tap_takes_precedence() {
+        let env = Environment::new();
+        let taps = Rc::new(RefCell::new(Vec::new()));
+        let (double, single) = (taps.clone(), taps.clone());
+        let mut handler = GestureHandler::new(
+            TapGesture::new()
+                .count(2)
+                .on_ended(move |_: TapValue| double.borrow_mut().push(2))
+                .exclusively(
+                    TapGesture::new().on_ended(move |_: TapValue| single.borrow_mut().push(1)),
+                ),
+        );
+
+        handler.handle(&event(0, PointerPhase::Down, 5.0, 5.0, 0), &env);
+        handler.handle(&event(0, PointerPhase::Up, 6.0, 5.0, 50), &env);
+        assert!(handler.needs_ticks());
+        handler.handle(&event(1, PointerPhase::Down, 7.0, 5.0, 150), &env);
+        let phase = handler.handle(&event(1, PointerPhase::Up, 7.0, 5.0, 200), &env);
+        assert_eq!(phase, GesturePhase::Ended);
+        assert_eq!(*taps.borrow(), [2]);
+        assert!(!handler.needs_ticks());
+
+        // A single tap is only recognized once the second tap is late.
+        handler.handle(&event(0, PointerPhase::Down, 5.0, 5.0, 1000), &env);
+        handler.handle(&event(0, PointerPhase::Up, 5.0, 5.0, 1050), &env);
+        assert_eq!(
+            handler.tick(Duration::from_millis(1200), &env),
+            GesturePhase::Possible
+        );
+        assert_eq!(
+            handler.tick(Duration::from_millis(1400), &env),
+            GesturePhase::Ended
+        );
+        assert_eq!(*taps.borrow(), [2, 1]);
+    }
+
+    #[test]
+    fn long_press_then_drag() {
+        let env = Environment::new();
+        let mut gesture = LongPressGesture::new().sequenced(DragGesture::new().min_distance(0.0));
+
+        gesture.handle(&event(0, PointerPhase::Down, 10.0, 10.0, 0), &env);
+        assert_eq!(
+            gesture.tick(Duration::from_millis(300), &env),
+            GesturePhase::Possible
+        );
+        assert_eq!(
+            gesture.tick(Duration::from_millis(500), &env),
+            GesturePhase::Began
+        );
+        let Some(SequenceValue::Second(press, None)) = gesture.value() else {
+            panic!("the drag didn't start");
+        };
+        assert_eq!(press.location, Point::new(10.0, 10.0));
+
+        let phase = gesture.handle(&event(0, PointerPhase::Move, 30.0, 10.0, 600), &env);
+        assert_eq!(phase, GesturePhase::Changed);
+        let Some(SequenceValue::Second(_, Some(drag))) = gesture.value() else {
+            panic!("the drag has no value");
+        };
+        assert_eq!(drag.translation, Size::new(20.0, 0.0));
+        let phase = gesture.handle(&event(0, PointerPhase::Up, 30.0, 10.0, 650), &env);
+        assert_eq!(phase, GesturePhase::Ended);
+
+        // Releasing early fails the whole sequence.
+        gesture.reset();
+        gesture.handle(&event(0, PointerPhase::Down, 10.0, 10.0, 1000), &env);
+        let phase = gesture.handle(&event(0, PointerPhase::Up, 10.0, 10.0, 1200), &env);
+        assert_eq!(phase, GesturePhase::Failed);
+    }
+
+    #[test]
+    fn drag() {
+        let env = Environment::new();
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let changed = values.clone();
+        let mut gesture =
+            DragGesture::new().on_changed(move |value: DragValue| changed.borrow_mut().push(value));
+
+        gesture.handle(&event(0, PointerPhase::Down, 0.0, 0.0, 0), &env);
+        let phase = gesture.handle(&event(0, PointerPhase::Move, 5.0, 0.0, 10), &env);
+        assert_eq!(phase, GesturePhase::Possible);
+        let phase = gesture.handle(&event(0, PointerPhase::Move, 20.0, 0.0, 20), &env);
+        assert_eq!(phase, GesturePhase::Began);
+        // Other pointers are ignored.
+        gesture.handle(&event(1, PointerPhase::Down, 90.0, 90.0, 30), &env);
+        let phase = gesture.handle(&event(0, PointerPhase::Move, 40.0, 10.0, 40), &env);
+        assert_eq!(phase, GesturePhase::Changed);
+
+        let value = values.borrow().last().copied().unwrap();
+        assert_eq!(values.borrow().len(), 2);
+        assert_eq!(value.translation, Size::new(40.0, 10.0));
+        assert!(close(value.velocity.width, 1000.0) && close(value.velocity.height, 250.0));
+
+        let phase = gesture.handle(&event(0, PointerPhase::Up, 40.0, 10.0, 50), &env);
+        assert_eq!(phase, GesturePhase::Ended);
+        assert_eq!(values.borrow().len(), 2);
+    }
+
+    #[test]
+    fn magnify_and_rotate() {
+        let env = Environment::new();
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let ended = values.clone();
+        let mut gesture = MagnifyGesture::new()
+            .simultaneously(RotateGesture::new())
+            .on_ended(
+                move |Use(value): Use<(Option<MagnifyValue>, Option<RotateValue>)>| {
+                    ended.borrow_mut().push(value);
+                },
+            );
+
+        gesture.handle(&event(0, PointerPhase::Down, 0.0, 0.0, 0), &env);
+        gesture.handle(&event(1, PointerPhase::Down, 10.0, 0.0, 0), &env);
+        let phase = gesture.handle(&event(1, PointerPhase::Move, 0.0, 20.0, 20), &env);
+        assert_eq!(phase, GesturePhase::Began);
+        let Some((Some(magnify), Some(rotate))) = gesture.value() else {
+            panic!("both gestures should be recognized");
+        };
+        assert!(close(magnify.scale, 2.0));
+        assert!(close(rotate.angle, FRAC_PI_2));
+        assert_eq!(magnify.center, Point::new(0.0, 10.0));
+
+        let phase = gesture.handle(&event(1, PointerPhase::Up, 0.0, 20.0, 40), &env);
+        assert_eq!(phase, GesturePhase::Ended);
+        assert_eq!(values.borrow().len(), 1);
+    }
+}
@@ -0,0 +1,264 @@
+//! Two-pointer gestures: magnifications and rotations.
+
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use libm::{atan2, fabs};
+
+use super::{Gesture, GesturePhase, PointerEvent, PointerId, PointerPhase};
+use crate::{shape::Point, Environment};
+
+/// The value of a magnification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MagnifyValue {
+    /// The distance between the pointers relative to their initial distance, above 1 when
+    /// they move apart.
+    pub scale: f64,
+    /// The point halfway between the pointers.
+    pub center: Point,
+}
+
+impl_extractor!(MagnifyValue);
+
+/// The value of a rotation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotateValue {
+    /// The angle the pointers turned by since the rotation started, in radians, positive
+    /// clockwise.
+    pub angle: f64,
+    /// The point halfway between the pointers.
+    pub center: Point,
+}
+
+impl_extractor!(RotateValue);
+
+/// The two pointers of a pinch, as they were when the second one went down and as they are.
+#[derive(Debug, Clone, Default)]
+struct Pinch {
+    phase: GesturePhase,
+    pointers: Vec<(PointerId, Point)>,
+    start: Option<(Point, Point)>,
+    /// The angle turned so far, unwrapped across the half turn.
+    angle: f64,
+}
+
+impl Pinch {
+    /// Handles an event, returning whether the pointers moved while both down.
+    fn handle(&mut self, event: &PointerEvent) -> bool {
+        if self.phase.is_finished() {
+            return false;
+        }
+        let index = self
+            .pointers
+            .iter()
+            .position(|&(pointer, _)| pointer == event.pointer);
+
+        match (event.phase, index) {
+            (PointerPhase::Down, None) if self.pointers.len() < 2 => {
+                self.pointers.push((event.pointer, event.location));
+                if let [(_, a), (_, b)] = self.pointers[..] {
+                    self.start = Some((a, b));
+                }
+            }
+            (PointerPhase::Move, Some(index)) => {
+                let previous = self.current();
+                self.pointers[index].1 = event.location;
+                if let (Some(previous), Some(current)) = (previous, self.current()) {
+                    self.angle += wrap(angle(current) - angle(previous));
+                    return true;
+                }
+            }
+            (PointerPhase::Up | PointerPhase::Cancel, Some(_)) => {
+                self.phase = match (event.phase, self.phase.is_active()) {
+                    (PointerPhase::Up, true) => GesturePhase::Ended,
+                    (PointerPhase::Cancel, true) => GesturePhase::Cancelled,
+                    _ => GesturePhase::Failed,
+                };
+            }
+            _ => {}
+        }
+        false
+    }
+
+    /// Moves to `Began` or `Changed` if the gesture is recognized.
+    fn update(&mut self, recognized: bool) -> GesturePhase {
+        self.phase = match self.phase {
+            GesturePhase::Possible if recognized => GesturePhase::Began,
+            GesturePhase::Began | GesturePhase::Changed => GesturePhase::Changed,
+            phase => phase,
+        };
+        self.phase
+    }
+
+    fn current(&self) -> Option<(Point, Point)> {
+        match self.pointers[..] {
+            [(_, a), (_, b)] => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    fn scale(&self) -> Option<f64> {
+        let (start_a, start_b) = self.start?;
+        let (a, b) = self.current()?;
+        let start = start_a.distance(start_b);
+        (start > 0.0).then(|| a.distance(b) / start)
+    }
+
+    fn center(&self) -> Option<Point> {
+        self.current().map(|(a, b)| a.midpoint(b))
+    }
+
+    fn recognized_value<T>(&self, value: impl FnOnce(&Self) -> Option<T>) -> Option<T> {
+        if self.phase.is_recognized() {
+            value(self)
+        } else {
+            None
+        }
+    }
+}
+
+fn angle((a, b): (Point, Point)) -> f64 {
+    atan2(b.y - a.y, b.x - a.x)
+}
+
+/// Brings an angle difference back within a half turn.
+fn wrap(mut delta: f64) -> f64 {
+    while delta > PI {
+        delta -= 2.0 * PI;
+    }
+    while delta < -PI {
+        delta += 2.0 * PI;
+    }
+    delta
+}
+
+/// Recognizes two pointers moving apart or together.
+///
+/// The magnification ends when either pointer is released.
+#[derive(Debug, Clone)]
+pub struct MagnifyGesture {
+    min_scale_delta: f64,
+    pinch: Pinch,
+}
+
+impl Default for MagnifyGesture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MagnifyGesture {
+    /// The change of scale from which the magnification begins.
+    pub const DEFAULT_MIN_SCALE_DELTA: f64 = 0.01;
+
+    /// Creates a gesture recognizing magnifications.
+    pub fn new() -> Self {
+        Self {
+            min_scale_delta: Self::DEFAULT_MIN_SCALE_DELTA,
+            pinch: Pinch::default(),
+        }
+    }
+
+    /// Sets the change of scale from which the magnification begins.
+    pub const fn min_scale_delta(mut self, delta: f64) -> Self {
+        self.min_scale_delta = delta;
+        self
+    }
+}
+
+impl Gesture for MagnifyGesture {
+    type Value = MagnifyValue;
+
+    fn handle(&mut self, event: &PointerEvent, _env: &Environment) -> GesturePhase {
+        if self.pinch.handle(event) {
+            let recognized = self
+                .pinch
+                .scale()
+                .is_some_and(|scale| fabs(scale - 1.0) >= self.min_scale_delta);
+            self.pinch.update(recognized)
+        } else {
+            self.pinch.phase
+        }
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.pinch.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        self.pinch.recognized_value(|pinch| {
+            Some(MagnifyValue {
+                scale: pinch.scale()?,
+                center: pinch.center()?,
+            })
+        })
+    }
+
+    fn reset(&mut self) {
+        self.pinch = Pinch::default();
+    }
+}
+
+/// Recognizes two pointers turning around each other.
+///
+/// The rotation ends when either pointer is released.
+#[derive(Debug, Clone)]
+pub struct RotateGesture {
+    min_angle: f64,
+    pinch: Pinch,
+}
+
+impl Default for RotateGesture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RotateGesture {
+    /// The angle from which the rotation begins, about one degree.
+    pub const DEFAULT_MIN_ANGLE: f64 = 0.02;
+
+    /// Creates a gesture recognizing rotations.
+    pub fn new() -> Self {
+        Self {
+            min_angle: Self::DEFAULT_MIN_ANGLE,
+            pinch: Pinch::default(),
+        }
+    }
+
+    /// Sets the angle from which the rotation begins, in radians.
+    pub const fn min_angle(mut self, angle: f64) -> Self {
+        self.min_angle = angle;
+        self
+    }
+}
+
+impl Gesture for RotateGesture {
+    type Value = RotateValue;
+
+    fn handle(&mut self, event: &PointerEvent, _env: &Environment) -> GesturePhase {
+        if self.pinch.handle(event) {
+            let recognized = fabs(self.pinch.angle) >= self.min_angle;
+            self.pinch.update(recognized)
+        } else {
+            self.pinch.phase
+        }
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.pinch.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        self.pinch.recognized_value(|pinch| {
+            Some(RotateValue {
+                angle: pinch.angle,
+                center: pinch.center()?,
+            })
+        })
+    }
+
+    fn reset(&mut self) {
+        self.pinch = Pinch::default();
+    }
+}
@@ -0,0 +1,258 @@
+//! Taps and long presses.
+
+use core::time::Duration;
+
+use super::{Gesture, GesturePhase, PointerEvent, PointerId, PointerPhase};
+use crate::{shape::Point, Environment};
+
+/// The value of a tap or a long press.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TapValue {
+    /// Where the pointer was pressed.
+    pub location: Point,
+}
+
+impl_extractor!(TapValue);
+
+/// Recognizes one or several taps in a row.
+///
+/// The gesture fails if the pointer moves too far, if another pointer goes down, or if
+/// the next tap doesn't come in time.
+#[derive(Debug, Clone)]
+pub struct TapGesture {
+    count: usize,
+    max_distance: f64,
+    max_interval: Duration,
+    phase: GesturePhase,
+    taps: usize,
+    /// The pointer down, with the location it was pressed at.
+    pressed: Option<(PointerId, Point)>,
+    /// The time the last tap was released, while waiting for the next one.
+    released: Option<Duration>,
+    location: Option<Point>,
+}
+
+impl Default for TapGesture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TapGesture {
+    /// The distance the pointer may move during a tap, in points.
+    pub const DEFAULT_MAX_DISTANCE: f64 = 10.0;
+    /// The longest time between taps of a multiple tap.
+    pub const DEFAULT_MAX_INTERVAL: Duration = Duration::from_millis(300);
+
+    /// Creates a gesture recognizing a single tap.
+    pub const fn new() -> Self {
+        Self {
+            count: 1,
+            max_distance: Self::DEFAULT_MAX_DISTANCE,
+            max_interval: Self::DEFAULT_MAX_INTERVAL,
+            phase: GesturePhase::Possible,
+            taps: 0,
+            pressed: None,
+            released: None,
+            location: None,
+        }
+    }
+
+    /// Sets the number of taps to recognize, such as 2 for a double tap.
+    pub const fn count(mut self, count: usize) -> Self {
+        self.count = if count == 0 { 1 } else { count };
+        self
+    }
+
+    /// Sets the distance the pointer may move during a tap, in points.
+    pub const fn max_distance(mut self, distance: f64) -> Self {
+        self.max_distance = distance;
+        self
+    }
+
+    /// Sets the longest time between taps of a multiple tap.
+    pub const fn max_interval(mut self, interval: Duration) -> Self {
+        self.max_interval = interval;
+        self
+    }
+
+    fn fail(&mut self) -> GesturePhase {
+        self.phase = GesturePhase::Failed;
+        self.phase
+    }
+}
+
+impl Gesture for TapGesture {
+    type Value = TapValue;
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        if self.phase.is_finished() {
+            return self.phase;
+        }
+        if self.tick(event.time, env).is_finished() {
+            return self.phase;
+        }
+
+        match event.phase {
+            PointerPhase::Down => {
+                if self.pressed.is_some() {
+                    return self.fail();
+                }
+                let location = *self.location.get_or_insert(event.location);
+                if location.distance(event.location) > self.max_distance {
+                    return self.fail();
+                }
+                self.pressed = Some((event.pointer, event.location));
+                self.released = None;
+            }
+            PointerPhase::Move => {
+                if let Some((pointer, pressed)) = self.pressed {
+                    if pointer == event.pointer
+                        && pressed.distance(event.location) > self.max_distance
+                    {
+                        return self.fail();
+                    }
+                }
+            }
+            PointerPhase::Up => {
+                if self.pressed.take().is_some() {
+                    self.taps += 1;
+                    if self.taps == self.count {
+                        self.phase = GesturePhase::Ended;
+                    } else {
+                        self.released = Some(event.time);
+                    }
+                }
+            }
+            PointerPhase::Cancel => return self.fail(),
+        }
+        self.phase
+    }
+
+    fn tick(&mut self, now: Duration, _env: &Environment) -> GesturePhase {
+        if let Some(released) = self.released {
+            if now.saturating_sub(released) > self.max_interval {
+                return self.fail();
+            }
+        }
+        self.phase
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        (self.phase == GesturePhase::Ended).then(|| TapValue {
+            location: self.location.unwrap_or_default(),
+        })
+    }
+
+    fn reset(&mut self) {
+        *self = Self {
+            count: self.count,
+            max_distance: self.max_distance,
+            max_interval: self.max_interval,
+            ..Self::new()
+        };
+    }
+}
+
+/// Recognizes a pointer held down without moving.
+///
+/// The gesture ends as soon as the pointer has been held long enough, without waiting
+/// for it to be released, and fails if the pointer is released earlier or moves too far.
+#[derive(Debug, Clone)]
+pub struct LongPressGesture {
+    min_duration: Duration,
+    max_distance: f64,
+    phase: GesturePhase,
+    /// The pointer down, with the location and time it was pressed at.
+    pressed: Option<(PointerId, Point, Duration)>,
+}
+
+impl Default for LongPressGesture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LongPressGesture {
+    /// The time the pointer must be held down.
+    pub const DEFAULT_MIN_DURATION: Duration = Duration::from_millis(500);
+
+    /// Creates a gesture recognizing a pointer held down for half a second.
+    pub const fn new() -> Self {
+        Self {
+            min_duration: Self::DEFAULT_MIN_DURATION,
+            max_distance: TapGesture::DEFAULT_MAX_DISTANCE,
+            phase: GesturePhase::Possible,
+            pressed: None,
+        }
+    }
+
+    /// Sets the time the pointer must be held down.
+    pub const fn min_duration(mut self, duration: Duration) -> Self {
+        self.min_duration = duration;
+        self
+    }
+
+    /// Sets the distance the pointer may move while held down, in points.
+    pub const fn max_distance(mut self, distance: f64) -> Self {
+        self.max_distance = distance;
+        self
+    }
+}
+
+impl Gesture for LongPressGesture {
+    type Value = TapValue;
+
+    fn handle(&mut self, event: &PointerEvent, env: &Environment) -> GesturePhase {
+        if self.tick(event.time, env).is_finished() {
+            return self.phase;
+        }
+
+        match (event.phase, self.pressed) {
+            (PointerPhase::Down, None) => {
+                self.pressed = Some((event.pointer, event.location, event.time));
+            }
+            (PointerPhase::Down, Some(_)) | (PointerPhase::Cancel, _) => {
+                self.phase = GesturePhase::Failed;
+            }
+            (PointerPhase::Move, Some((pointer, location, _))) => {
+                if pointer == event.pointer && location.distance(event.location) > self.max_distance
+                {
+                    self.phase = GesturePhase::Failed;
+                }
+            }
+            (PointerPhase::Up, Some(_)) => self.phase = GesturePhase::Failed,
+            (PointerPhase::Move | PointerPhase::Up, None) => {}
+        }
+        self.phase
+    }
+
+    fn tick(&mut self, now: Duration, _env: &Environment) -> GesturePhase {
+        if let (GesturePhase::Possible, Some((_, _, pressed))) = (self.phase, self.pressed) {
+            if now.saturating_sub(pressed) >= self.min_duration {
+                self.phase = GesturePhase::Ended;
+            }
+        }
+        self.phase
+    }
+
+    fn phase(&self) -> GesturePhase {
+        self.phase
+    }
+
+    fn value(&self) -> Option<Self::Value> {
+        match (self.phase, self.pressed) {
+            (GesturePhase::Ended, Some((_, location, _))) => Some(TapValue { location }),
+            _ => None,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.phase = GesturePhase::Possible;
+        self.pressed = None;
+    }
+}
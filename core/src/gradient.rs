@@ -0,0 +1,383 @@
+//! # Gradients
+//!
+//! Linear, radial and angular gradients, usable as backgrounds and shape fills.
+//!
+//! A [`Gradient`] is a list of color stops, each color being reactive, along with the
+//! [`ColorSpace`] colors are interpolated in. It is then laid out in the rectangle of a
+//! view by one of:
+//! - [`LinearGradient`]: Along a line between two points
+//! - [`RadialGradient`]: Along circles growing from a center
+//! - [`AngularGradient`]: Around a center, like a color wheel
+//!
+//! Points are given in [`UnitPoint`]s, relative to the rectangle of the view, so gradients
+//! keep their look whatever the size of the view.
+//!
+//! ```rust
+//! use waterui_core::color::{Color, ColorSpace};
+//! use waterui_core::gradient::{Gradient, LinearGradient, UnitPoint};
+//!
+//! let sunset = LinearGradient::new(
+//!     Gradient::new([Color::ORANGE, Color::PURPLE]).interpolation(ColorSpace::Oklch),
+//!     UnitPoint::TOP,
+//!     UnitPoint::BOTTOM,
+//! );
+//! // Gradients are used as backgrounds, with `view.background(sunset)`, or as shape fills.
+//! ```
+
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_PI_2, TAU};
+
+use libm::{atan2, fmod, sqrt};
+use waterui_reactive::{compute::IntoComputed, Compute, Computed};
+
+use crate::{
+    color::ColorSpace,
+    shape::{Point, Rect},
+    Color,
+};
+
+/// A point relative to a rectangle, from `(0, 0)` at the top-leading corner to `(1, 1)`
+/// at the bottom-trailing corner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Record)]
+pub struct UnitPoint {
+    /// The horizontal position, as a fraction of the width.
+    pub x: f64,
+    /// The vertical position, as a fraction of the height.
+    pub y: f64,
+}
+
+impl UnitPoint {
+    /// The top-leading corner.
+    pub const TOP_LEADING: Self = Self::new(0.0, 0.0);
+    /// The middle of the top edge.
+    pub const TOP: Self = Self::new(0.5, 0.0);
+    /// The top-trailing corner.
+    pub const TOP_TRAILING: Self = Self::new(1.0, 0.0);
+    /// The middle of the leading edge.
+    pub const LEADING: Self = Self::new(0.0, 0.5);
+    /// The center.
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+    /// The middle of the trailing edge.
+    pub const TRAILING: Self = Self::new(1.0, 0.5);
+    /// The bottom-leading corner.
+    pub const BOTTOM_LEADING: Self = Self::new(0.0, 1.0);
+    /// The middle of the bottom edge.
+    pub const BOTTOM: Self = Self::new(0.5, 1.0);
+    /// The bottom-trailing corner.
+    pub const BOTTOM_TRAILING: Self = Self::new(1.0, 1.0);
+
+    /// Creates a unit point from its relative coordinates.
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the absolute position of this point in `rect`.
+    pub const fn resolve(&self, rect: Rect) -> Point {
+        Point::new(
+            rect.origin.x + self.x * rect.size.width,
+            rect.origin.y + self.y * rect.size.height,
+        )
+    }
+}
+
+/// A color at a location of a gradient.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct GradientStop {
+    /// The color, which may change over time.
+    pub color: Computed<Color>,
+    /// The location, between 0 at the start and 1 at the end of the gradient.
+    pub location: f64,
+}
+
+impl GradientStop {
+    /// Creates a color stop.
+    pub fn new(color: impl IntoComputed<Color>, location: f64) -> Self {
+        Self {
+            color: color.into_computed(),
+            location,
+        }
+    }
+}
+
+/// A list of color stops, and how to interpolate between them.
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct Gradient {
+    /// The color stops, in any order.
+    pub stops: Vec<GradientStop>,
+    /// The color space colors are interpolated in.
+    pub interpolation: ColorSpace,
+}
+
+impl Gradient {
+    /// Creates a gradient from evenly spaced colors.
+    pub fn new<C: IntoComputed<Color>>(colors: impl IntoIterator<Item = C>) -> Self {
+        let colors: Vec<Computed<Color>> = colors
+            .into_iter()
+            .map(IntoComputed::into_computed)
+            .collect();
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        Self::from_stops(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(index, color)| GradientStop {
+                    color,
+                    location: index as f64 / last,
+                }),
+        )
+    }
+
+    /// Creates a gradient from color stops.
+    pub fn from_stops(stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        Self {
+            stops: stops.into_iter().collect(),
+            interpolation: ColorSpace::default(),
+        }
+    }
+
+    /// Sets the color space colors are interpolated in.
+    pub fn interpolation(mut self, space: ColorSpace) -> Self {
+        self.interpolation = space;
+        self
+    }
+
+    /// Returns the current color at `location`, between 0 and 1.
+    ///
+    /// Locations before the first stop or after the last one take the color of that stop.
+    /// An empty gradient is transparent.
+    pub fn color_at(&self, location: f64) -> Color {
+        let mut stops: Vec<(f64, &Computed<Color>)> = self
+            .stops
+            .iter()
+            .map(|stop| (stop.location, &stop.color))
+            .collect();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let next = stops.partition_point(|(stop, _)| *stop <= location);
+        match (
+            next.checked_sub(1).map(|index| stops[index]),
+            stops.get(next).copied(),
+        ) {
+            (None, None) => Color::TRANSPARENT,
+            (Some((_, color)), None) | (None, Some((_, color))) => color.compute(),
+            (Some((from, from_color)), Some((to, to_color))) => {
+                let progress = (location - from) / (to - from);
+                from_color.compute().mix_in(
+                    &to_color.compute(),
+                    progress as f32,
+                    self.interpolation,
+                )
+            }
+        }
+    }
+}
+
+impl<C: IntoComputed<Color>> FromIterator<C> for Gradient {
+    fn from_iter<T: IntoIterator<Item = C>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// A gradient along the line between two points.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct LinearGradient {
+    /// The colors of the gradient.
+    pub gradient: Gradient,
+    /// Where the gradient starts.
+    pub start: UnitPoint,
+    /// Where the gradient ends.
+    pub end: UnitPoint,
+}
+
+impl LinearGradient {
+    /// Creates a gradient going from `start` to `end`.
+    pub fn new(gradient: impl Into<Gradient>, start: UnitPoint, end: UnitPoint) -> Self {
+        Self {
+            gradient: gradient.into(),
+            start,
+            end,
+        }
+    }
+
+    /// Returns the location of `point` along the gradient laid out in `rect`.
+    pub fn location(&self, point: Point, rect: Rect) -> f64 {
+        let start = self.start.resolve(rect);
+        let end = self.end.resolve(rect);
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let length = dx * dx + dy * dy;
+        if length == 0.0 {
+            return 0.0;
+        }
+        ((point.x - start.x) * dx + (point.y - start.y) * dy) / length
+    }
+
+    /// Returns the color of `point` when the gradient is laid out in `rect`.
+    pub fn color_at(&self, point: Point, rect: Rect) -> Color {
+        self.gradient.color_at(self.location(point, rect))
+    }
+}
+
+/// A gradient along circles around a center.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct RadialGradient {
+    /// The colors of the gradient.
+    pub gradient: Gradient,
+    /// The center of the circles.
+    pub center: UnitPoint,
+    /// The radius where the gradient starts, in points.
+    pub start_radius: f64,
+    /// The radius where the gradient ends, in points.
+    pub end_radius: f64,
+}
+
+impl RadialGradient {
+    /// Creates a gradient going from `start_radius` to `end_radius` around `center`.
+    pub fn new(
+        gradient: impl Into<Gradient>,
+        center: UnitPoint,
+        start_radius: f64,
+        end_radius: f64,
+    ) -> Self {
+        Self {
+            gradient: gradient.into(),
+            center,
+            start_radius,
+            end_radius,
+        }
+    }
+
+    /// Returns the location of `point` along the gradient laid out in `rect`.
+    pub fn location(&self, point: Point, rect: Rect) -> f64 {
+        let center = self.center.resolve(rect);
+        let (dx, dy) = (point.x - center.x, point.y - center.y);
+        let distance = sqrt(dx * dx + dy * dy);
+        let span = self.end_radius - self.start_radius;
+        if span == 0.0 {
+            return if distance < self.start_radius {
+                0.0
+            } else {
+                1.0
+            };
+        }
+        (distance - self.start_radius) / span
+    }
+
+    /// Returns the color of `point` when the gradient is laid out in `rect`.
+    pub fn color_at(&self, point: Point, rect: Rect) -> Color {
+        self.gradient.color_at(self.location(point, rect))
+    }
+}
+
+/// A gradient sweeping around a center.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct AngularGradient {
+    /// The colors of the gradient.
+    pub gradient: Gradient,
+    /// The center of the sweep.
+    pub center: UnitPoint,
+    /// The angle where the gradient starts, in radians, growing clockwise from the
+    /// trailing direction.
+    pub start_angle: f64,
+    /// The angle where the gradient ends, in radians, after the start angle.
+    pub end_angle: f64,
+}
+
+impl AngularGradient {
+    /// Creates a gradient sweeping a full turn clockwise around `center`, from the top.
+    pub fn new(gradient: impl Into<Gradient>, center: UnitPoint) -> Self {
+        Self {
+            gradient: gradient.into(),
+            center,
+            start_angle: -FRAC_PI_2,
+            end_angle: 3.0 * FRAC_PI_2,
+        }
+    }
+
+    /// Sets the angles where the gradient starts and ends, in radians.
+    pub fn angles(mut self, start: f64, end: f64) -> Self {
+        self.start_angle = start;
+        self.end_angle = end;
+        self
+    }
+
+    /// Returns the location of `point` along the gradient laid out in `rect`.
+    pub fn location(&self, point: Point, rect: Rect) -> f64 {
+        let center = self.center.resolve(rect);
+        let span = self.end_angle - self.start_angle;
+        if span <= 0.0 {
+            return 0.0;
+        }
+        let angle = atan2(point.y - center.y, point.x - center.x);
+        let mut offset = fmod(angle - self.start_angle, TAU);
+        if offset < 0.0 {
+            offset += TAU;
+        }
+        // Points outside of a partial sweep take the closest end.
+        if offset > span {
+            let past_end = offset - span;
+            let before_start = TAU - offset;
+            return if past_end < before_start { 1.0 } else { 0.0 };
+        }
+        offset / span
+    }
+
+    /// Returns the color of `point` when the gradient is laid out in `rect`.
+    pub fn color_at(&self, point: Point, rect: Rect) -> Color {
+        self.gradient.color_at(self.location(point, rect))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_reactive::binding;
+
+    use super::{AngularGradient, Gradient, LinearGradient, RadialGradient, UnitPoint};
+    use crate::{
+        shape::{Point, Rect},
+        Color,
+    };
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn locations() {
+        let rect = Rect::new(0.0, 0.0, 100.0, 50.0);
+        let linear = LinearGradient::new(
+            Gradient::new([Color::BLACK, Color::WHITE]),
+            UnitPoint::LEADING,
+            UnitPoint::TRAILING,
+        );
+        assert!(close(linear.location(Point::new(25.0, 10.0), rect), 0.25));
+
+        let radial = RadialGradient::new(
+            Gradient::new([Color::BLACK, Color::WHITE]),
+            UnitPoint::CENTER,
+            10.0,
+            20.0,
+        );
+        assert!(close(radial.location(Point::new(65.0, 25.0), rect), 0.5));
+
+        let angular = AngularGradient::new(
+            Gradient::new([Color::BLACK, Color::WHITE]),
+            UnitPoint::CENTER,
+        );
+        // A full turn starts at the top and goes clockwise.
+        assert!(close(angular.location(Point::new(100.0, 25.0), rect), 0.25));
+        assert!(close(angular.location(Point::new(50.0, 50.0), rect), 0.5));
+    }
+
+    #[test]
+    fn reactive_stops() {
+        let end = binding(Color::WHITE);
+        let gradient = Gradient::new([binding(Color::BLACK), end.clone()]);
+        assert_eq!(gradient.color_at(-1.0), Color::BLACK);
+        assert_eq!(gradient.color_at(2.0), Color::WHITE);
+
+        end.set(Color::RED);
+        assert_eq!(gradient.color_at(1.0), Color::RED);
+        assert_eq!(gradient.color_at(0.5), Color::BLACK.mix(&Color::RED, 0.5));
+        assert_eq!(Gradient::default().color_at(0.5), Color::TRANSPARENT);
+    }
+}
@@ -6,14 +6,22 @@
 //! - `Handler` - For immutable handlers that don't change during execution
 //! - `HandlerMut` - For mutable handlers that may modify their state
 //! - `HandlerOnce` - For single-use handlers that are consumed during processing
+//! - `AsyncHandlerFn` - For asynchronous functions, run as actions through `AsyncAction`
 //!
 //! The module also provides utility functions to convert regular functions into handlers
 //! with automatic parameter extraction from environments.
 
 use crate::extract::Extractor;
-use alloc::boxed::Box;
-use core::marker::PhantomData;
+use alloc::{boxed::Box, rc::Rc};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    marker::PhantomData,
+    ops::AsyncFn,
+};
 use std::sync::Arc;
+use waterui_reactive::{Binding, Computed};
+use waterui_task::LocalTask;
 
 use crate::Environment;
 
@@ -33,6 +41,12 @@ impl Handler<()> for () {
     fn handle(&self, _env: &Environment) {}
 }
 
+impl<T, H: Handler<T> + ?Sized> Handler<T> for Rc<H> {
+    fn handle(&self, env: &Environment) -> T {
+        (**self).handle(env)
+    }
+}
+
 impl HandlerMut<()> for () {
     fn handle(&mut self, _env: &Environment) {}
 }
@@ -168,6 +182,37 @@ macro_rules! impl_handle_fn_once {
 
 tuples!(impl_handle_fn_once);
 
+/// Function-like trait for asynchronous handlers that extract parameters from the environment.
+///
+/// This is implemented for async closures and for closures returning a future.
+/// P represents the parameter types to extract, T represents the output of the future.
+pub trait AsyncHandlerFn<P, T>: 'static {
+    /// Internal implementation that extracts parameters from the environment and starts the handler.
+    fn handle_inner(&self, env: &Environment) -> impl Future<Output = T>;
+}
+
+macro_rules! impl_async_handle_fn {
+    ($($ty:ident),*) => {
+        #[allow(unused_variables)]
+        #[allow(non_snake_case)]
+        impl<F, R, $($ty:Extractor,)*> AsyncHandlerFn<($($ty,)*),R> for F
+        where
+            F: AsyncFn($($ty,)*) -> R+ 'static,
+        {
+            fn handle_inner(&self, env: &Environment) -> impl Future<Output = R> {
+
+                $(
+                    let $ty:$ty=Extractor::extract(env).unwrap();
+                )*
+
+                self($($ty,)*)
+            }
+        }
+    };
+}
+
+tuples!(impl_async_handle_fn);
+
 macro_rules! into_handlers {
     ($name:ident,$handler:ident,$handler_fn:ident) => {
         /// Wrapper that converts a function into a handler.
@@ -281,6 +326,94 @@ where
     IntoHandlerOnce::new(h)
 }
 
+/// Decides what happens when an asynchronous action is triggered while still running.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ActionPolicy {
+    /// Ignore the new trigger until the running action finishes.
+    #[default]
+    Ignore,
+    /// Cancel the running action and start a new one.
+    Cancel,
+}
+
+/// A handler running an asynchronous function on the main thread each time it is triggered.
+///
+/// The progress of the action is exposed through [`AsyncAction::running`], which controls
+/// can use to disable themselves or show a loading indicator.
+pub struct AsyncAction<H, P> {
+    handler: Rc<H>,
+    policy: ActionPolicy,
+    running: Binding<bool>,
+    generation: Rc<Cell<usize>>,
+    task: Rc<RefCell<Option<LocalTask<()>>>>,
+    _marker: PhantomData<P>,
+}
+
+impl<H, P> core::fmt::Debug for AsyncAction<H, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(core::any::type_name::<Self>())
+    }
+}
+
+impl<H, P> AsyncAction<H, P>
+where
+    H: AsyncHandlerFn<P, ()>,
+    P: 'static,
+{
+    /// Creates a new asynchronous action with the given policy for repeated triggers.
+    pub fn new(handler: H, policy: ActionPolicy) -> Self {
+        Self {
+            handler: Rc::new(handler),
+            policy,
+            running: Binding::container(false),
+            generation: Rc::default(),
+            task: Rc::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns whether the action is currently running.
+    pub fn running(&self) -> Computed<bool> {
+        self.running.clone().into()
+    }
+}
+
+impl<H, P> Handler<()> for AsyncAction<H, P>
+where
+    H: AsyncHandlerFn<P, ()>,
+    P: 'static,
+{
+    fn handle(&self, env: &Environment) {
+        if self.running.get() {
+            match self.policy {
+                ActionPolicy::Ignore => return,
+                ActionPolicy::Cancel => {
+                    if let Some(task) = self.task.take() {
+                        LocalTask::on_main(task.cancel());
+                    }
+                }
+            }
+        }
+
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        self.running.set(true);
+
+        let handler = self.handler.clone();
+        let running = self.running.clone();
+        let current = self.generation.clone();
+        let env = env.clone();
+        let task = LocalTask::on_main(async move {
+            handler.handle_inner(&env).await;
+            // A cancelled action may still finish before its cancellation is processed.
+            if current.get() == generation {
+                running.set(false);
+            }
+        });
+        self.task.replace(Some(task));
+    }
+}
+
 mod ffi {
     use std::sync::Arc;
 
@@ -311,3 +444,102 @@ mod ffi {
 }
 
 uniffi::custom_type!(ActionObject, Arc<ffi::FFIActionObject>);
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::{
+        cell::{Cell, RefCell},
+        future::poll_fn,
+        task::{Poll, Waker},
+    };
+
+    use waterui_reactive::Compute;
+
+    use super::{ActionPolicy, AsyncAction, AsyncHandlerFn, Handler};
+    use crate::Environment;
+
+    /// Counts the runs of an action, each run waiting until it is released.
+    #[derive(Default)]
+    struct Runs {
+        started: Cell<usize>,
+        finished: Cell<usize>,
+        released: Cell<bool>,
+        waker: RefCell<Option<Waker>>,
+    }
+
+    impl Runs {
+        async fn run(&self) {
+            self.started.set(self.started.get() + 1);
+            poll_fn(|cx| {
+                if self.released.take() {
+                    Poll::Ready(())
+                } else {
+                    self.waker.replace(Some(cx.waker().clone()));
+                    Poll::Pending
+                }
+            })
+            .await;
+            self.finished.set(self.finished.get() + 1);
+        }
+
+        fn release(&self) {
+            self.released.set(true);
+            let waker = self.waker.take();
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+
+        fn get(&self) -> (usize, usize) {
+            (self.started.get(), self.finished.get())
+        }
+    }
+
+    fn action(policy: ActionPolicy) -> (AsyncAction<impl AsyncHandlerFn<(), ()>, ()>, Rc<Runs>) {
+        let runs = Rc::new(Runs::default());
+        let counter = runs.clone();
+        (
+            AsyncAction::new(async move || counter.run().await, policy),
+            runs,
+        )
+    }
+
+    #[test]
+    fn ignore_while_running() {
+        let env = Environment::new();
+        let (action, runs) = action(ActionPolicy::Ignore);
+        let running = action.running();
+        assert!(!running.compute());
+
+        action.handle(&env);
+        assert!(running.compute());
+        action.handle(&env);
+        assert_eq!(runs.get(), (1, 0));
+
+        runs.release();
+        assert!(!running.compute());
+        assert_eq!(runs.get(), (1, 1));
+
+        action.handle(&env);
+        assert!(running.compute());
+        assert_eq!(runs.get(), (2, 1));
+    }
+
+    #[test]
+    fn cancel_while_running() {
+        let env = Environment::new();
+        let (action, runs) = action(ActionPolicy::Cancel);
+        let running = action.running();
+
+        action.handle(&env);
+        action.handle(&env);
+        assert_eq!(runs.get(), (2, 0));
+        assert!(running.compute());
+
+        // The first run was cancelled, so only the second one finishes.
+        runs.release();
+        assert_eq!(runs.get(), (2, 1));
+        assert!(!running.compute());
+    }
+}
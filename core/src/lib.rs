@@ -84,11 +84,15 @@
 //!
 //! ```rust
 //! pub trait Plugin: Sized + 'static {
+//!     fn dependencies(&self) -> Vec<PluginId>;
+//!     fn modifiers(&self) -> Vec<ModifierId>;
 //!     fn install(self, env: &mut Environment);
-//!     fn uninstall(self, env: &mut Environment);
 //! }
 //! ```
 //!
+//! Installed plugins are recorded in the environment, and uninstalling one restores
+//! everything it replaced.
+//!
 //! This enables modular functionality like theming, localization, and platform-specific features.
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -100,17 +104,23 @@ mod macros;
 pub mod components;
 pub use components::anyview::AnyView;
 pub mod env;
+pub mod error;
 pub mod view;
 pub use env::Environment;
 pub use paste::paste as __paste;
 pub use view::View;
 pub mod extract;
+pub mod focus;
 pub mod handler;
 pub mod plugin;
 pub use anyhow::Error;
+pub mod accessibility;
 pub mod animation;
 pub mod color;
+pub mod command;
 pub use color::Color;
+pub mod gesture;
+pub mod gradient;
 pub mod shape;
 pub use waterui_reactive as reactive;
 pub use waterui_reactive::{binding, constant, Binding, Compute, ComputeExt, Computed};
@@ -66,10 +66,11 @@ macro_rules! configurable {
         impl $crate::view::View for $view {
             fn body(self, env: &$crate::Environment) -> impl $crate::View {
                 use $crate::view::ConfigurableView;
-                if let Some(modifier) = env.get::<$crate::view::Modifier<Self>>() {
-                    $crate::components::AnyView::new(
-                        modifier.clone().modify(env.clone(), self.config()),
-                    )
+                let modifiers = env.get::<$crate::view::Modifiers<Self>>();
+                if let Some(modifiers) = modifiers.filter(|modifiers| !modifiers.is_empty()) {
+                    modifiers.apply(env, self.config())
+                } else if env.get::<$crate::accessibility::Inspecting>().is_some() {
+                    $crate::AnyView::new($crate::accessibility::Opaque::of::<Self>())
                 } else {
                     panic!("This view ({}) depends on a platform view, but the renderer is not handling it. Check the implementation of the renderer", core::any::type_name::<$view>())
                 }
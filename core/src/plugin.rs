@@ -6,15 +6,51 @@
 //!
 //! The plugin system supports:
 //! - Dynamic installation and removal of components
-//! - Separation of concerns through modular design
-//! - Extension of application functionality without modifying core code
+//! - Declared dependencies between plugins, checked at installation
+//! - Composed modifiers: plugins modifying the same view don't conflict, their modifiers
+//!   run in installation order, and uninstalling a plugin removes only its own
+//! - An enumerable registry of the installed plugins, kept in the environment
+//! - Clean removal: uninstalling a plugin restores every value it replaced
 //!
 //! # Usage
 //!
 //! Plugins are typically implemented as standalone structs that implement the `Plugin` trait.
 //! Once implemented, they can be installed into an `Environment` to extend its capabilities.
+//!
+//! Plugins are installed in the order of the calls to [`Environment::install`]. Dependencies
+//! are only checked, not installed or reordered, so a plugin must be installed after the
+//! plugins it depends on.
+//!
+//! # Migrating
+//!
+//! These are breaking changes for existing plugins:
+//!
+//! - `Plugin::uninstall` was removed, so plugins overriding it no longer compile. Plugins
+//!   are uninstalled with [`Environment::uninstall`], which restores the values replaced
+//!   by the plugin instead of removing them; cleanup done in `uninstall` is no longer
+//!   needed for values inserted in [`Plugin::install`]. Plugins registering modifiers
+//!   must declare them with [`Plugin::modifiers`] to have them removed.
+//! - Calling `plugin.install(&mut env)` directly still works, but bypasses the registry;
+//!   use [`Environment::install`] instead.
+//! - Installing a plugin a second time now keeps the first installation instead of
+//!   replacing it.
+//! - Two plugins modifying the same view used to be reported as a conflict. Their
+//!   modifiers are now composed on purpose, as a middleware stack (see
+//!   [`Modifiers`]), so there is no conflict to report: the
+//!   modifier of the plugin installed first runs first, and passes the configuration on
+//!   to the modifiers installed after it.
 
-use crate::Environment;
+use core::{
+    any::{type_name, Any, TypeId},
+    fmt::{self, Debug, Display},
+};
+
+use alloc::{collections::BTreeSet, rc::Rc, vec::Vec};
+
+use crate::{
+    view::{ConfigurableView, Modifiers},
+    Environment,
+};
 
 /// The `Plugin` trait defines the interface for components that can be installed into
 /// and removed from an `Environment`.
@@ -22,12 +58,14 @@ use crate::Environment;
 /// # Examples
 ///
 /// ```
+/// use waterui_core::{Environment, plugin::Plugin};
+///
 /// struct MyPlugin;
 ///
 /// impl Plugin for MyPlugin {
 ///     // Plugins don't require any implementation-specific methods by default,
-///     // but you can override the `install` and `uninstall` methods if your plugin
-///     // needs custom installation or removal behavior.
+///     // but you can override the `install` method if your plugin needs custom
+///     // installation behavior.
 ///     //
 ///     // For example, a plugin might:
 ///     // - Register event handlers
@@ -35,32 +73,460 @@ use crate::Environment;
 ///     // - Set up configurations
 ///     // - Connect to external services
 ///     //
-///     // The default implementation simply stores/removes the plugin
-///     // instance in the environment.
+///     // The default implementation simply stores the plugin instance
+///     // in the environment.
 /// }
 ///
-/// let mut env = Environment::new();
-/// MyPlugin.install(&mut env);
+/// let env = Environment::new().install(MyPlugin);
+/// assert!(env.is_installed::<MyPlugin>());
 /// ```
 pub trait Plugin: Sized + 'static {
+    /// Returns the plugins that must be installed before this one.
+    fn dependencies(&self) -> Vec<PluginId> {
+        Vec::new()
+    }
+
+    /// Returns the view modifiers this plugin registers.
+    ///
+    /// Modifiers of several plugins compose, so declaring them lets uninstallation remove
+    /// exactly the modifiers of this plugin, keeping those installed by other plugins.
+    fn modifiers(&self) -> Vec<ModifierId> {
+        Vec::new()
+    }
+
     /// Installs this plugin into the provided environment.
     ///
     /// This method adds the plugin instance to the environment's storage,
     /// making it available for later retrieval.
     ///
+    /// This is called by [`Environment::install`], which also records the plugin in
+    /// the registry. Calling it directly bypasses the registry, so the plugin can't
+    /// be uninstalled afterwards.
+    ///
     /// # Arguments
     ///
     /// * `env` - A mutable reference to the environment
     fn install(self, env: &mut Environment) {
         env.insert(self);
     }
+}
 
-    /// Removes this plugin from the provided environment.
-    ///
-    /// # Arguments
-    ///
-    /// * `env` - A mutable reference to the environment
-    fn uninstall(self, env: &mut Environment) {
-        env.remove::<Self>()
+/// Identifies a plugin type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PluginId {
+    id: TypeId,
+    name: &'static str,
+}
+
+impl PluginId {
+    /// Returns the identifier of the plugin `P`.
+    pub fn of<P: Plugin>() -> Self {
+        Self {
+            id: TypeId::of::<P>(),
+            name: type_name::<P>(),
+        }
+    }
+
+    /// Returns the type name of the plugin.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl Display for PluginId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Identifies the modifiers of a configurable view.
+#[derive(Clone, Copy)]
+pub struct ModifierId {
+    id: TypeId,
+    name: &'static str,
+    remove: RemoveFn,
+}
+
+/// Removes the modifiers added by a plugin, given the stacks after and before its installation.
+type RemoveFn = fn(&mut Environment, &Rc<dyn Any>, Option<&Rc<dyn Any>>);
+
+impl Debug for ModifierId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModifierId")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ModifierId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for ModifierId {}
+
+impl ModifierId {
+    /// Returns the identifier of the modifiers for views of type `V`.
+    pub fn of<V: ConfigurableView>() -> Self {
+        Self {
+            id: TypeId::of::<Modifiers<V>>(),
+            name: type_name::<V>(),
+            remove: remove_modifiers::<V>,
+        }
+    }
+
+    /// Returns the type name of the modified view.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl Display for ModifierId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "modifiers of `{}`", self.name)
+    }
+}
+
+/// An error raised when installing or uninstalling a plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginError {
+    /// The plugin is already installed.
+    AlreadyInstalled(PluginId),
+    /// The plugin isn't installed.
+    NotInstalled(PluginId),
+    /// A dependency of the plugin isn't installed.
+    MissingDependency {
+        /// The plugin being installed.
+        plugin: PluginId,
+        /// The missing dependency.
+        dependency: PluginId,
+    },
+    /// Another installed plugin depends on the plugin being uninstalled.
+    Required {
+        /// The plugin being uninstalled.
+        plugin: PluginId,
+        /// The installed plugin depending on it.
+        dependent: PluginId,
+    },
+}
+
+impl Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyInstalled(plugin) => write!(f, "Plugin `{plugin}` is already installed"),
+            Self::NotInstalled(plugin) => write!(f, "Plugin `{plugin}` is not installed"),
+            Self::MissingDependency { plugin, dependency } => write!(
+                f,
+                "Plugin `{plugin}` depends on `{dependency}`, which is not installed"
+            ),
+            Self::Required { plugin, dependent } => write!(
+                f,
+                "Plugin `{plugin}` can't be uninstalled because `{dependent}` depends on it"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PluginError {}
+
+/// Information about an installed plugin.
+#[derive(Clone)]
+pub struct PluginInfo {
+    id: PluginId,
+    dependencies: Vec<PluginId>,
+    modifiers: Vec<ModifierId>,
+    changes: Vec<Change>,
+}
+
+impl Debug for PluginInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginInfo")
+            .field("id", &self.id)
+            .field("dependencies", &self.dependencies)
+            .field("modifiers", &self.modifiers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PluginInfo {
+    /// Returns the identifier of the plugin.
+    pub const fn id(&self) -> PluginId {
+        self.id
+    }
+
+    /// Returns the plugins this plugin depends on.
+    pub fn dependencies(&self) -> &[PluginId] {
+        &self.dependencies
+    }
+
+    /// Returns the modifiers this plugin contributes.
+    pub fn modifiers(&self) -> &[ModifierId] {
+        &self.modifiers
+    }
+}
+
+/// A value written by a plugin during its installation.
+#[derive(Clone)]
+struct Change {
+    id: TypeId,
+    previous: Option<Rc<dyn Any>>,
+    installed: Option<Rc<dyn Any>>,
+}
+
+/// The registry of installed plugins, stored in the environment.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Plugins {
+    pub(crate) entries: Vec<PluginInfo>,
+}
+
+fn same(a: &Option<Rc<dyn Any>>, b: &Option<Rc<dyn Any>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Removes from the environment the modifiers in `installed` that aren't in `previous`.
+fn remove_modifiers<V: ConfigurableView>(
+    env: &mut Environment,
+    installed: &Rc<dyn Any>,
+    previous: Option<&Rc<dyn Any>>,
+) {
+    let installed = installed.downcast_ref::<Modifiers<V>>();
+    let previous = previous.and_then(|previous| previous.downcast_ref::<Modifiers<V>>());
+    let Some((installed, current)) = installed.zip(env.get::<Modifiers<V>>()) else {
+        return;
+    };
+
+    let mut current = current.clone();
+    current.retain(|modifier| {
+        !installed.contains(modifier)
+            || previous.is_some_and(|previous| previous.contains(modifier))
+    });
+    env.insert(current);
+}
+
+pub(crate) fn install<P: Plugin>(env: &mut Environment, plugin: P) -> Result<(), PluginError> {
+    let id = PluginId::of::<P>();
+    let mut plugins = env.get::<Plugins>().cloned().unwrap_or_default();
+
+    if plugins.entries.iter().any(|entry| entry.id == id) {
+        return Err(PluginError::AlreadyInstalled(id));
+    }
+
+    let dependencies = plugin.dependencies();
+    for dependency in &dependencies {
+        if !plugins.entries.iter().any(|entry| entry.id == *dependency) {
+            return Err(PluginError::MissingDependency {
+                plugin: id,
+                dependency: *dependency,
+            });
+        }
+    }
+
+    let modifiers = plugin.modifiers();
+
+    // Compare the environment before and after installation to record what was replaced.
+    let before = env.snapshot();
+    plugin.install(env);
+    let after = env.snapshot();
+
+    let registry = TypeId::of::<Plugins>();
+    let keys: BTreeSet<TypeId> = before.keys().chain(after.keys()).copied().collect();
+    let changes = keys
+        .into_iter()
+        .filter(|key| *key != registry)
+        .map(|key| Change {
+            id: key,
+            previous: before.get(&key).cloned(),
+            installed: after.get(&key).cloned(),
+        })
+        .filter(|change| !same(&change.previous, &change.installed))
+        .collect();
+
+    plugins.entries.push(PluginInfo {
+        id,
+        dependencies,
+        modifiers,
+        changes,
+    });
+    env.insert(plugins);
+    Ok(())
+}
+
+pub(crate) fn uninstall(env: &mut Environment, id: PluginId) -> Result<(), PluginError> {
+    let mut plugins = env.get::<Plugins>().cloned().unwrap_or_default();
+    let index = plugins
+        .entries
+        .iter()
+        .position(|entry| entry.id == id)
+        .ok_or(PluginError::NotInstalled(id))?;
+
+    if let Some(dependent) = plugins
+        .entries
+        .iter()
+        .find(|entry| entry.dependencies.contains(&id))
+    {
+        return Err(PluginError::Required {
+            plugin: id,
+            dependent: dependent.id,
+        });
+    }
+
+    let entry = plugins.entries.remove(index);
+    for change in entry.changes {
+        // Declared modifiers are removed from the stack, keeping those of other plugins.
+        if let Some(modifier) = entry
+            .modifiers
+            .iter()
+            .find(|modifier| modifier.id == change.id)
+        {
+            if let Some(installed) = &change.installed {
+                (modifier.remove)(env, installed, change.previous.as_ref());
+            }
+            continue;
+        }
+
+        // If a later plugin replaced this value in turn, it now restores our previous value.
+        let later = plugins.entries[index..]
+            .iter_mut()
+            .flat_map(|entry| entry.changes.iter_mut())
+            .find(|later| later.id == change.id);
+
+        if let Some(later) = later {
+            later.previous = change.previous;
+        } else if same(&env.get_raw(change.id), &change.installed) {
+            env.set_raw(change.id, change.previous);
+        }
+    }
+
+    env.insert(plugins);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::{ModifierId, Plugin, PluginError, PluginId};
+    use crate::{
+        view::{
+            tests::{logging, Log, Logged},
+            Modifiers,
+        },
+        Environment,
+    };
+
+    struct Base(i32);
+    impl Plugin for Base {}
+
+    struct Extension;
+    impl Plugin for Extension {
+        fn dependencies(&self) -> alloc::vec::Vec<PluginId> {
+            vec![PluginId::of::<Base>()]
+        }
+
+        fn install(self, env: &mut Environment) {
+            env.insert(self);
+            env.insert("extension");
+        }
+    }
+
+    struct Override;
+    impl Plugin for Override {
+        fn install(self, env: &mut Environment) {
+            env.insert("override");
+        }
+    }
+
+    #[test]
+    fn dependencies() {
+        let mut env = Environment::new();
+        assert_eq!(
+            env.try_install(Extension),
+            Err(PluginError::MissingDependency {
+                plugin: PluginId::of::<Extension>(),
+                dependency: PluginId::of::<Base>(),
+            })
+        );
+
+        let mut env = env.install(Base(1)).install(Extension);
+        let installed: alloc::vec::Vec<_> = env.plugins().map(|plugin| plugin.id()).collect();
+        assert_eq!(
+            installed,
+            [PluginId::of::<Base>(), PluginId::of::<Extension>()]
+        );
+        assert!(matches!(
+            env.uninstall::<Base>(),
+            Err(PluginError::Required { .. })
+        ));
+        assert_eq!(env.get::<Base>().map(|base| base.0), Some(1));
+    }
+
+    #[test]
+    fn install_twice_keeps_the_first() {
+        let mut env = Environment::new().install(Base(1)).install(Base(2));
+        assert_eq!(env.get::<Base>().map(|base| base.0), Some(1));
+        assert_eq!(env.plugins().count(), 1);
+        assert_eq!(
+            env.try_install(Base(3)),
+            Err(PluginError::AlreadyInstalled(PluginId::of::<Base>()))
+        );
+    }
+
+    #[test]
+    fn uninstall_restores_values() {
+        let mut env = Environment::new()
+            .with("original")
+            .install(Base(1))
+            .install(Extension)
+            .install(Override);
+        assert_eq!(env.get::<&str>(), Some(&"override"));
+
+        env.uninstall::<Extension>().unwrap();
+        assert!(env.get::<Extension>().is_none());
+        assert_eq!(env.get::<&str>(), Some(&"override"));
+
+        env.uninstall::<Override>().unwrap();
+        assert_eq!(env.get::<&str>(), Some(&"original"));
+        assert!(!env.is_installed::<Override>());
+    }
+
+    struct Analytics;
+    impl Plugin for Analytics {
+        fn modifiers(&self) -> alloc::vec::Vec<ModifierId> {
+            vec![ModifierId::of::<Logged>()]
+        }
+
+        fn install(self, env: &mut Environment) {
+            env.insert_modifier(logging("analytics"));
+        }
+    }
+
+    struct Theme;
+    impl Plugin for Theme {
+        fn modifiers(&self) -> alloc::vec::Vec<ModifierId> {
+            vec![ModifierId::of::<Logged>()]
+        }
+
+        fn install(self, env: &mut Environment) {
+            env.insert_modifier(logging("theme"));
+        }
+    }
+
+    #[test]
+    fn uninstall_keeps_other_modifiers() {
+        let mut env = Environment::new().install(Analytics).install(Theme);
+        assert_eq!(env.get::<Modifiers<Logged>>().map(Modifiers::len), Some(2));
+
+        env.uninstall::<Analytics>().unwrap();
+        let log = Log::default();
+        let _ = env
+            .get::<Modifiers<Logged>>()
+            .unwrap()
+            .apply(&env, log.clone());
+        assert_eq!(*log.borrow(), ["theme", "view"]);
     }
 }
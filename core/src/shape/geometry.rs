@@ -0,0 +1,167 @@
+//! Points, sizes and rectangles.
+
+/// A point in a two-dimensional coordinate space, with y growing downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Record)]
+pub struct Point {
+    /// The horizontal coordinate.
+    pub x: f64,
+    /// The vertical coordinate.
+    pub y: f64,
+}
+
+impl Point {
+    /// The origin of the coordinate space.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    /// Creates a point from its coordinates.
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns this point moved by `dx` and `dy`.
+    pub const fn offset(self, dx: f64, dy: f64) -> Self {
+        Self::new(self.x + dx, self.y + dy)
+    }
+
+    /// Returns the point at `progress` on the segment from this point to `to`.
+    pub fn lerp(self, to: Self, progress: f64) -> Self {
+        Self::new(
+            self.x + (to.x - self.x) * progress,
+            self.y + (to.y - self.y) * progress,
+        )
+    }
+
+    /// Returns the distance between this point and `other`.
+    pub fn distance(self, other: Self) -> f64 {
+        libm::hypot(other.x - self.x, other.y - self.y)
+    }
+
+    /// Returns the point halfway between this point and `other`.
+    pub fn midpoint(self, other: Self) -> Self {
+        self.lerp(other, 0.5)
+    }
+}
+
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// A width and a height.
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Record)]
+pub struct Size {
+    /// The horizontal extent.
+    pub width: f64,
+    /// The vertical extent.
+    pub height: f64,
+}
+
+impl Size {
+    /// A size of zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    /// Creates a size from its dimensions.
+    pub const fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+}
+
+impl From<(f64, f64)> for Size {
+    fn from((width, height): (f64, f64)) -> Self {
+        Self::new(width, height)
+    }
+}
+
+/// An axis-aligned rectangle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Record)]
+pub struct Rect {
+    /// The top-left corner.
+    pub origin: Point,
+    /// The dimensions.
+    pub size: Size,
+}
+
+impl Rect {
+    /// An empty rectangle at the origin.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Creates a rectangle from its top-left corner and dimensions.
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            origin: Point::new(x, y),
+            size: Size::new(width, height),
+        }
+    }
+
+    /// Creates a rectangle of `size` at the origin.
+    pub const fn from_size(size: Size) -> Self {
+        Self {
+            origin: Point::ZERO,
+            size,
+        }
+    }
+
+    /// Returns the smallest x coordinate.
+    pub const fn min_x(&self) -> f64 {
+        self.origin.x
+    }
+
+    /// Returns the smallest y coordinate.
+    pub const fn min_y(&self) -> f64 {
+        self.origin.y
+    }
+
+    /// Returns the largest x coordinate.
+    pub const fn max_x(&self) -> f64 {
+        self.origin.x + self.size.width
+    }
+
+    /// Returns the largest y coordinate.
+    pub const fn max_y(&self) -> f64 {
+        self.origin.y + self.size.height
+    }
+
+    /// Returns the width.
+    pub const fn width(&self) -> f64 {
+        self.size.width
+    }
+
+    /// Returns the height.
+    pub const fn height(&self) -> f64 {
+        self.size.height
+    }
+
+    /// Returns the center point.
+    pub const fn center(&self) -> Point {
+        Point::new(
+            self.origin.x + self.size.width / 2.0,
+            self.origin.y + self.size.height / 2.0,
+        )
+    }
+
+    /// Returns this rectangle shrunk by `amount` on every side.
+    pub const fn inset(&self, amount: f64) -> Self {
+        Self::new(
+            self.origin.x + amount,
+            self.origin.y + amount,
+            self.size.width - 2.0 * amount,
+            self.size.height - 2.0 * amount,
+        )
+    }
+
+    /// Returns whether `point` lies within the rectangle, edges included.
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min_x()..=self.max_x()).contains(&point.x)
+            && (self.min_y()..=self.max_y()).contains(&point.y)
+    }
+
+    /// Returns the smallest rectangle containing both rectangles.
+    pub fn union(&self, other: &Self) -> Self {
+        let min_x = self.min_x().min(other.min_x());
+        let min_y = self.min_y().min(other.min_y());
+        let max_x = self.max_x().max(other.max_x());
+        let max_y = self.max_y().max(other.max_y());
+        Self::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+}
@@ -0,0 +1,408 @@
+//! Provides geometric shapes for UI elements
+//!
+//! This module contains various shape implementations that can be used to create
+//! different UI elements. Each shape implements the `Shape` trait, describing its outline
+//! as a [`Path`] within the rectangle it is laid out in, so shapes stay sharp at any
+//! resolution and their geometry can be inspected without a renderer.
+//!
+//! Available shapes include:
+//! - `Rectangle`: A basic rectangular shape with no rounded corners
+//! - `RoundedRectangle`: A rectangular shape with customizable rounded corners
+//! - `UnevenRoundedRectangle`: A rectangular shape with a different radius per corner
+//! - `Circle`: A perfect circular shape
+//! - `Ellipse`: An ellipse filling its rectangle
+//! - `Capsule`: A rectangle with fully rounded ends
+//! - `Polygon` and `Star`: Regular polygons and stars
+//! - `Path`: Any outline, built from lines and curves or parsed from SVG path data
+//!
+//! All shapes can be used directly with the rendering system, and styled with
+//! [`ShapeExt::fill`] and [`ShapeExt::stroke`], using colors or gradients.
+
+mod geometry;
+mod path;
+mod style;
+mod svg;
+
+pub use geometry::{Point, Rect, Size};
+pub use path::{CornerRadii, Path, PathElement};
+pub use style::{FillRule, FilledShape, LineCap, LineJoin, Paint, StrokeStyle, StrokedShape};
+pub use svg::ParsePathError;
+
+use alloc::rc::Rc;
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use libm::{cos, sin};
+use waterui_reactive::{compute::IntoComputed, Compute, Computed};
+
+/// Rectangle shape with no rounded corners
+///
+/// This shape can be used to create rectangular UI elements.
+#[derive(Debug)]
+#[must_use]
+#[derive(uniffi::Record)]
+pub struct Rectangle;
+
+/// Rectangle shape with rounded corners
+///
+/// This shape can be used to create rectangular UI elements with rounded corners.
+/// The corner radius is specified by the `radius` field.
+#[derive(Debug)]
+#[must_use]
+pub struct RoundedRectangle {
+    /// The radius of the rounded corners
+    pub radius: Computed<f64>,
+}
+
+impl RoundedRectangle {
+    /// Creates a rounded rectangle with the given corner radius
+    pub fn new(radius: impl IntoComputed<f64>) -> Self {
+        Self {
+            radius: radius.into_computed(),
+        }
+    }
+}
+
+/// Rectangle shape with a different radius for each corner
+#[derive(Debug)]
+#[must_use]
+pub struct UnevenRoundedRectangle {
+    /// The radius of each corner
+    pub radii: CornerRadii,
+}
+
+impl UnevenRoundedRectangle {
+    /// Creates a rectangle with the given corner radii
+    pub const fn new(radii: CornerRadii) -> Self {
+        Self { radii }
+    }
+}
+
+/// Circle shape
+///
+/// This shape can be used to create circular UI elements.
+/// The circle is centered in its rectangle, with a diameter of its smallest side.
+#[derive(Debug)]
+#[must_use]
+pub struct Circle;
+
+/// Ellipse shape
+///
+/// The ellipse fills the rectangle it is laid out in.
+#[derive(Debug)]
+#[must_use]
+pub struct Ellipse;
+
+/// Capsule shape
+///
+/// A rectangle whose shortest sides are half circles.
+#[derive(Debug)]
+#[must_use]
+pub struct Capsule;
+
+/// Regular polygon shape
+///
+/// The polygon is inscribed in the ellipse filling its rectangle, with a vertex at the top.
+#[derive(Debug)]
+#[must_use]
+pub struct Polygon {
+    /// The number of sides, at least 3
+    pub sides: usize,
+}
+
+impl Polygon {
+    /// Creates a regular polygon with the given number of sides
+    pub const fn new(sides: usize) -> Self {
+        Self { sides }
+    }
+}
+
+/// Star shape
+///
+/// The star is inscribed in the ellipse filling its rectangle, with a point at the top.
+#[derive(Debug)]
+#[must_use]
+pub struct Star {
+    /// The number of points, at least 2
+    pub points: usize,
+    /// The radius of the inner vertices, as a fraction of the outer radius
+    pub inner_radius: f64,
+}
+
+impl Star {
+    /// Creates a star with the given number of points and inner radius ratio
+    pub const fn new(points: usize, inner_radius: f64) -> Self {
+        Self {
+            points,
+            inner_radius,
+        }
+    }
+}
+
+/// Trait representing a shape that can be drawn
+///
+/// This trait is implemented by all shape types in this module.
+pub trait Shape: 'static {
+    /// Returns the outline of the shape when laid out in `rect`.
+    fn path(&self, rect: Rect) -> Path;
+}
+
+/// A type-erased shape.
+#[derive(Clone)]
+pub struct AnyShape(Rc<dyn Shape>);
+
+impl_debug!(AnyShape);
+
+impl AnyShape {
+    /// Creates a type-erased shape.
+    pub fn new(shape: impl Shape) -> Self {
+        Self(Rc::new(shape))
+    }
+}
+
+impl Shape for AnyShape {
+    fn path(&self, rect: Rect) -> Path {
+        self.0.path(rect)
+    }
+}
+
+impl Shape for Rectangle {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().rect(rect)
+    }
+}
+
+impl Shape for RoundedRectangle {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().rounded_rect(rect, self.radius.compute())
+    }
+}
+
+impl Shape for UnevenRoundedRectangle {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().rounded_rect(rect, self.radii)
+    }
+}
+
+impl Shape for Circle {
+    fn path(&self, rect: Rect) -> Path {
+        let diameter = rect.width().min(rect.height());
+        let center = rect.center();
+        Path::new().ellipse(Rect::new(
+            center.x - diameter / 2.0,
+            center.y - diameter / 2.0,
+            diameter,
+            diameter,
+        ))
+    }
+}
+
+impl Shape for Ellipse {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().ellipse(rect)
+    }
+}
+
+impl Shape for Capsule {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().rounded_rect(rect, rect.width().min(rect.height()) / 2.0)
+    }
+}
+
+/// Returns `count` points evenly spread on the ellipse filling `rect`, starting at the top,
+/// with every other point moved to `alternate` times the radius.
+fn radial_points(rect: Rect, count: usize, alternate: f64) -> impl Iterator<Item = Point> {
+    let center = rect.center();
+    let (rx, ry) = (rect.width() / 2.0, rect.height() / 2.0);
+    (0..count).map(move |index| {
+        let angle = -FRAC_PI_2 + 2.0 * PI * index as f64 / count as f64;
+        let scale = if index % 2 == 1 { alternate } else { 1.0 };
+        Point::new(
+            center.x + rx * scale * cos(angle),
+            center.y + ry * scale * sin(angle),
+        )
+    })
+}
+
+impl Shape for Polygon {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().polygon(radial_points(rect, self.sides.max(3), 1.0))
+    }
+}
+
+impl Shape for Star {
+    fn path(&self, rect: Rect) -> Path {
+        Path::new().polygon(radial_points(
+            rect,
+            self.points.max(2) * 2,
+            self.inner_radius,
+        ))
+    }
+}
+
+impl Shape for Path {
+    /// Returns the path unchanged, as it is already in the coordinate space of its view.
+    fn path(&self, _rect: Rect) -> Path {
+        self.clone()
+    }
+}
+
+/// Extension methods to style shapes.
+pub trait ShapeExt: Shape + Sized {
+    /// Fills the shape with a color or a gradient.
+    fn fill(self, paint: impl Into<Paint>) -> FilledShape {
+        FilledShape::new(self, paint)
+    }
+
+    /// Draws the outline of the shape with a color or a gradient.
+    ///
+    /// # Arguments
+    /// * `paint` - What the outline is painted with
+    /// * `style` - The stroke style, or simply its width
+    fn stroke(self, paint: impl Into<Paint>, style: impl Into<StrokeStyle>) -> StrokedShape {
+        StrokedShape::new(self, paint, style)
+    }
+}
+
+impl<S: Shape> ShapeExt for S {}
+
+/// Implements raw_view for multiple shape types
+///
+/// This macro helps reduce boilerplate by implementing common functionality
+/// across multiple shape types.
+macro_rules! impl_shape {
+    ($($ty:ty),*) => {
+        $(
+            raw_view!($ty);
+        )*
+    };
+}
+
+impl_shape!(
+    Rectangle,
+    RoundedRectangle,
+    UnevenRoundedRectangle,
+    Circle,
+    Ellipse,
+    Capsule,
+    Polygon,
+    Star,
+    Path
+);
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{
+        Capsule, Circle, CornerRadii, ParsePathError, Path, PathElement, Point, Polygon, Rect,
+        Rectangle, Shape, Star, UnevenRoundedRectangle,
+    };
+
+    fn close(a: Point, b: Point) -> bool {
+        (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6
+    }
+
+    #[test]
+    fn parse_svg() {
+        let path: Path = "m10 10 20 0 v10 h-20z M0,0 C 1 2 3 4 5 6 s 1 1 2 2 Q1-1.5.5 2 t3 3"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            &path.elements()[..5],
+            &[
+                PathElement::MoveTo(Point::new(10.0, 10.0)),
+                PathElement::LineTo(Point::new(30.0, 10.0)),
+                PathElement::LineTo(Point::new(30.0, 20.0)),
+                PathElement::LineTo(Point::new(10.0, 20.0)),
+                PathElement::Close,
+            ]
+        );
+        assert_eq!(
+            path.elements()[7],
+            PathElement::CubicTo {
+                control1: Point::new(7.0, 8.0),
+                control2: Point::new(6.0, 7.0),
+                to: Point::new(7.0, 8.0),
+            }
+        );
+        assert_eq!(
+            path.elements()[8],
+            PathElement::QuadTo {
+                control: Point::new(1.0, -1.5),
+                to: Point::new(0.5, 2.0),
+            }
+        );
+        assert_eq!(
+            path.elements()[9],
+            PathElement::QuadTo {
+                control: Point::new(0.0, 5.5),
+                to: Point::new(3.5, 5.0),
+            }
+        );
+
+        let round_trip: Path = path.to_string().parse().unwrap();
+        assert_eq!(round_trip, path);
+
+        assert_eq!("L 1 1".parse::<Path>(), Err(ParsePathError::MissingMoveTo));
+        assert_eq!("M 1".parse::<Path>(), Err(ParsePathError::UnexpectedEnd));
+        assert_eq!(
+            "M 0 0 A 1 1 0 2 0 3 3".parse::<Path>(),
+            Err(ParsePathError::InvalidFlag(14))
+        );
+    }
+
+    #[test]
+    fn arcs() {
+        // A half circle from the left to the right of a circle of radius 10 around (10, 0).
+        let path: Path = "M0 0 A10 10 0 0 1 20 0".parse().unwrap();
+        let PathElement::CubicTo { to, .. } = path.elements()[1] else {
+            panic!("arcs are made of cubic curves");
+        };
+        // Clockwise with y growing downwards goes through the top.
+        assert!(close(to, Point::new(10.0, -10.0)));
+        assert_eq!(path.current_point(), Some(Point::new(20.0, 0.0)));
+
+        let bounds = Path::new()
+            .arc((0.0, 0.0), 5.0, 0.0, core::f64::consts::PI, true)
+            .bounding_rect()
+            .unwrap();
+        assert!((bounds.max_y() - 5.0).abs() < 1e-9 && bounds.min_y() > -1e-9);
+    }
+
+    #[test]
+    fn shapes() {
+        let rect = Rect::new(0.0, 0.0, 40.0, 20.0);
+        assert_eq!(Rectangle.path(rect).elements().len(), 5);
+        assert_eq!(
+            Rectangle.path(rect).bounding_rect(),
+            Some(Rect::new(0.0, 0.0, 40.0, 20.0))
+        );
+
+        let circle = Circle.path(rect).bounding_rect().unwrap();
+        assert!((circle.width() - 20.0).abs() < 1e-9 && (circle.min_x() - 10.0).abs() < 1e-9);
+
+        let capsule = Capsule.path(rect);
+        assert_eq!(
+            capsule.elements()[0],
+            PathElement::MoveTo(Point::new(10.0, 0.0))
+        );
+
+        let uneven = UnevenRoundedRectangle::new(CornerRadii {
+            top_leading: 100.0,
+            ..CornerRadii::default()
+        })
+        .path(rect);
+        // Radii are clamped to half the smallest side.
+        assert_eq!(
+            uneven.elements()[0],
+            PathElement::MoveTo(Point::new(10.0, 0.0))
+        );
+
+        let square = Polygon::new(4).path(Rect::new(0.0, 0.0, 2.0, 2.0));
+        assert!(close(square.current_point().unwrap(), Point::new(1.0, 0.0)));
+        assert_eq!(square.elements().len(), 5);
+
+        let star = Star::new(5, 0.5).path(rect);
+        assert_eq!(star.elements().len(), 11);
+    }
+}
@@ -0,0 +1,490 @@
+//! Vector paths made of lines and Bézier curves.
+
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use libm::{atan2, ceil, cos, fabs, fmod, sin, sqrt, tan};
+
+use super::{Point, Rect};
+
+/// A drawing instruction of a [`Path`].
+#[derive(Debug, Clone, Copy, PartialEq, uniffi::Enum)]
+pub enum PathElement {
+    /// Starts a new subpath at a point.
+    MoveTo(Point),
+    /// Draws a straight line to a point.
+    LineTo(Point),
+    /// Draws a quadratic Bézier curve.
+    QuadTo {
+        /// The control point.
+        control: Point,
+        /// The end point.
+        to: Point,
+    },
+    /// Draws a cubic Bézier curve.
+    CubicTo {
+        /// The first control point.
+        control1: Point,
+        /// The second control point.
+        control2: Point,
+        /// The end point.
+        to: Point,
+    },
+    /// Closes the current subpath with a straight line to its start.
+    Close,
+}
+
+/// The radius of each corner of a rounded rectangle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, uniffi::Record)]
+pub struct CornerRadii {
+    /// The radius of the top-leading corner.
+    pub top_leading: f64,
+    /// The radius of the top-trailing corner.
+    pub top_trailing: f64,
+    /// The radius of the bottom-trailing corner.
+    pub bottom_trailing: f64,
+    /// The radius of the bottom-leading corner.
+    pub bottom_leading: f64,
+}
+
+impl CornerRadii {
+    /// Uses the same radius for every corner.
+    pub const fn all(radius: f64) -> Self {
+        Self {
+            top_leading: radius,
+            top_trailing: radius,
+            bottom_trailing: radius,
+            bottom_leading: radius,
+        }
+    }
+}
+
+impl From<f64> for CornerRadii {
+    fn from(radius: f64) -> Self {
+        Self::all(radius)
+    }
+}
+
+/// A vector path, built from lines, Bézier curves and arcs.
+///
+/// Arcs are converted to cubic Bézier curves, so that renderers only deal with
+/// [`PathElement`]s.
+///
+/// # Examples
+///
+/// ```
+/// use waterui_core::shape::Path;
+///
+/// let triangle = Path::new()
+///     .move_to((0.0, 10.0))
+///     .line_to((5.0, 0.0))
+///     .line_to((10.0, 10.0))
+///     .close();
+///
+/// let heart: Path = "M 10,30 A 20,20 0,0,1 50,30 A 20,20 0,0,1 90,30 Q 90,60 50,90 Q 10,60 10,30 z"
+///     .parse()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, uniffi::Record)]
+pub struct Path {
+    elements: Vec<PathElement>,
+}
+
+impl Path {
+    /// Creates an empty path.
+    pub const fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+
+    /// Returns the drawing instructions of the path.
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    /// Returns whether the path draws nothing.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the point the next instruction starts from, if any.
+    pub fn current_point(&self) -> Option<Point> {
+        let mut start = None;
+        let mut current = None;
+        for element in &self.elements {
+            match *element {
+                PathElement::MoveTo(point) => {
+                    start = Some(point);
+                    current = Some(point);
+                }
+                PathElement::LineTo(to)
+                | PathElement::QuadTo { to, .. }
+                | PathElement::CubicTo { to, .. } => current = Some(to),
+                PathElement::Close => current = start,
+            }
+        }
+        current
+    }
+
+    /// Appends a drawing instruction.
+    pub fn push(&mut self, element: PathElement) {
+        self.elements.push(element);
+    }
+
+    /// Starts a new subpath at `point`.
+    pub fn move_to(mut self, point: impl Into<Point>) -> Self {
+        self.push(PathElement::MoveTo(point.into()));
+        self
+    }
+
+    /// Draws a straight line to `point`.
+    pub fn line_to(mut self, point: impl Into<Point>) -> Self {
+        self.push(PathElement::LineTo(point.into()));
+        self
+    }
+
+    /// Draws a quadratic Bézier curve to `to`.
+    pub fn quad_to(mut self, control: impl Into<Point>, to: impl Into<Point>) -> Self {
+        self.push(PathElement::QuadTo {
+            control: control.into(),
+            to: to.into(),
+        });
+        self
+    }
+
+    /// Draws a cubic Bézier curve to `to`.
+    pub fn cubic_to(
+        mut self,
+        control1: impl Into<Point>,
+        control2: impl Into<Point>,
+        to: impl Into<Point>,
+    ) -> Self {
+        self.push(PathElement::CubicTo {
+            control1: control1.into(),
+            control2: control2.into(),
+            to: to.into(),
+        });
+        self
+    }
+
+    /// Closes the current subpath.
+    pub fn close(mut self) -> Self {
+        self.push(PathElement::Close);
+        self
+    }
+
+    /// Draws an arc of a circle around `center`, from `start_angle` to `end_angle` in
+    /// radians.
+    ///
+    /// Angles grow clockwise from the positive x axis, as y grows downwards. A line joins
+    /// the current point to the start of the arc, or a new subpath starts there.
+    pub fn arc(
+        mut self,
+        center: impl Into<Point>,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        clockwise: bool,
+    ) -> Self {
+        let center = center.into();
+        let mut sweep = fmod(end_angle - start_angle, TAU);
+        if clockwise && sweep <= 0.0 {
+            sweep += TAU;
+        } else if !clockwise && sweep >= 0.0 {
+            sweep -= TAU;
+        }
+
+        let start = Point::new(
+            center.x + radius * cos(start_angle),
+            center.y + radius * sin(start_angle),
+        );
+        if self.current_point().is_some() {
+            self.push(PathElement::LineTo(start));
+        } else {
+            self.push(PathElement::MoveTo(start));
+        }
+        self.append_arc(center, radius, radius, 0.0, start_angle, sweep);
+        self
+    }
+
+    /// Draws an elliptical arc to `to`, following the SVG arc parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius_x`, `radius_y` - The radii of the ellipse, scaled up if too small
+    /// * `rotation` - The rotation of the ellipse, in radians
+    /// * `large_arc` - Whether to take the arc spanning more than 180 degrees
+    /// * `sweep` - Whether to go clockwise from the current point
+    /// * `to` - The end point
+    pub fn arc_to(
+        mut self,
+        radius_x: f64,
+        radius_y: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        to: impl Into<Point>,
+    ) -> Self {
+        let to = to.into();
+        let from = self.current_point().unwrap_or(Point::ZERO);
+        if from == to {
+            return self;
+        }
+        let (mut rx, mut ry) = (fabs(radius_x), fabs(radius_y));
+        if rx == 0.0 || ry == 0.0 {
+            return self.line_to(to);
+        }
+
+        // Conversion from endpoint to center parameterization, see the SVG specification.
+        let (sin_phi, cos_phi) = (sin(rotation), cos(rotation));
+        let dx = (from.x - to.x) / 2.0;
+        let dy = (from.y - to.y) / 2.0;
+        let x1 = cos_phi * dx + sin_phi * dy;
+        let y1 = -sin_phi * dx + cos_phi * dy;
+
+        let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= sqrt(lambda);
+            ry *= sqrt(lambda);
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+        let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+        let mut coefficient = sqrt((numerator / denominator).max(0.0));
+        if large_arc == sweep {
+            coefficient = -coefficient;
+        }
+        let cx1 = coefficient * rx * y1 / ry;
+        let cy1 = -coefficient * ry * x1 / rx;
+        let center = Point::new(
+            cos_phi * cx1 - sin_phi * cy1 + (from.x + to.x) / 2.0,
+            sin_phi * cx1 + cos_phi * cy1 + (from.y + to.y) / 2.0,
+        );
+
+        let angle =
+            |ux: f64, uy: f64, vx: f64, vy: f64| atan2(ux * vy - uy * vx, ux * vx + uy * vy);
+        let start = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+        let mut delta = angle(
+            (x1 - cx1) / rx,
+            (y1 - cy1) / ry,
+            (-x1 - cx1) / rx,
+            (-y1 - cy1) / ry,
+        );
+        if !sweep && delta > 0.0 {
+            delta -= TAU;
+        } else if sweep && delta < 0.0 {
+            delta += TAU;
+        }
+
+        self.append_arc(center, rx, ry, rotation, start, delta);
+        // Land exactly on the requested point despite rounding errors.
+        if let Some(PathElement::CubicTo { to: end, .. }) = self.elements.last_mut() {
+            *end = to;
+        }
+        self
+    }
+
+    /// Appends cubic curves approximating an elliptical arc, starting at the current point.
+    fn append_arc(
+        &mut self,
+        center: Point,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        start: f64,
+        sweep: f64,
+    ) {
+        if sweep == 0.0 || (rx == 0.0 && ry == 0.0) {
+            return;
+        }
+        let (sin_phi, cos_phi) = (sin(rotation), cos(rotation));
+        let point = |angle: f64| {
+            let (x, y) = (rx * cos(angle), ry * sin(angle));
+            Point::new(
+                center.x + cos_phi * x - sin_phi * y,
+                center.y + sin_phi * x + cos_phi * y,
+            )
+        };
+        let tangent = |angle: f64| {
+            let (x, y) = (-rx * sin(angle), ry * cos(angle));
+            (cos_phi * x - sin_phi * y, sin_phi * x + cos_phi * y)
+        };
+
+        // Quarter turns or less keep the approximation error invisible.
+        let segments = ceil(fabs(sweep) / FRAC_PI_2 - 1e-9).max(1.0) as usize;
+        let delta = sweep / segments as f64;
+        let k = 4.0 / 3.0 * tan(delta / 4.0);
+        for i in 0..segments {
+            let from = start + delta * i as f64;
+            let to = from + delta;
+            let (p0, p1) = (point(from), point(to));
+            let (t0, t1) = (tangent(from), tangent(to));
+            self.push(PathElement::CubicTo {
+                control1: p0.offset(k * t0.0, k * t0.1),
+                control2: p1.offset(-k * t1.0, -k * t1.1),
+                to: p1,
+            });
+        }
+    }
+
+    /// Adds a closed rectangle as a new subpath.
+    pub fn rect(self, rect: Rect) -> Self {
+        self.move_to(rect.origin)
+            .line_to((rect.max_x(), rect.min_y()))
+            .line_to((rect.max_x(), rect.max_y()))
+            .line_to((rect.min_x(), rect.max_y()))
+            .close()
+    }
+
+    /// Adds a closed rectangle with rounded corners as a new subpath.
+    ///
+    /// Radii are clamped to half the smallest side of the rectangle.
+    pub fn rounded_rect(mut self, rect: Rect, radii: impl Into<CornerRadii>) -> Self {
+        let radii = radii.into();
+        let limit = (rect.width().min(rect.height()) / 2.0).max(0.0);
+        let clamp = |radius: f64| radius.clamp(0.0, limit);
+        let top_leading = clamp(radii.top_leading);
+        let top_trailing = clamp(radii.top_trailing);
+        let bottom_trailing = clamp(radii.bottom_trailing);
+        let bottom_leading = clamp(radii.bottom_leading);
+
+        self.push(PathElement::MoveTo(Point::new(
+            rect.min_x() + top_leading,
+            rect.min_y(),
+        )));
+        let corners = [
+            (
+                Point::new(rect.max_x() - top_trailing, rect.min_y()),
+                Point::new(rect.max_x() - top_trailing, rect.min_y() + top_trailing),
+                top_trailing,
+                -FRAC_PI_2,
+            ),
+            (
+                Point::new(rect.max_x(), rect.max_y() - bottom_trailing),
+                Point::new(
+                    rect.max_x() - bottom_trailing,
+                    rect.max_y() - bottom_trailing,
+                ),
+                bottom_trailing,
+                0.0,
+            ),
+            (
+                Point::new(rect.min_x() + bottom_leading, rect.max_y()),
+                Point::new(rect.min_x() + bottom_leading, rect.max_y() - bottom_leading),
+                bottom_leading,
+                FRAC_PI_2,
+            ),
+            (
+                Point::new(rect.min_x(), rect.min_y() + top_leading),
+                Point::new(rect.min_x() + top_leading, rect.min_y() + top_leading),
+                top_leading,
+                PI,
+            ),
+        ];
+        for (line_end, center, radius, start) in corners {
+            self.push(PathElement::LineTo(line_end));
+            self.append_arc(center, radius, radius, 0.0, start, FRAC_PI_2);
+        }
+        self.close()
+    }
+
+    /// Adds a closed ellipse inscribed in `rect` as a new subpath.
+    pub fn ellipse(mut self, rect: Rect) -> Self {
+        let center = rect.center();
+        let (rx, ry) = (rect.width() / 2.0, rect.height() / 2.0);
+        self.push(PathElement::MoveTo(Point::new(center.x + rx, center.y)));
+        self.append_arc(center, rx, ry, 0.0, 0.0, TAU);
+        self.close()
+    }
+
+    /// Adds a closed polygon through `points` as a new subpath.
+    pub fn polygon(mut self, points: impl IntoIterator<Item = Point>) -> Self {
+        let mut points = points.into_iter();
+        let Some(first) = points.next() else {
+            return self;
+        };
+        self.push(PathElement::MoveTo(first));
+        for point in points {
+            self.push(PathElement::LineTo(point));
+        }
+        self.close()
+    }
+
+    /// Adds the elements of `other` to this path.
+    pub fn append(mut self, other: Self) -> Self {
+        self.elements.extend(other.elements);
+        self
+    }
+
+    /// Returns the path with every point transformed by `f`.
+    ///
+    /// Only affine transforms keep the curves exact.
+    pub fn map_points(mut self, f: impl Fn(Point) -> Point) -> Self {
+        for element in &mut self.elements {
+            match element {
+                PathElement::MoveTo(point) | PathElement::LineTo(point) => *point = f(*point),
+                PathElement::QuadTo { control, to } => {
+                    *control = f(*control);
+                    *to = f(*to);
+                }
+                PathElement::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    *control1 = f(*control1);
+                    *control2 = f(*control2);
+                    *to = f(*to);
+                }
+                PathElement::Close => {}
+            }
+        }
+        self
+    }
+
+    /// Returns the path moved by `dx` and `dy`.
+    pub fn translate(self, dx: f64, dy: f64) -> Self {
+        self.map_points(|point| point.offset(dx, dy))
+    }
+
+    /// Returns the path scaled from the origin.
+    pub fn scale(self, sx: f64, sy: f64) -> Self {
+        self.map_points(|point| Point::new(point.x * sx, point.y * sy))
+    }
+
+    /// Returns a rectangle containing the path, or `None` if it is empty.
+    ///
+    /// Control points are included, so the rectangle may be larger than the curves.
+    pub fn bounding_rect(&self) -> Option<Rect> {
+        let mut points = self.elements.iter().flat_map(|element| {
+            let points: [Option<Point>; 3] = match *element {
+                PathElement::MoveTo(point) | PathElement::LineTo(point) => {
+                    [Some(point), None, None]
+                }
+                PathElement::QuadTo { control, to } => [Some(control), Some(to), None],
+                PathElement::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => [Some(control1), Some(control2), Some(to)],
+                PathElement::Close => [None, None, None],
+            };
+            points.into_iter().flatten()
+        });
+        let first = points.next()?;
+        Some(
+            points.fold(Rect::new(first.x, first.y, 0.0, 0.0), |rect, point| {
+                rect.union(&Rect::new(point.x, point.y, 0.0, 0.0))
+            }),
+        )
+    }
+}
+
+impl FromIterator<PathElement> for Path {
+    fn from_iter<T: IntoIterator<Item = PathElement>>(iter: T) -> Self {
+        Self {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Fills and strokes.
+
+use alloc::vec::Vec;
+
+use waterui_reactive::{compute::IntoComputed, Binding, Computed};
+
+use super::{AnyShape, Shape};
+use crate::{
+    color::SemanticColor,
+    gradient::{AngularGradient, LinearGradient, RadialGradient},
+    Color,
+};
+
+/// What the inside or the outline of a shape is painted with.
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Paint {
+    /// A solid color.
+    Color(Computed<Color>),
+    /// A linear gradient.
+    LinearGradient(LinearGradient),
+    /// A radial gradient.
+    RadialGradient(RadialGradient),
+    /// An angular gradient.
+    AngularGradient(AngularGradient),
+}
+
+impl Paint {
+    /// Creates a paint of a solid color, which may change over time.
+    pub fn color(color: impl IntoComputed<Color>) -> Self {
+        Self::Color(color.into_computed())
+    }
+}
+
+macro_rules! impl_paint_from_color {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Paint {
+                fn from(value: $ty) -> Self {
+                    Self::color(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_paint_from_color!(Color, Computed<Color>, Binding<Color>);
+
+impl From<SemanticColor> for Paint {
+    fn from(value: SemanticColor) -> Self {
+        Self::color(Color::from(value))
+    }
+}
+
+impl From<LinearGradient> for Paint {
+    fn from(value: LinearGradient) -> Self {
+        Self::LinearGradient(value)
+    }
+}
+
+impl From<RadialGradient> for Paint {
+    fn from(value: RadialGradient) -> Self {
+        Self::RadialGradient(value)
+    }
+}
+
+impl From<AngularGradient> for Paint {
+    fn from(value: AngularGradient) -> Self {
+        Self::AngularGradient(value)
+    }
+}
+
+/// How the inside of a self-intersecting path is determined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, uniffi::Enum)]
+pub enum FillRule {
+    /// A point is inside if the path winds around it a non-zero number of times.
+    #[default]
+    NonZero,
+    /// A point is inside if a ray from it crosses the path an odd number of times.
+    EvenOdd,
+}
+
+/// The shape drawn at the ends of open strokes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, uniffi::Enum)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end point.
+    #[default]
+    Butt,
+    /// The stroke ends with a half circle.
+    Round,
+    /// The stroke ends with a half square, extending past the end point.
+    Square,
+}
+
+/// The shape drawn where two segments of a stroke meet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, uniffi::Enum)]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet, up to the miter limit.
+    #[default]
+    Miter,
+    /// The corner is rounded.
+    Round,
+    /// The corner is cut off.
+    Bevel,
+}
+
+/// How the outline of a shape is drawn.
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct StrokeStyle {
+    /// The width of the stroke, in points.
+    pub width: f64,
+    /// The shape at the ends of open subpaths.
+    pub cap: LineCap,
+    /// The shape of the corners.
+    pub join: LineJoin,
+    /// The longest a miter join may be, relative to the width, before becoming a bevel.
+    pub miter_limit: f64,
+    /// The lengths of alternating dashes and gaps, or empty for a solid stroke.
+    pub dash: Vec<f64>,
+    /// How far into the dash pattern the stroke starts.
+    pub dash_phase: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            cap: LineCap::default(),
+            join: LineJoin::default(),
+            miter_limit: 10.0,
+            dash: Vec::new(),
+            dash_phase: 0.0,
+        }
+    }
+}
+
+impl StrokeStyle {
+    /// Creates a solid stroke of the given width.
+    pub fn new(width: f64) -> Self {
+        Self {
+            width,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the shape at the ends of open subpaths.
+    pub fn cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Sets the shape of the corners.
+    pub fn join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Sets the miter limit.
+    pub fn miter_limit(mut self, limit: f64) -> Self {
+        self.miter_limit = limit;
+        self
+    }
+
+    /// Makes the stroke dashed.
+    ///
+    /// # Arguments
+    /// * `pattern` - The lengths of alternating dashes and gaps
+    /// * `phase` - How far into the pattern the stroke starts
+    pub fn dash(mut self, pattern: impl Into<Vec<f64>>, phase: f64) -> Self {
+        self.dash = pattern.into();
+        self.dash_phase = phase;
+        self
+    }
+}
+
+impl From<f64> for StrokeStyle {
+    fn from(width: f64) -> Self {
+        Self::new(width)
+    }
+}
+
+/// A filled shape.
+#[derive(Debug)]
+#[must_use]
+pub struct FilledShape {
+    /// The filled shape.
+    pub shape: AnyShape,
+    /// What the inside of the shape is painted with.
+    pub paint: Paint,
+    /// How the inside of the shape is determined.
+    pub rule: FillRule,
+}
+
+impl FilledShape {
+    /// Creates a shape filled with `paint`.
+    pub fn new(shape: impl Shape, paint: impl Into<Paint>) -> Self {
+        Self {
+            shape: AnyShape::new(shape),
+            paint: paint.into(),
+            rule: FillRule::default(),
+        }
+    }
+
+    /// Sets how the inside of the shape is determined.
+    pub fn rule(mut self, rule: FillRule) -> Self {
+        self.rule = rule;
+        self
+    }
+}
+
+/// The outline of a shape.
+#[derive(Debug)]
+#[must_use]
+pub struct StrokedShape {
+    /// The stroked shape.
+    pub shape: AnyShape,
+    /// What the outline is painted with.
+    pub paint: Paint,
+    /// How the outline is drawn.
+    pub style: StrokeStyle,
+}
+
+impl StrokedShape {
+    /// Creates the outline of a shape painted with `paint`.
+    pub fn new(shape: impl Shape, paint: impl Into<Paint>, style: impl Into<StrokeStyle>) -> Self {
+        Self {
+            shape: AnyShape::new(shape),
+            paint: paint.into(),
+            style: style.into(),
+        }
+    }
+}
+
+raw_view!(FilledShape);
+raw_view!(StrokedShape);
@@ -0,0 +1,307 @@
+//! SVG path data.
+
+use core::{
+    f64::consts::PI,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use super::{Path, PathElement, Point};
+
+/// An error returned when parsing SVG path data fails.
+///
+/// Positions are byte offsets into the parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePathError {
+    /// The path data doesn't start with a move command.
+    MissingMoveTo,
+    /// A character isn't a command nor part of a number.
+    UnexpectedCharacter(usize),
+    /// A number is malformed.
+    InvalidNumber(usize),
+    /// An arc flag isn't `0` or `1`.
+    InvalidFlag(usize),
+    /// The path data ends in the middle of a command.
+    UnexpectedEnd,
+}
+
+impl Display for ParsePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingMoveTo => f.write_str("Path data must start with a move command"),
+            Self::UnexpectedCharacter(position) => {
+                write!(f, "Unexpected character at {position}")
+            }
+            Self::InvalidNumber(position) => write!(f, "Invalid number at {position}"),
+            Self::InvalidFlag(position) => write!(f, "Invalid arc flag at {position}"),
+            Self::UnexpectedEnd => f.write_str("Unexpected end of path data"),
+        }
+    }
+}
+
+impl core::error::Error for ParsePathError {}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn skip_separators(&mut self) {
+        let rest = &self.source[self.position..];
+        let trimmed = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        self.position += rest.len() - trimmed.len();
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.source.as_bytes().get(self.position).copied()
+    }
+
+    fn at_number(&mut self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'-' | b'+' | b'.'))
+    }
+
+    fn number(&mut self) -> Result<f64, ParsePathError> {
+        let start = self.position;
+        let bytes = self.source.as_bytes();
+        if start >= bytes.len() {
+            return Err(ParsePathError::UnexpectedEnd);
+        }
+        let mut end = start;
+        if matches!(bytes[end], b'-' | b'+') {
+            end += 1;
+        }
+        let mut seen_dot = false;
+        while end < bytes.len() {
+            match bytes[end] {
+                b'0'..=b'9' => {}
+                // A second dot starts the next number, as in `0.5.5`.
+                b'.' if !seen_dot => seen_dot = true,
+                _ => break,
+            }
+            end += 1;
+        }
+        if end < bytes.len() && matches!(bytes[end], b'e' | b'E') {
+            let mut exponent = end + 1;
+            if exponent < bytes.len() && matches!(bytes[exponent], b'-' | b'+') {
+                exponent += 1;
+            }
+            if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+                end = exponent;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+            }
+        }
+        let value = self.source[start..end]
+            .parse()
+            .map_err(|_| ParsePathError::InvalidNumber(start))?;
+        self.position = end;
+        Ok(value)
+    }
+
+    fn coordinate(&mut self) -> Result<f64, ParsePathError> {
+        if self.peek().is_none() {
+            return Err(ParsePathError::UnexpectedEnd);
+        }
+        self.number()
+    }
+
+    fn point(&mut self) -> Result<Point, ParsePathError> {
+        Ok(Point::new(self.coordinate()?, self.coordinate()?))
+    }
+
+    fn flag(&mut self) -> Result<bool, ParsePathError> {
+        match self.peek() {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                Ok(true)
+            }
+            Some(_) => Err(ParsePathError::InvalidFlag(self.position)),
+            None => Err(ParsePathError::UnexpectedEnd),
+        }
+    }
+}
+
+/// Reflects the last control point around `current` if it belongs to a curve of `kind`,
+/// as smooth curves do.
+fn reflect(last_control: Option<(u8, Point)>, kind: u8, current: Point) -> Point {
+    match last_control {
+        Some((last, control)) if last == kind => {
+            Point::new(2.0 * current.x - control.x, 2.0 * current.y - control.y)
+        }
+        _ => current,
+    }
+}
+
+impl Path {
+    /// Parses SVG path data, as found in the `d` attribute of a `<path>` element.
+    ///
+    /// Every command is supported, in absolute and relative forms.
+    pub fn from_svg(source: &str) -> Result<Self, ParsePathError> {
+        let mut parser = Parser {
+            source,
+            position: 0,
+        };
+        let mut path = Self::new();
+        let mut command: Option<u8> = None;
+        let mut current = Point::ZERO;
+        let mut start = Point::ZERO;
+        // The last control point of the previous curve, with its kind (`C` or `Q`).
+        let mut last_control: Option<(u8, Point)> = None;
+
+        while let Some(byte) = parser.peek() {
+            let name = if byte.is_ascii_alphabetic() {
+                parser.position += 1;
+                byte
+            } else if parser.at_number() {
+                // Repeated parameters repeat the command, and moves turn into lines.
+                match command {
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(command) if !command.eq_ignore_ascii_case(&b'z') => command,
+                    Some(_) => return Err(ParsePathError::UnexpectedCharacter(parser.position)),
+                    None => return Err(ParsePathError::MissingMoveTo),
+                }
+            } else {
+                return Err(ParsePathError::UnexpectedCharacter(parser.position));
+            };
+            if command.is_none() && !name.eq_ignore_ascii_case(&b'm') {
+                return Err(ParsePathError::MissingMoveTo);
+            }
+            command = Some(name);
+
+            let relative = name.is_ascii_lowercase();
+            let origin = if relative { current } else { Point::ZERO };
+            let absolute = |point: Point| point.offset(origin.x, origin.y);
+            let mut control = None;
+            match name.to_ascii_uppercase() {
+                b'M' => {
+                    current = absolute(parser.point()?);
+                    start = current;
+                    path.push(PathElement::MoveTo(current));
+                }
+                b'L' => {
+                    current = absolute(parser.point()?);
+                    path.push(PathElement::LineTo(current));
+                }
+                b'H' => {
+                    current.x = parser.coordinate()? + origin.x;
+                    path.push(PathElement::LineTo(current));
+                }
+                b'V' => {
+                    current.y = parser.coordinate()? + origin.y;
+                    path.push(PathElement::LineTo(current));
+                }
+                b'C' | b'S' => {
+                    let control1 = if name.eq_ignore_ascii_case(&b'C') {
+                        absolute(parser.point()?)
+                    } else {
+                        reflect(last_control, b'C', current)
+                    };
+                    let control2 = absolute(parser.point()?);
+                    current = absolute(parser.point()?);
+                    path.push(PathElement::CubicTo {
+                        control1,
+                        control2,
+                        to: current,
+                    });
+                    control = Some((b'C', control2));
+                }
+                b'Q' | b'T' => {
+                    let quad_control = if name.eq_ignore_ascii_case(&b'Q') {
+                        absolute(parser.point()?)
+                    } else {
+                        reflect(last_control, b'Q', current)
+                    };
+                    current = absolute(parser.point()?);
+                    path.push(PathElement::QuadTo {
+                        control: quad_control,
+                        to: current,
+                    });
+                    control = Some((b'Q', quad_control));
+                }
+                b'A' => {
+                    let radius_x = parser.coordinate()?;
+                    let radius_y = parser.coordinate()?;
+                    let rotation = parser.coordinate()?;
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    let to = absolute(parser.point()?);
+                    path = path.move_to_if_empty(current).arc_to(
+                        radius_x,
+                        radius_y,
+                        rotation * PI / 180.0,
+                        large_arc,
+                        sweep,
+                        to,
+                    );
+                    current = to;
+                }
+                b'Z' => {
+                    current = start;
+                    path.push(PathElement::Close);
+                }
+                _ => return Err(ParsePathError::UnexpectedCharacter(parser.position - 1)),
+            }
+            last_control = control;
+        }
+
+        Ok(path)
+    }
+
+    fn move_to_if_empty(self, point: Point) -> Self {
+        if self.current_point().is_some() {
+            self
+        } else {
+            self.move_to(point)
+        }
+    }
+
+    /// Formats the path as SVG path data with absolute commands.
+    pub fn to_svg(&self) -> alloc::string::String {
+        alloc::format!("{self}")
+    }
+}
+
+impl FromStr for Path {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_svg(s)
+    }
+}
+
+impl Display for Path {
+    /// Formats the path as SVG path data with absolute commands, which can be parsed back.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, element) in self.elements().iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            match element {
+                PathElement::MoveTo(point) => write!(f, "M{} {}", point.x, point.y)?,
+                PathElement::LineTo(point) => write!(f, "L{} {}", point.x, point.y)?,
+                PathElement::QuadTo { control, to } => {
+                    write!(f, "Q{} {} {} {}", control.x, control.y, to.x, to.y)?
+                }
+                PathElement::CubicTo {
+                    control1,
+                    control2,
+                    to,
+                } => write!(
+                    f,
+                    "C{} {} {} {} {} {}",
+                    control1.x, control1.y, control2.x, control2.y, to.x, to.y
+                )?,
+                PathElement::Close => f.write_str("Z")?,
+            }
+        }
+        Ok(())
+    }
+}
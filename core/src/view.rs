@@ -7,13 +7,16 @@
 //! - `IntoView`: A trait for converting values into views
 //! - `TupleViews`: A trait for working with collections of views
 //! - `ConfigurableView`: A trait for views that can be configured
-//! - `Modifier`: A type for modifying configurable views
+//! - `Modifier`: A middleware-style function modifying configurable views
+//! - `Modifiers`: The stack of modifiers applied to a view type
 //!
 //! These abstractions support a declarative and composable approach to UI building, allowing
 //! for flexible combinations of views and transformations.
 
+use core::fmt::Debug;
+
 use crate::{components::Metadata, AnyView, Environment};
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{rc::Rc, vec::Vec};
 
 /// View represents a part of the user interface.
 ///
@@ -145,18 +148,48 @@ pub trait ConfigurableView: View {
 /// `Modifier` provides a way to transform views with specific configurations,
 /// enabling a consistent approach to view customization. Modifiers can be
 /// reused across different instances of the same view type.
-pub struct Modifier<V: ConfigurableView>(Box<dyn Fn(Environment, V::Config) -> AnyView>);
+///
+/// Modifiers work like middleware: each one receives the configuration and a [`Next`]
+/// continuation running the remaining modifiers. A modifier may adjust the configuration
+/// before passing it on, wrap the resulting view, or skip the rest of the chain entirely.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Text and its configuration are defined by the `waterui-text` crate.
+/// use waterui::text::{Text, TextConfig};
+/// use waterui::{ComputeExt, core::view::{Modifier, Next}};
+///
+/// let uppercase = Modifier::<Text>::new(|_env, mut config: TextConfig, next: Next<Text>| {
+///     config.content = config.content.map(|content| content.to_uppercase().into()).computed();
+///     next.call(config)
+/// });
+/// ```
+pub struct Modifier<V: ConfigurableView>(Rc<ModifierFn<V>>);
+
+type ModifierFn<V> = dyn Fn(Environment, <V as ConfigurableView>::Config, Next<V>) -> AnyView;
+
+impl<V: ConfigurableView> Clone for Modifier<V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<V: ConfigurableView> Debug for Modifier<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(core::any::type_name::<Self>())
+    }
+}
 
 impl<V, V2, F> From<F> for Modifier<V>
 where
     V: ConfigurableView,
     V2: View,
-    F: Fn(Environment, V::Config) -> V2 + 'static,
+    F: Fn(Environment, V::Config, Next<V>) -> V2 + 'static,
 {
     fn from(value: F) -> Self {
-        Self(Box::new(move |mut env, config| {
-            env.remove::<Self>();
-            AnyView::new(Metadata::new(value(env.clone(), config), env))
+        Self(Rc::new(move |env, config, next| {
+            AnyView::new(value(env, config, next))
         }))
     }
 }
@@ -166,7 +199,8 @@ impl<V: ConfigurableView> Modifier<V> {
     ///
     /// # Arguments
     ///
-    /// * `f` - A function that takes an environment and a configuration, and returns a view.
+    /// * `f` - A function that takes an environment, a configuration and the continuation
+    ///   running the remaining modifiers, and returns a view.
     ///
     /// # Returns
     ///
@@ -175,7 +209,7 @@ impl<V: ConfigurableView> Modifier<V> {
     where
         V: ConfigurableView,
         V2: View,
-        F: Fn(Environment, V::Config) -> V2 + 'static,
+        F: Fn(Environment, V::Config, Next<V>) -> V2 + 'static,
     {
         Self::from(f)
     }
@@ -186,12 +220,167 @@ impl<V: ConfigurableView> Modifier<V> {
     ///
     /// * `env` - The environment context to use when applying the modification.
     /// * `config` - The configuration of the view to modify.
+    /// * `next` - The continuation running the remaining modifiers.
     ///
     /// # Returns
     ///
     /// An `AnyView` containing the modified view.
-    pub fn modify(&self, env: Environment, config: V::Config) -> AnyView {
-        (self.0)(env, config)
+    pub fn modify(&self, env: Environment, config: V::Config, next: Next<V>) -> AnyView {
+        (self.0)(env, config, next)
+    }
+}
+
+/// The stack of modifiers applied to views of type `V`, in installation order.
+///
+/// The environment holds one stack per view type. [`Environment::insert_modifier`]
+/// pushes onto it, so that several plugins can customize the same view.
+pub struct Modifiers<V: ConfigurableView>(Rc<[Modifier<V>]>);
+
+impl<V: ConfigurableView> Clone for Modifiers<V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<V: ConfigurableView> Default for Modifiers<V> {
+    fn default() -> Self {
+        Self(Rc::new([]))
+    }
+}
+
+impl<V: ConfigurableView> Debug for Modifiers<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl<V: ConfigurableView> From<Modifier<V>> for Modifiers<V> {
+    fn from(value: Modifier<V>) -> Self {
+        Self(Rc::new([value]))
+    }
+}
+
+impl<V: ConfigurableView> Modifiers<V> {
+    /// Creates an empty stack of modifiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a modifier, which runs after the modifiers already in the stack.
+    pub fn push(&mut self, modifier: Modifier<V>) {
+        self.0 = self.0.iter().cloned().chain([modifier]).collect();
+    }
+
+    /// Removes the modifiers for which `f` returns `false`.
+    pub fn retain(&mut self, mut f: impl FnMut(&Modifier<V>) -> bool) {
+        self.0 = self
+            .0
+            .iter()
+            .filter(|modifier| f(modifier))
+            .cloned()
+            .collect();
+    }
+
+    /// Returns `true` if the stack contains `modifier` itself, not merely an equivalent one.
+    pub fn contains(&self, modifier: &Modifier<V>) -> bool {
+        self.0.iter().any(|item| Rc::ptr_eq(&item.0, &modifier.0))
+    }
+
+    /// Returns the modifiers, in the order they run.
+    pub fn iter(&self) -> impl Iterator<Item = &Modifier<V>> {
+        self.0.iter()
+    }
+
+    /// Returns the number of modifiers in the stack.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the stack contains no modifiers.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Runs the modifiers on a view configuration.
+    ///
+    /// The modifiers and the view they produce see the environment without this stack,
+    /// so rebuilding a view of type `V` inside a modifier doesn't modify it again.
+    pub fn apply(&self, env: &Environment, config: V::Config) -> AnyView
+    where
+        V: From<V::Config>,
+    {
+        let mut env = env.clone();
+        env.remove::<Self>();
+        let view = Next {
+            env: env.clone(),
+            modifiers: self.0.clone(),
+            index: 0,
+        }
+        .call(config);
+        AnyView::new(Metadata::new(view, env))
+    }
+}
+
+/// The continuation passed to a [`Modifier`], running the modifiers after it.
+///
+/// Once every modifier has run, the view is built from the final configuration.
+pub struct Next<V: ConfigurableView> {
+    env: Environment,
+    modifiers: Rc<[Modifier<V>]>,
+    index: usize,
+}
+
+impl<V: ConfigurableView> Debug for Next<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &(self.modifiers.len() - self.index))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V: ConfigurableView + From<V::Config>> Next<V> {
+    /// Runs the remaining modifiers with the given configuration.
+    pub fn call(self, config: V::Config) -> AnyView {
+        match self.modifiers.get(self.index) {
+            Some(modifier) => modifier.modify(
+                self.env.clone(),
+                config,
+                Self {
+                    env: self.env.clone(),
+                    modifiers: self.modifiers.clone(),
+                    index: self.index + 1,
+                },
+            ),
+            None => AnyView::new(V::from(config)),
+        }
+    }
+}
+
+/// A configurable view with a modifier applied on top of those in the environment.
+///
+/// Created by `ConfigViewExt::modifier`.
+#[derive(Debug)]
+#[must_use]
+pub struct Modified<V: ConfigurableView> {
+    content: V,
+    modifier: Modifier<V>,
+}
+
+impl<V: ConfigurableView> Modified<V> {
+    /// Creates a view applying `modifier` after the modifiers of the environment.
+    pub fn new(content: V, modifier: impl Into<Modifier<V>>) -> Self {
+        Self {
+            content,
+            modifier: modifier.into(),
+        }
+    }
+}
+
+impl<V: ConfigurableView + From<V::Config>> View for Modified<V> {
+    fn body(self, env: &Environment) -> impl View {
+        let mut modifiers = env.get::<Modifiers<V>>().cloned().unwrap_or_default();
+        modifiers.push(self.modifier);
+        modifiers.apply(env, self.content.config())
     }
 }
 
@@ -222,3 +411,61 @@ impl<V: View> View for (V,) {
 impl View for ! {
     fn body(self, _env: &Environment) -> impl View {}
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use core::cell::RefCell;
+
+    use alloc::{rc::Rc, vec, vec::Vec};
+
+    use super::{ConfigurableView, Modifier, Modifiers, Next, View};
+    use crate::Environment;
+
+    pub(crate) type Log = Rc<RefCell<Vec<&'static str>>>;
+
+    pub(crate) struct Logged(Log);
+
+    impl View for Logged {
+        fn body(self, _env: &Environment) -> impl View {}
+    }
+
+    impl ConfigurableView for Logged {
+        type Config = Log;
+
+        fn config(self) -> Self::Config {
+            self.0
+        }
+    }
+
+    impl From<Log> for Logged {
+        fn from(value: Log) -> Self {
+            value.borrow_mut().push("view");
+            Self(value)
+        }
+    }
+
+    pub(crate) fn logging(name: &'static str) -> Modifier<Logged> {
+        Modifier::new(move |_env, config: Log, next: Next<Logged>| {
+            config.borrow_mut().push(name);
+            next.call(config)
+        })
+    }
+
+    #[test]
+    fn modifiers_run_in_order() {
+        let env = Environment::new()
+            .with_modifier(logging("first"))
+            .with_modifier(logging("second"));
+        let log = Log::default();
+        let _ = env
+            .get::<Modifiers<Logged>>()
+            .unwrap()
+            .apply(&env, log.clone());
+        assert_eq!(*log.borrow(), vec!["first", "second", "view"]);
+
+        let log = Log::default();
+        let skip = Modifier::<Logged>::new(|_env, _config: Log, _next: Next<Logged>| ());
+        let _ = Modifiers::from(skip).apply(&env, log.clone());
+        assert!(log.borrow().is_empty());
+    }
+}
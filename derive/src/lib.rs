@@ -1,8 +1,10 @@
 extern crate proc_macro;
+mod text;
+
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::{ToTokens, quote};
-use syn::{self, Ident, ItemFn, parse_macro_input};
+use quote::{ToTokens, format_ident, quote};
+use syn::{self, Data, DeriveInput, Fields, Ident, ItemFn, parse_macro_input, parse_quote};
 
 #[proc_macro_attribute]
 pub fn init(_attr: TokenStream, items: TokenStream) -> TokenStream {
@@ -64,3 +66,92 @@ pub fn main(_attr: TokenStream, items: TokenStream) -> TokenStream {
         items,
     )
 }
+
+/// Derives `Extractor` for a struct whose fields are all extractors.
+///
+/// Every field is extracted from the environment. If some of them fail, the error
+/// lists all the failures instead of stopping at the first one.
+#[proc_macro_derive(Extractor)]
+pub fn derive_extractor(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident.clone();
+
+    let fields = match &input.data {
+        Data::Struct(data) => data.fields.clone(),
+        _ => {
+            return syn::Error::new(
+                Span::call_site(),
+                "`Extractor` can only be derived for structs",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let where_clause = input.generics.make_where_clause();
+    for field in fields.iter() {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::waterui::core::extract::Extractor));
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let bindings: Vec<Ident> = (0..fields.len())
+        .map(|index| format_ident!("__field{}", index))
+        .collect();
+    let types = fields.iter().map(|field| &field.ty);
+    let construct = match &fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!(Self { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(Self(#(#bindings),*)),
+        Fields::Unit => quote!(Self),
+    };
+
+    // Every field is extracted before matching, so that all failures are reported together.
+    let body = if fields.is_empty() {
+        quote! {
+            let _ = env;
+            ::core::result::Result::Ok(#construct)
+        }
+    } else {
+        quote! {
+            #(
+                let #bindings = <#types as ::waterui::core::extract::Extractor>::extract(env);
+            )*
+            match (#(#bindings,)*) {
+                (#(::core::result::Result::Ok(#bindings),)*) => ::core::result::Result::Ok(#construct),
+                (#(#bindings,)*) => ::core::result::Result::Err(
+                    ::waterui::core::extract::combine_errors(
+                        ::core::any::type_name::<Self>(),
+                        [#(#bindings.err()),*].into_iter().flatten(),
+                    ),
+                ),
+            }
+        }
+    };
+
+    let expanded = quote! {
+        impl #impl_generics ::waterui::core::extract::Extractor for #name #ty_generics #where_clause {
+            fn extract(
+                env: &::waterui::core::Environment,
+            ) -> ::core::result::Result<Self, ::waterui::core::Error> {
+                #body
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Implements the `text!` macro of `waterui-text`, which forwards its path and input.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __text(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as text::Input);
+    text::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
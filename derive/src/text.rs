@@ -0,0 +1,413 @@
+//! The implementation of the `text!` macro of `waterui-text`.
+//!
+//! The macro receives the path of the text crate followed by its own input:
+//!
+//! ```text
+//! $crate; "Hello {name:bold}, you have {} new {kind}", count, kind = "messages"
+//! ```
+//!
+//! Arguments are bound once, reactive ones are zipped together, and the text is formatted
+//! again whenever one of them changes. Literal arguments are constants, captured by the
+//! formatting closure instead of being watched.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    Expr, ExprLit, ExprPath, Ident, LitStr, Token,
+    parse::{Parse, ParseStream},
+};
+
+/// The styles that can follow the format spec of an interpolation, such as `{name:bold}`.
+const STYLES: [(&str, &str); 5] = [
+    ("bold", "Bold"),
+    ("italic", "Italic"),
+    ("underline", "Underline"),
+    ("strikethrough", "Strikethrough"),
+    ("code", "Code"),
+];
+
+pub struct Input {
+    krate: TokenStream,
+    format: LitStr,
+    positional: Vec<Expr>,
+    named: Vec<(Ident, Expr)>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let krate = input.step(|cursor| {
+            let mut krate = TokenStream::new();
+            let mut rest = *cursor;
+            while let Some((token, next)) = rest.token_tree() {
+                if matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ';') {
+                    return Ok((krate, next));
+                }
+                krate.extend([token]);
+                rest = next;
+            }
+            Err(cursor.error("expected the path of the text crate"))
+        })?;
+
+        let format: LitStr = input.parse()?;
+        let mut positional = Vec::new();
+        let mut named: Vec<(Ident, Expr)> = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+                let name: Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                if named.iter().any(|(other, _)| *other == name) {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("duplicate argument named `{name}`"),
+                    ));
+                }
+                named.push((name, input.parse()?));
+            } else if let Some((name, _)) = named.first() {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "positional arguments cannot follow named arguments",
+                ));
+            } else {
+                positional.push(input.parse()?);
+            }
+        }
+
+        Ok(Self {
+            krate,
+            format,
+            positional,
+            named,
+        })
+    }
+}
+
+enum Segment {
+    Literal(String),
+    Argument {
+        index: usize,
+        spec: String,
+        styles: Vec<&'static str>,
+    },
+}
+
+struct Argument {
+    value: TokenStream,
+    constant: bool,
+    used: bool,
+    span: Span,
+}
+
+impl Argument {
+    fn new(expr: &Expr) -> Self {
+        let span = syn::spanned::Spanned::span(expr);
+        let (value, constant) = match expr {
+            Expr::Lit(ExprLit { .. }) => (quote!(#expr), true),
+            // Through auto-ref, bindings and references to them are both cloned to bindings.
+            Expr::Path(ExprPath { .. }) => (quote!((#expr).clone()), false),
+            _ => (quote!(#expr), false),
+        };
+        Self {
+            value,
+            constant,
+            used: false,
+            span,
+        }
+    }
+}
+
+pub fn expand(input: Input) -> syn::Result<TokenStream> {
+    let Input {
+        krate,
+        format,
+        positional,
+        named,
+    } = input;
+    let error = |message: String| syn::Error::new(format.span(), message);
+
+    let mut arguments: Vec<Argument> = positional.iter().map(Argument::new).collect();
+    let positional_count = arguments.len();
+    let mut names: Vec<String> = Vec::new();
+    for (name, expr) in &named {
+        arguments.push(Argument::new(expr));
+        names.push(name.to_string());
+    }
+
+    let source = format.value();
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut next_positional = 0;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '}' => return Err(error("unmatched `}` in format string".into())),
+            '{' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => inner.push(c),
+                        None => return Err(error("unmatched `{` in format string".into())),
+                    }
+                }
+                let (name, spec) = inner.split_once(':').unwrap_or((&inner, ""));
+                let name = name.trim();
+                let explicit = name.parse::<usize>().ok();
+                let index = if let Some(index) =
+                    explicit.or(name.is_empty().then_some(next_positional))
+                {
+                    if explicit.is_none() {
+                        next_positional += 1;
+                    }
+                    if index >= positional_count {
+                        return Err(error(format!(
+                            "invalid reference to positional argument {index} ({positional_count} given)"
+                        )));
+                    }
+                    index
+                } else if let Some(index) = names.iter().position(|other| other == name) {
+                    positional_count + index
+                } else {
+                    syn::parse_str::<Ident>(name)
+                        .map_err(|_| error(format!("invalid argument name `{name}`")))?;
+                    let ident = Ident::new(name, format.span());
+                    arguments.push(Argument::new(&syn::parse_quote!(#ident)));
+                    names.push(name.into());
+                    arguments.len() - 1
+                };
+
+                let (spec, styles) = split_styles(spec);
+                if let Some(style) = spec.split(':').find(|part| is_unknown_style(part)) {
+                    let known: Vec<&str> = STYLES.iter().map(|(name, _)| *name).collect();
+                    return Err(error(format!(
+                        "unknown style `{style}`, expected one of {}",
+                        known.join(", ")
+                    )));
+                }
+                if spec.contains(['$', '*']) {
+                    return Err(error(
+                        "`$` and `*` are not supported in the format specs of `text!`".into(),
+                    ));
+                }
+                arguments[index].used = true;
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(core::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Argument {
+                    index,
+                    spec,
+                    styles,
+                });
+            }
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    if let Some(unused) = arguments.iter().find(|argument| !argument.used) {
+        return Err(syn::Error::new(unused.span, "argument never used"));
+    }
+
+    let bindings: Vec<Ident> = (0..arguments.len())
+        .map(|index| format_ident!("__text_arg{}", index))
+        .collect();
+    let values = arguments.iter().map(|argument| &argument.value);
+    let reactive: Vec<&Ident> = arguments
+        .iter()
+        .zip(&bindings)
+        .filter(|(argument, _)| !argument.constant)
+        .map(|(_, binding)| binding)
+        .collect();
+
+    let styled = segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Argument { styles, .. } if !styles.is_empty()));
+    let private = quote!(#krate::__private);
+    let placeholder = |index: usize, spec: &str| {
+        let binding = &bindings[index];
+        let spec = if spec.is_empty() {
+            String::new()
+        } else {
+            format!(":{spec}")
+        };
+        (format!("{{{binding}{spec}}}"), binding)
+    };
+
+    let content = if styled {
+        let pushes = segments.iter().map(|segment| match segment {
+            Segment::Literal(text) => quote!(string.push_str(#text);),
+            Segment::Argument {
+                index,
+                spec,
+                styles,
+            } => {
+                let (format, binding) = placeholder(*index, spec);
+                let styles = styles.iter().map(|style| format_ident!("{}", style));
+                quote! {
+                    string.push(
+                        #private::format!(#format, #binding = #binding),
+                        [#(#krate::attributed::Attribute::#styles),*],
+                    );
+                }
+            }
+        });
+        quote! {{
+            let mut string = #krate::attributed::AttributedStr::new();
+            #(#pushes)*
+            string
+        }}
+    } else {
+        let mut format = String::new();
+        let mut used = Vec::new();
+        for segment in &segments {
+            match segment {
+                Segment::Literal(text) => {
+                    format.push_str(&text.replace('{', "{{").replace('}', "}}"))
+                }
+                Segment::Argument { index, spec, .. } => {
+                    let (placeholder, binding) = placeholder(*index, spec);
+                    format.push_str(&placeholder);
+                    if !used.contains(&binding) {
+                        used.push(binding);
+                    }
+                }
+            }
+        }
+        quote!(#private::Str::from(#private::format!(#format, #(#used = #used),*)))
+    };
+
+    let output = match reactive.split_last() {
+        // Nothing to watch, the text is formatted once.
+        None => content,
+        Some((last, rest)) => {
+            let (mut zipped, mut pattern) = (quote!(#last), quote!(#last));
+            for binding in rest.iter().rev() {
+                zipped = quote!(#private::ComputeExt::zip(#binding, #zipped));
+                pattern = quote!((#binding, #pattern));
+            }
+            quote! {
+                #private::ComputeExt::computed(
+                    #private::ComputeExt::map(#zipped, move |#pattern| #content),
+                )
+            }
+        }
+    };
+    let view = if styled {
+        quote!(#krate::attributed::StyledText::new)
+    } else {
+        quote!(#krate::Text::new)
+    };
+
+    Ok(quote! {{
+        #(let #bindings = #values;)*
+        #view(#output)
+    }})
+}
+
+/// Splits the styles off the end of a format spec, as in `>8:bold+italic`.
+fn split_styles(spec: &str) -> (String, Vec<&'static str>) {
+    let mut parts: Vec<&str> = spec.split(':').collect();
+    let mut styles = Vec::new();
+    while let Some(part) = parts.last() {
+        let words: Option<Vec<&'static str>> = part
+            .split('+')
+            .map(|word| {
+                STYLES
+                    .iter()
+                    .find(|(name, _)| *name == word.trim())
+                    .map(|(_, variant)| *variant)
+            })
+            .collect();
+        let Some(words) = words else { break };
+        styles.splice(0..0, words);
+        parts.pop();
+    }
+    (parts.join(":"), styles)
+}
+
+/// Returns whether a part of a format spec is made of words, but isn't a format trait such
+/// as `x`, so it can only be a misspelt style.
+fn is_unknown_style(part: &str) -> bool {
+    part.len() > 1 && part.chars().all(|c| c.is_ascii_alphabetic() || c == '+')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, expand};
+
+    fn expanded(input: &str) -> syn::Result<String> {
+        expand(syn::parse_str::<Input>(input)?).map(|tokens| tokens.to_string())
+    }
+
+    fn error(input: &str) -> String {
+        expanded(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn constants_are_not_watched() {
+        let constant = expanded(r#"krate; "{} and {name}", 1, name = "two""#).unwrap();
+        assert!(!constant.contains("ComputeExt"));
+
+        let reactive = expanded(r#"krate; "{} of {}", count, 10"#).unwrap();
+        assert!(reactive.contains("ComputeExt :: map (__text_arg0 , move | __text_arg0 |"));
+    }
+
+    #[test]
+    fn styles_create_an_attributed_string() {
+        let plain = expanded(r#"krate; "{count:>3}""#).unwrap();
+        assert!(plain.contains("krate :: Text :: new"));
+
+        let styled = expanded(r#"krate; "{count:>3:bold+italic}""#).unwrap();
+        assert!(styled.contains("krate :: attributed :: StyledText :: new"));
+        assert!(styled.contains(
+            "[krate :: attributed :: Attribute :: Bold , krate :: attributed :: Attribute :: Italic]"
+        ));
+        assert!(styled.contains(r#""{__text_arg0:>3}""#));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert_eq!(
+            error(r#"krate; "{a}", a = 1, a = 2"#),
+            "duplicate argument named `a`"
+        );
+        assert_eq!(
+            error(r#"krate; "{a} {}", a = 1, 2"#),
+            "positional arguments cannot follow named arguments"
+        );
+        assert_eq!(
+            error(r#"krate; "{1}", 0"#),
+            "invalid reference to positional argument 1 (1 given)"
+        );
+        assert_eq!(error(r#"krate; "{}", 1, 2"#), "argument never used");
+        assert_eq!(error(r#"krate; "{a-b}""#), "invalid argument name `a-b`");
+        assert!(error(r#"krate; "{:1$}", 1"#).contains("not supported"));
+    }
+
+    #[test]
+    fn invalid_styles_and_braces() {
+        assert_eq!(
+            error(r#"krate; "{name:blink}""#),
+            "unknown style `blink`, expected one of bold, italic, underline, strikethrough, code"
+        );
+        assert_eq!(
+            error(r#"krate; "{name:>3:bold+blink}""#),
+            "unknown style `bold+blink`, expected one of bold, italic, underline, strikethrough, code"
+        );
+        assert_eq!(error(r#"krate; "a } b""#), "unmatched `}` in format string");
+        assert_eq!(error(r#"krate; "{name""#), "unmatched `{` in format string");
+        assert!(expanded(r#"krate; "{name:x?}""#).is_ok());
+    }
+}
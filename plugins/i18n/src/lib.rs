@@ -2,20 +2,40 @@
 #![no_std]
 #![forbid(unsafe_code)]
 
+pub mod message;
 #[cfg(feature = "std")]
 mod std_on;
 extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 
+pub use message::{Args, Message, ParseError, Value};
 use waterui_core::{
-    ComputeExt, Environment, Str, extract::Extractor, plugin::Plugin, view::Modifier,
+    Compute, ComputeExt, Computed, Environment, Str, View, env,
+    extract::Extractor,
+    plugin::{ModifierId, Plugin},
+    view::Modifier,
 };
 use waterui_text::{Text, locale::Locale};
 #[derive(Debug, Default)]
 pub struct I18n {
-    map: BTreeMap<Str, BTreeMap<Str, Str>>,
+    map: BTreeMap<Str, BTreeMap<Str, Translation>>,
+}
+
+/// A translation, with its message parsed once, when it's inserted.
+#[derive(Debug)]
+struct Translation {
+    source: Str,
+    /// The parsed message, or `None` if the source isn't a valid [`Message`].
+    message: Option<Message>,
+}
+
+impl Translation {
+    fn new(source: Str) -> Self {
+        let message = Message::parse(&source).ok();
+        Self { source, message }
+    }
 }
 
 impl I18n {
@@ -29,36 +49,258 @@ impl I18n {
         self.map
             .entry(locale.into())
             .or_default()
-            .insert(key.into(), value.into());
+            .insert(key.into(), Translation::new(value.into()));
     }
 
+    /// Returns the translation of `key` in `locale`, or the key if there is none.
     pub fn get(&self, locale: &str, key: impl Into<Str>) -> Str {
         let key = key.into();
         self.try_get(locale, &key).cloned().unwrap_or(key)
     }
 
+    /// Returns the translation of `key` in `locale`, falling back to the less specific
+    /// locales, as from `zh-Hant-TW` to `zh-Hant` and `zh`.
     pub fn try_get(&self, locale: &str, key: &str) -> Option<&Str> {
-        self.map.get(locale).and_then(|map| map.get(key))
+        self.translation(locale, key)
+            .map(|translation| &translation.source)
+    }
+
+    fn translation(&self, locale: &str, key: &str) -> Option<&Translation> {
+        Locale(Str::from(alloc::string::String::from(locale)))
+            .fallbacks()
+            .find_map(|locale| self.map.get(locale).and_then(|map| map.get(key)))
     }
+
+    /// Formats the message of `key` in `locale` with `args`, or returns the key if there
+    /// is no translation.
+    ///
+    /// A translation that isn't a valid [`Message`] is returned as is.
+    pub fn format(&self, locale: &Locale, key: &str, args: &Args) -> Str {
+        let Some(translation) = self.translation(&locale.0, key) else {
+            return Str::from(alloc::string::String::from(key));
+        };
+        match &translation.message {
+            Some(message) => message.format(locale, args).into(),
+            None => translation.source.clone(),
+        }
+    }
+}
+
+/// Marks the environment of texts that are already translated, which the [`Text`]
+/// modifier of [`I18n`] leaves as they are.
+#[derive(Debug, Clone, Copy)]
+struct Translated;
+
+/// A text showing the translation of a message, formatted again whenever the locale or
+/// one of its arguments changes.
+///
+/// The formatted text isn't translated again by the [`Text`] modifier of [`I18n`].
+///
+/// Usually created with the [`t!`] macro.
+#[derive(Debug)]
+pub struct Localized {
+    key: Str,
+    args: Computed<Args>,
+}
+
+impl Localized {
+    pub fn new(key: impl Into<Str>) -> Self {
+        Self {
+            key: key.into(),
+            args: Computed::constant(Args::new()),
+        }
+    }
+
+    /// Sets the argument `name` of the message to `value`, watching it for changes.
+    pub fn arg<C>(mut self, name: impl Into<Str>, value: C) -> Self
+    where
+        C: Compute + 'static,
+        C::Output: Into<Value>,
+    {
+        let name = name.into();
+        self.args = self
+            .args
+            .zip(value)
+            .map(move |(mut args, value)| {
+                args.insert(name.clone(), value.into());
+                args
+            })
+            .computed();
+        self
+    }
+}
+
+impl View for Localized {
+    fn body(self, env: &Environment) -> impl View {
+        let locale = env
+            .get_computed::<Locale>()
+            .unwrap_or_else(|| Computed::constant(Locale(Str::from("en"))));
+        let env = env.clone();
+        let key = self.key;
+        let text = Text::new(
+            locale
+                .zip(self.args)
+                .map(move |(locale, args)| match env.get::<I18n>() {
+                    Some(i18n) => i18n.format(&locale, &key, &args),
+                    None => key.clone(),
+                })
+                .computed(),
+        );
+        env::With::new(text, Translated)
+    }
+}
+
+/// Creates a [`Localized`] text from a message key and named arguments.
+///
+/// Each argument is any `Compute`, such as a binding, whose output converts into a
+/// [`Value`]; the text is formatted again when one of them changes.
+///
+/// ```
+/// # use waterui_core::Binding;
+/// # use waterui_i18n::t;
+/// let count = Binding::int(3);
+/// let items = t!("cart.items", count = count.clone(), name = "Ada");
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::Localized::new($key)$(.arg(stringify!($name), $value))*
+    };
 }
 
 impl Plugin for I18n {
+    fn modifiers(&self) -> Vec<ModifierId> {
+        vec![ModifierId::of::<Text>()]
+    }
+
     fn install(self, env: &mut Environment) {
         env.insert(self);
 
-        env.insert(Modifier::<Text>::new(|env, mut config| {
-            let Locale(locale) = Locale::extract(&env).unwrap();
+        env.insert_modifier(Modifier::<Text>::new(|env, mut config, next| {
+            if env.get::<Translated>().is_some() {
+                return next.call(config);
+            }
+            let locale = Computed::<Locale>::extract(&env).unwrap();
             config.content = config
                 .content
-                .map(move |content| {
+                .zip(locale)
+                .map(move |(content, locale)| {
                     if let Some(i18n) = env.get::<I18n>() {
-                        i18n.get(&locale, content)
+                        i18n.format(&locale, &content, &Args::new())
                     } else {
                         content
                     }
                 })
                 .computed();
-            Text::from(config)
-        }))
+            next.call(config)
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_core::{
+        AnyView, Binding, Compute, Computed, Environment, Str, View, components::Metadata,
+        view::Modifiers,
+    };
+    use waterui_text::{Text, locale::Locale, text};
+
+    use super::I18n;
+
+    fn i18n() -> I18n {
+        let mut i18n = I18n::new();
+        i18n.insert("en", "greeting", "Hello");
+        i18n.insert("en", "Hello", "Hi there");
+        i18n.insert("fr", "Hello", "Bonjour");
+        i18n.insert(
+            "en",
+            "cart",
+            "{count, plural, one {# item} other {# items}}",
+        );
+        i18n.insert(
+            "fr",
+            "cart",
+            "{count, plural, one {# article} other {# articles}}",
+        );
+        i18n
+    }
+
+    fn env(locale: &Binding<Locale>) -> Environment {
+        let mut env = Environment::new();
+        env.insert_computed::<Locale>(locale.clone());
+        env.install(i18n())
+    }
+
+    /// Builds `view` until the text shown by the renderer, returning its content.
+    fn content(view: impl View, env: &Environment) -> Computed<Str> {
+        let (mut view, mut env) = (AnyView::new(view), env.clone());
+        loop {
+            view = match view.downcast::<Metadata<Environment>>() {
+                Ok(metadata) => {
+                    env = metadata.value;
+                    metadata.content
+                }
+                Err(view) if env.get::<Modifiers<Text>>().is_some() => {
+                    AnyView::new(view.body(&env))
+                }
+                Err(view) => match view.downcast::<Text>() {
+                    Ok(text) => return text.content(),
+                    Err(view) => AnyView::new(view.body(&env)),
+                },
+            };
+        }
+    }
+
+    #[test]
+    fn translations_fall_back_to_less_specific_locales() {
+        let mut i18n = I18n::new();
+        i18n.insert("zh-Hant", "title", "標題");
+        i18n.insert("zh", "title", "标题");
+        i18n.insert("zh", "save", "保存");
+
+        assert_eq!(
+            i18n.try_get("zh-Hant-TW", "title").map(|s| &**s),
+            Some("標題")
+        );
+        assert_eq!(
+            i18n.try_get("zh-Hant-TW", "save").map(|s| &**s),
+            Some("保存")
+        );
+        assert_eq!(i18n.try_get("zh-Hant-TW", "open"), None);
+        assert_eq!(&*i18n.get("zh-Hant-TW", "open"), "open");
+        assert_eq!(i18n.try_get("fr", "title"), None);
+    }
+
+    #[test]
+    fn localized_texts_follow_their_arguments_and_the_locale() {
+        let locale = Binding::container(Locale(Str::from("en")));
+        let count = Binding::int(1);
+        let cart = content(t!("cart", count = count.clone()), &env(&locale));
+
+        assert_eq!(&*cart.compute(), "1 item");
+        count.set(3);
+        assert_eq!(&*cart.compute(), "3 items");
+        locale.set(Locale(Str::from("fr-CA")));
+        assert_eq!(&*cart.compute(), "3 articles");
+    }
+
+    #[test]
+    fn localized_texts_are_not_translated_again() {
+        let locale = Binding::container(Locale(Str::from("en")));
+        let greeting = content(t!("greeting"), &env(&locale));
+        assert_eq!(&*greeting.compute(), "Hello");
+    }
+
+    #[test]
+    fn texts_are_translated_by_the_modifier() {
+        let locale = Binding::container(Locale(Str::from("en")));
+        let env = env(&locale);
+        let hello = content(text("Hello"), &env);
+        let untranslated = content(text("Goodbye"), &env);
+
+        assert_eq!(&*hello.compute(), "Hi there");
+        assert_eq!(&*untranslated.compute(), "Goodbye");
+        locale.set(Locale(Str::from("fr")));
+        assert_eq!(&*hello.compute(), "Bonjour");
     }
 }
@@ -0,0 +1,587 @@
+//! Messages in the ICU MessageFormat syntax.
+//!
+//! A message interpolates named arguments, and chooses between variants with plural and
+//! select arguments:
+//!
+//! ```text
+//! {name} added {count, plural, =0 {no items} one {# item} other {# items}} to {gender, select, female {her} male {his} other {their}} cart.
+//! ```
+//!
+//! Numbers are formatted for the locale, also as `{n, number, integer}` or
+//! `{n, number, percent}`, and `#` is the number of a plural variant. Plural variants are
+//! chosen by exact values (`=0`) or by the plural category of the locale, with an optional
+//! `offset:` subtracted first. Apostrophes quote special characters, as in `'{'`, and `''`
+//! is an apostrophe.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+
+use waterui_core::Str;
+use waterui_text::locale::{Formatter, Locale, NumberFormatter, PercentFormatter, PluralCategory};
+
+/// The arguments of a message, by name.
+pub type Args = BTreeMap<Str, Value>;
+
+/// The value of a message argument.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Str(Str),
+}
+
+macro_rules! impl_number_value {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Self::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+impl From<Str> for Value {
+    fn from(value: Str) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<&'static str> for Value {
+    fn from(value: &'static str) -> Self {
+        Self::Str(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::Str(value.into())
+    }
+}
+
+/// A parsed message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Text(String),
+    Argument {
+        name: String,
+        style: NumberStyle,
+    },
+    /// The number of the enclosing plural variant.
+    Pound,
+    Plural {
+        name: String,
+        offset: f64,
+        variants: Vec<(Selector, Message)>,
+    },
+    Select {
+        name: String,
+        variants: Vec<(String, Message)>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumberStyle {
+    Default,
+    Integer,
+    Percent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Exact(f64),
+    Category(PluralCategory),
+}
+
+/// An error in the syntax of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte offset of the error in the message.
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Message {
+    /// Parses `source` as a message.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            source,
+            position: 0,
+        };
+        let message = parser.message(false)?;
+        if parser.position < source.len() {
+            return Err(parser.error("unmatched `}`"));
+        }
+        Ok(message)
+    }
+
+    /// Formats the message in `locale` with `args`.
+    ///
+    /// Missing arguments are left as `{name}`, and plural and select arguments without
+    /// a value use their `other` variant.
+    pub fn format(&self, locale: &Locale, args: &Args) -> String {
+        let mut output = String::new();
+        self.write(&mut output, locale, args, None);
+        output
+    }
+
+    fn write(&self, output: &mut String, locale: &Locale, args: &Args, pound: Option<f64>) {
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => output.push_str(text),
+                Part::Argument { name, style } => match args.get(name.as_str()) {
+                    Some(Value::Number(number)) => {
+                        output.push_str(&format_number(locale, *number, *style));
+                    }
+                    Some(Value::Str(text)) => output.push_str(text),
+                    None => {
+                        output.push('{');
+                        output.push_str(name);
+                        output.push('}');
+                    }
+                },
+                Part::Pound => match pound {
+                    Some(number) => {
+                        output.push_str(&format_number(locale, number, NumberStyle::Default));
+                    }
+                    None => output.push('#'),
+                },
+                Part::Plural {
+                    name,
+                    offset,
+                    variants,
+                } => {
+                    let number = match args.get(name.as_str()) {
+                        Some(Value::Number(number)) => Some(*number),
+                        Some(Value::Str(text)) => text.parse().ok(),
+                        None => None,
+                    };
+                    let category = number.map(|number| locale.plural_category(number - offset));
+                    let variant = variants
+                        .iter()
+                        .find(|(selector, _)| matches!(selector, Selector::Exact(exact) if Some(*exact) == number))
+                        .or_else(|| {
+                            variants.iter().find(|(selector, _)| {
+                                matches!(selector, Selector::Category(c) if Some(*c) == category)
+                            })
+                        })
+                        .or_else(|| {
+                            variants.iter().find(|(selector, _)| {
+                                *selector == Selector::Category(PluralCategory::Other)
+                            })
+                        });
+                    if let Some((_, message)) = variant {
+                        let pound = number.map(|number| number - offset);
+                        message.write(output, locale, args, pound);
+                    }
+                }
+                Part::Select { name, variants } => {
+                    let selected = match args.get(name.as_str()) {
+                        Some(Value::Str(text)) => Some(text.as_str()),
+                        _ => None,
+                    };
+                    let variant = variants
+                        .iter()
+                        .find(|(key, _)| Some(key.as_str()) == selected)
+                        .or_else(|| variants.iter().find(|(key, _)| key == "other"));
+                    if let Some((_, message)) = variant {
+                        message.write(output, locale, args, pound);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_number(locale: &Locale, number: f64, style: NumberStyle) -> Str {
+    match style {
+        NumberStyle::Default => NumberFormatter::new(locale).format(&number),
+        NumberStyle::Integer => NumberFormatter::new(locale)
+            .fraction_digits(0, 0)
+            .format(&number),
+        NumberStyle::Percent => PercentFormatter::new(locale).format(&number),
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError {
+            position: self.position,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    /// Reads a name, a keyword or a selector.
+    fn word(&mut self) -> Result<&str, ParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '=' | ':'))
+        {
+            self.bump();
+        }
+        if start == self.position {
+            return Err(self.error("expected a name"));
+        }
+        Ok(&self.source[start..self.position])
+    }
+
+    /// Reads quoted text, up to the next single apostrophe.
+    fn quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.bump() {
+            if c == '\'' {
+                if self.peek() != Some('\'') {
+                    break;
+                }
+                self.bump();
+            }
+            text.push(c);
+        }
+    }
+
+    /// Parses a message, up to the end of the source or an unmatched `}`.
+    fn message(&mut self, in_plural: bool) -> Result<Message, ParseError> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\'' => {
+                    self.bump();
+                    match self.peek() {
+                        Some('\'') => {
+                            self.bump();
+                            text.push('\'');
+                        }
+                        Some('{' | '}') => self.quoted(&mut text),
+                        Some('#') if in_plural => self.quoted(&mut text),
+                        _ => text.push('\''),
+                    }
+                }
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(self.argument(in_plural)?);
+                }
+                '}' => break,
+                '#' if in_plural => {
+                    self.bump();
+                    if !text.is_empty() {
+                        parts.push(Part::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Pound);
+                }
+                c => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(Message { parts })
+    }
+
+    fn argument(&mut self, in_plural: bool) -> Result<Part, ParseError> {
+        self.bump();
+        let name = String::from(self.word()?);
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Part::Argument {
+                name,
+                style: NumberStyle::Default,
+            });
+        }
+        self.expect(',', "expected `,` or `}` after the argument name")?;
+
+        let part = match self.word()? {
+            "number" => {
+                self.skip_whitespace();
+                let style = if self.peek() == Some(',') {
+                    self.bump();
+                    match self.word()? {
+                        "integer" => NumberStyle::Integer,
+                        "percent" => NumberStyle::Percent,
+                        _ => return Err(self.error("unsupported number style")),
+                    }
+                } else {
+                    NumberStyle::Default
+                };
+                Part::Argument { name, style }
+            }
+            "plural" => {
+                self.expect(',', "expected `,` before the plural variants")?;
+                let mut offset = 0.0;
+                let mut variants = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some('}') => break,
+                        None => return Err(self.error("unmatched `{`")),
+                        Some(_) => {}
+                    }
+                    let selector = self.word()?;
+                    let selector = if let Some(value) = selector.strip_prefix("offset:") {
+                        offset = value
+                            .parse()
+                            .map_err(|_| self.error("invalid plural offset"))?;
+                        continue;
+                    } else if let Some(value) = selector.strip_prefix('=') {
+                        Selector::Exact(
+                            value
+                                .parse()
+                                .map_err(|_| self.error("invalid exact plural value"))?,
+                        )
+                    } else {
+                        Selector::Category(
+                            PluralCategory::from_name(selector)
+                                .ok_or_else(|| self.error("unknown plural category"))?,
+                        )
+                    };
+                    variants.push((selector, self.variant(true)?));
+                }
+                if !variants
+                    .iter()
+                    .any(|(selector, _)| *selector == Selector::Category(PluralCategory::Other))
+                {
+                    return Err(self.error("plural arguments need an `other` variant"));
+                }
+                Part::Plural {
+                    name,
+                    offset,
+                    variants,
+                }
+            }
+            "select" => {
+                self.expect(',', "expected `,` before the select variants")?;
+                let mut variants = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some('}') => break,
+                        None => return Err(self.error("unmatched `{`")),
+                        Some(_) => {}
+                    }
+                    let key = String::from(self.word()?);
+                    variants.push((key, self.variant(in_plural)?));
+                }
+                if !variants.iter().any(|(key, _)| key == "other") {
+                    return Err(self.error("select arguments need an `other` variant"));
+                }
+                Part::Select { name, variants }
+            }
+            _ => return Err(self.error("unsupported argument type")),
+        };
+        self.expect('}', "expected `}` at the end of the argument")?;
+        Ok(part)
+    }
+
+    /// Parses a variant of a plural or select argument, in braces.
+    fn variant(&mut self, in_plural: bool) -> Result<Message, ParseError> {
+        self.expect('{', "expected `{` before the variant")?;
+        let message = self.message(in_plural)?;
+        if self.peek() != Some('}') {
+            return Err(self.error("unmatched `{`"));
+        }
+        self.bump();
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use waterui_core::Str;
+    use waterui_text::locale::Locale;
+
+    use super::{Args, Message, ParseError, Value};
+
+    fn format(locale: &'static str, source: &str, args: &[(&'static str, Value)]) -> String {
+        let args: Args = args
+            .iter()
+            .map(|(name, value)| (Str::from(*name), value.clone()))
+            .collect();
+        Message::parse(source)
+            .unwrap()
+            .format(&Locale(locale.into()), &args)
+    }
+
+    fn error(source: &str) -> (usize, &'static str) {
+        let ParseError { position, message } = Message::parse(source).unwrap_err();
+        (position, message)
+    }
+
+    #[test]
+    fn arguments_and_number_styles() {
+        let args = [("name", "Ada".into()), ("n", 1234.6.into())];
+        assert_eq!(format("en", "{name}: {n}", &args), "Ada: 1,234.6");
+        assert_eq!(format("de", "{ n , number, integer }", &args), "1.235");
+        assert_eq!(
+            format("en", "{p, number, percent}", &[("p", 0.25.into())]),
+            "25%"
+        );
+        assert_eq!(format("en", "{missing} {name}", &args), "{missing} Ada");
+    }
+
+    #[test]
+    fn plurals() {
+        let source = "{n, plural, =0 {no items} one {# item} other {# items}}";
+        assert_eq!(format("en", source, &[("n", 0.into())]), "no items");
+        assert_eq!(format("en", source, &[("n", 1.into())]), "1 item");
+        assert_eq!(format("en", source, &[("n", 1500.into())]), "1,500 items");
+        assert_eq!(format("en", source, &[("n", "2".into())]), "2 items");
+        assert_eq!(
+            format("fr", source, &[("n", 1500.into())]),
+            "1\u{202f}500 items"
+        );
+        // Without a number, the `other` variant is used and `#` is kept.
+        assert_eq!(format("en", source, &[]), "# items");
+
+        let russian = "{n, plural, one {# файл} few {# файла} many {# файлов} other {# файла}}";
+        assert_eq!(format("ru", russian, &[("n", 21.into())]), "21 файл");
+        assert_eq!(format("ru", russian, &[("n", 3.into())]), "3 файла");
+        assert_eq!(format("ru", russian, &[("n", 11.into())]), "11 файлов");
+    }
+
+    #[test]
+    fn plural_offsets() {
+        let source = "{n, plural, offset:1 =0 {nobody} =1 {{name}} one {{name} and # other} \
+                      other {{name} and # others}}";
+        let with = |n: i32| format("en", source, &[("n", n.into()), ("name", "Ada".into())]);
+        assert_eq!(with(0), "nobody");
+        // Exact values are matched before the offset is subtracted.
+        assert_eq!(with(1), "Ada");
+        assert_eq!(with(2), "Ada and 1 other");
+        assert_eq!(with(4), "Ada and 3 others");
+    }
+
+    #[test]
+    fn selects_and_nesting() {
+        let source = "{gender, select, female {her} male {his} other {their}} \
+                      {n, plural, other {{gender, select, female {# for her} other {#}}}}";
+        let with = |gender: &'static str| {
+            format("en", source, &[("gender", gender.into()), ("n", 2.into())])
+        };
+        assert_eq!(with("female"), "her 2 for her");
+        assert_eq!(with("male"), "his 2");
+        assert_eq!(with("unknown"), "their 2");
+    }
+
+    #[test]
+    fn pounds_outside_plurals_are_text() {
+        assert_eq!(format("en", "# {n}", &[("n", 5.into())]), "# 5");
+    }
+
+    #[test]
+    fn apostrophes() {
+        assert_eq!(
+            format("en", "It''s {name}", &[("name", "Ada".into())]),
+            "It's Ada"
+        );
+        assert_eq!(format("en", "don't", &[]), "don't");
+        assert_eq!(format("en", "'{name}' is quoted", &[]), "{name} is quoted");
+        assert_eq!(format("en", "'{it''s}'", &[]), "{it's}");
+        assert_eq!(format("en", "'#' stays", &[]), "'#' stays");
+        assert_eq!(
+            format(
+                "en",
+                "{n, plural, other {'#'# and '{'}}",
+                &[("n", 3.into())]
+            ),
+            "#3 and {"
+        );
+        // An unclosed quote runs to the end of the message.
+        assert_eq!(format("en", "'{unclosed", &[]), "{unclosed");
+    }
+
+    #[test]
+    fn malformed_messages() {
+        assert_eq!(error("oops }"), (5, "unmatched `}`"));
+        assert_eq!(
+            error("{a"),
+            (2, "expected `,` or `}` after the argument name")
+        );
+        assert_eq!(error("{}"), (1, "expected a name"));
+        assert_eq!(error("{a, date}"), (8, "unsupported argument type"));
+        assert_eq!(
+            error("{a, number, currency}"),
+            (20, "unsupported number style")
+        );
+        assert_eq!(
+            error("{a, plural, one {x}}"),
+            (19, "plural arguments need an `other` variant")
+        );
+        assert_eq!(
+            error("{a, select, x {y}}"),
+            (17, "select arguments need an `other` variant")
+        );
+        assert_eq!(
+            error("{a, plural, several {x} other {y}}"),
+            (19, "unknown plural category")
+        );
+        assert_eq!(
+            error("{a, plural, offset:x other {y}}"),
+            (20, "invalid plural offset")
+        );
+        assert_eq!(
+            error("{a, plural, =x {y} other {z}}"),
+            (14, "invalid exact plural value")
+        );
+        assert_eq!(error("{a, plural, other {x"), (20, "unmatched `{`"));
+        assert_eq!(error("{a, plural, other {x}"), (21, "unmatched `{`"));
+        assert_eq!(
+            error("{a, plural other {x}}"),
+            (11, "expected `,` before the plural variants")
+        );
+    }
+}
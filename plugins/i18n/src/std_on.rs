@@ -24,7 +24,7 @@ pub enum Error {
 impl I18n {
     pub async fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
         let mut dir = read_dir(path).await?;
-        let mut i18n: BTreeMap<Str, BTreeMap<Str, Str>> = BTreeMap::new();
+        let mut i18n = I18n::new();
         while let Some(file) = dir.next().await {
             let file = file?;
             let path = file.path();
@@ -33,18 +33,25 @@ impl I18n {
                     let buf = read_to_string(&path).await?;
                     let map: BTreeMap<Str, Str> = from_str(&buf)?;
                     if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
-                        i18n.insert(Str::from(name.to_string()), map);
+                        let locale = Str::from(name.to_string());
+                        for (key, value) in map {
+                            i18n.insert(locale.clone(), key, value);
+                        }
                     }
                 }
             }
         }
-        Ok(I18n { map: i18n })
+        Ok(i18n)
     }
 
     pub async fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), Error> {
         let path = path.as_ref();
         for (locale, map) in self.map.iter() {
             let path = path.join(locale.deref()).with_extension("toml");
+            let map: BTreeMap<&Str, &Str> = map
+                .iter()
+                .map(|(key, translation)| (key, &translation.source))
+                .collect();
             write(path, to_string_pretty(&map)?).await?;
         }
         Ok(())
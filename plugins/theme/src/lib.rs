@@ -0,0 +1,234 @@
+#![doc=include_str!("../README.md")]
+#![no_std]
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "std")]
+mod std_on;
+#[cfg(feature = "std")]
+pub use std_on::Error;
+extern crate alloc;
+
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use waterui_core::{
+    Color, Environment,
+    color::{ColorScheme, Palette, Palettes, SemanticColor},
+    plugin::Plugin,
+};
+use waterui_text::font::{Font, FontWeight, TextStyle};
+
+/// The design tokens of an application.
+///
+/// Installing a theme makes its palettes available to semantic colors, and the theme
+/// itself available to views through `Use<Theme>`.
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    /// The semantic colors, for the light and dark color schemes.
+    pub colors: Palettes,
+    /// The typography scale.
+    pub typography: Typography,
+    /// The spacing tokens.
+    pub spacing: Spacing,
+    /// The corner radius tokens.
+    pub radius: Radius,
+}
+
+impl Theme {
+    /// Creates a theme with the default tokens.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the palette of the given color scheme.
+    pub const fn palette(&self, scheme: ColorScheme) -> &Palette {
+        self.colors.get(scheme)
+    }
+
+    /// Returns the concrete color of a role in the given color scheme.
+    pub const fn color(&self, color: SemanticColor, scheme: ColorScheme) -> &Color {
+        self.palette(scheme).get(color)
+    }
+}
+
+impl Plugin for Theme {
+    fn install(self, env: &mut Environment) {
+        env.insert(self.colors.clone());
+        env.insert(self);
+    }
+}
+
+/// The typeface of a level of the typography scale, in place of the system font.
+///
+/// The size of the text still comes from its [`TextStyle`], so it follows the dynamic
+/// type size.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Typeface {
+    /// The font family, or `None` for the system font.
+    pub family: Option<String>,
+    /// The weight, from 1 to 1000, or `None` for the weight of the text style.
+    pub weight: Option<u16>,
+    /// Whether the text is italic.
+    pub italic: bool,
+}
+
+impl Typeface {
+    /// Returns the font of `style` with this typeface.
+    pub fn font(&self, style: TextStyle) -> Font {
+        let mut font = Font::style(style);
+        if let Some(family) = &self.family {
+            font = font.family(family.clone());
+        }
+        if let Some(weight) = self.weight {
+            font = font.weight(FontWeight::new(weight));
+        }
+        if self.italic {
+            font = font.italic();
+        }
+        font
+    }
+}
+
+/// The typography scale of a theme, with the typeface of each [`TextStyle`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Typography {
+    /// The typeface of large titles, at the top of a screen.
+    pub large_title: Typeface,
+    /// The typeface of titles.
+    pub title: Typeface,
+    /// The typeface of second level titles.
+    pub title2: Typeface,
+    /// The typeface of third level titles.
+    pub title3: Typeface,
+    /// The typeface of headings.
+    pub headline: Typeface,
+    /// The typeface of body text.
+    pub body: Typeface,
+    /// The typeface of callouts.
+    pub callout: Typeface,
+    /// The typeface of subheadings.
+    pub subheadline: Typeface,
+    /// The typeface of footnotes.
+    pub footnote: Typeface,
+    /// The typeface of captions.
+    pub caption: Typeface,
+    /// The typeface of secondary captions.
+    pub caption2: Typeface,
+}
+
+impl Typography {
+    /// Returns the typeface of `style`.
+    pub const fn typeface(&self, style: TextStyle) -> &Typeface {
+        match style {
+            TextStyle::LargeTitle => &self.large_title,
+            TextStyle::Title => &self.title,
+            TextStyle::Title2 => &self.title2,
+            TextStyle::Title3 => &self.title3,
+            TextStyle::Headline => &self.headline,
+            TextStyle::Body => &self.body,
+            TextStyle::Callout => &self.callout,
+            TextStyle::Subheadline => &self.subheadline,
+            TextStyle::Footnote => &self.footnote,
+            TextStyle::Caption => &self.caption,
+            TextStyle::Caption2 => &self.caption2,
+        }
+    }
+
+    /// Returns the font of `style`, sized for the dynamic type size when resolved.
+    pub fn font(&self, style: TextStyle) -> Font {
+        self.typeface(style).font(style)
+    }
+}
+
+/// The spacing tokens of a theme, in points.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Spacing {
+    /// The tightest spacing, between closely related elements.
+    pub extra_small: f64,
+    /// A small spacing.
+    pub small: f64,
+    /// The default spacing.
+    pub medium: f64,
+    /// A large spacing.
+    pub large: f64,
+    /// The loosest spacing, between sections.
+    pub extra_large: f64,
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            extra_small: 4.0,
+            small: 8.0,
+            medium: 16.0,
+            large: 24.0,
+            extra_large: 32.0,
+        }
+    }
+}
+
+/// The corner radius tokens of a theme, in points.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Radius {
+    /// The radius of small elements, like tags.
+    pub small: f64,
+    /// The default radius, for buttons and fields.
+    pub medium: f64,
+    /// The radius of large elements, like cards and sheets.
+    pub large: f64,
+}
+
+impl Default for Radius {
+    fn default() -> Self {
+        Self {
+            small: 4.0,
+            medium: 8.0,
+            large: 16.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_core::Str;
+    use waterui_text::font::{DynamicTypeSize, FontWeight, TextStyle};
+
+    use super::{Typeface, Typography};
+
+    #[test]
+    fn fonts_follow_their_text_style() {
+        let typography = Typography {
+            headline: Typeface {
+                family: Some("Inter".into()),
+                weight: Some(800),
+                italic: true,
+            },
+            ..Typography::default()
+        };
+
+        let body = typography.font(TextStyle::Body);
+        assert!(body.families.is_empty());
+        assert_eq!(body.text_style, Some(TextStyle::Body));
+        assert_eq!(body.scaled(DynamicTypeSize::ExtraLarge).size, 19.0);
+
+        let headline = typography.font(TextStyle::Headline);
+        assert_eq!(headline.families, [Str::from("Inter")]);
+        assert_eq!(headline.weight, FontWeight::EXTRA_BOLD);
+        assert!(headline.italic);
+        assert_eq!(headline.scaled(DynamicTypeSize::Accessibility1).size, 28.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn typefaces_are_read_from_toml() {
+        let theme =
+            super::Theme::from_toml("[typography.headline]\nfamily = \"Inter\"\nweight = 600\n")
+                .unwrap();
+        assert_eq!(theme.typography.headline.family.as_deref(), Some("Inter"));
+        assert_eq!(theme.typography.headline.weight, Some(600));
+        assert_eq!(theme.typography.body, Typeface::default());
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::{collections::BTreeMap, string::String};
+use async_fs::read_to_string;
+use serde::Deserialize;
+use std::io;
+use toml::from_str;
+use waterui_core::{
+    Color,
+    color::{Palette, SemanticColor},
+};
+
+use super::{Radius, Spacing, Theme, Typography};
+
+extern crate std;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Io Error {0}")]
+    Io(#[from] io::Error),
+    #[error("Deserialize error {0}")]
+    Deserialize(#[from] toml::de::Error),
+    #[error("Unknown color `{0}`")]
+    UnknownColor(String),
+}
+
+/// The layout of a theme file, where every value is optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ThemeFile {
+    colors: ColorsFile,
+    typography: Typography,
+    spacing: Spacing,
+    radius: Radius,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ColorsFile {
+    light: BTreeMap<String, Color>,
+    dark: BTreeMap<String, Color>,
+}
+
+fn apply(palette: &mut Palette, colors: BTreeMap<String, Color>) -> Result<(), Error> {
+    for (name, value) in colors {
+        let color = SemanticColor::from_name(&name).ok_or(Error::UnknownColor(name))?;
+        *palette.get_mut(color) = value;
+    }
+    Ok(())
+}
+
+impl Theme {
+    /// Loads a theme from a TOML file.
+    ///
+    /// Values missing from the file keep their default.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_toml(&read_to_string(path).await?)
+    }
+
+    /// Parses a theme from a TOML document.
+    pub fn from_toml(source: &str) -> Result<Self, Error> {
+        let file: ThemeFile = from_str(source)?;
+        let mut theme = Self {
+            typography: file.typography,
+            spacing: file.spacing,
+            radius: file.radius,
+            ..Self::default()
+        };
+        apply(&mut theme.colors.light, file.colors.light)?;
+        apply(&mut theme.colors.dark, file.colors.dark)?;
+        Ok(theme)
+    }
+}
@@ -5,14 +5,18 @@
 //!   that produces a tuple of their results.
 //! - `FlattenMap`: A trait for flattening and mapping nested tuple structures,
 //!   which simplifies working with multiple zipped computations.
+//! - `Zip3` through `Zip8`: Fixed-arity zips producing flat tuples, created with
+//!   `zip3` through `zip8`.
+//! - `ZipAll`: A zip over a runtime-sized list of computations, created with `zip_all`.
 //!
 //! These utilities enable composition of reactive computations, making it easier
 //! to work with multiple interdependent values in a reactive context.
 
-use alloc::rc::Rc;
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::RefCell;
 
 use crate::{
-    Compute,
+    Compute, ComputeExt, Computed,
     map::{Map, map},
     watcher::{Watcher, WatcherGuard},
 };
@@ -147,3 +151,234 @@ impl<A: Compute, B: Compute> Compute for Zip<A, B> {
         })
     }
 }
+
+/// Builds the left-nested `Zip` type for a list of type parameters,
+/// e.g. `Zip<Zip<A, B>, C>` for `A, B, C`.
+macro_rules! nested_zip_type {
+    ($first:ident, $($rest:ident),+) => {
+        nested_zip_type!(@acc $first; $($rest),+)
+    };
+    (@acc $acc:ty; $next:ident $(, $rest:ident)*) => {
+        nested_zip_type!(@acc Zip<$acc, $next>; $($rest),*)
+    };
+    (@acc $acc:ty;) => {
+        $acc
+    };
+}
+
+/// Builds the left-nested `Zip` value for a list of computations.
+macro_rules! nested_zip_value {
+    ($first:ident, $($rest:ident),+) => {
+        nested_zip_value!(@acc $first; $($rest),+)
+    };
+    (@acc $acc:expr; $next:ident $(, $rest:ident)*) => {
+        nested_zip_value!(@acc Zip::new($acc, $next); $($rest),*)
+    };
+    (@acc $acc:expr;) => {
+        $acc
+    };
+}
+
+/// Builds the pattern destructuring a left-nested tuple, e.g. `((a, b), c)`.
+macro_rules! nested_zip_pat {
+    ($first:ident, $($rest:ident),+) => {
+        nested_zip_pat!(@acc [$first]; $($rest),+)
+    };
+    (@acc [$($acc:tt)*]; $next:ident $(, $rest:ident)*) => {
+        nested_zip_pat!(@acc [($($acc)*, $next)]; $($rest),*)
+    };
+    (@acc [$($acc:tt)*];) => {
+        $($acc)*
+    };
+}
+
+macro_rules! impl_zip_n {
+    ($name:ident, $fn_name:ident, $n:literal, $($ty:ident $value:ident),+) => {
+        #[doc = concat!("A computation combining ", stringify!($n), " computations into a flat tuple of their results.")]
+        ///
+        /// When one source changes, its new value is passed through and the remaining
+        /// sources are computed once, so each change produces exactly one notification.
+        pub struct $name<$($ty),+>(nested_zip_type!($($ty),+));
+
+        impl<$($ty: Compute),+> Clone for $name<$($ty),+> {
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+
+        impl<$($ty: Compute),+> $name<$($ty),+> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` from ", stringify!($n), " computations.")]
+            #[allow(clippy::too_many_arguments)]
+            pub fn new($($value: $ty),+) -> Self {
+                Self(nested_zip_value!($($value),+))
+            }
+        }
+
+        #[doc = concat!("Combines ", stringify!($n), " computations into one producing a flat tuple.")]
+        ///
+        /// This is a convenience wrapper around the constructor of the returned type.
+        #[allow(clippy::too_many_arguments)]
+        pub fn $fn_name<$($ty: Compute),+>($($value: $ty),+) -> $name<$($ty),+> {
+            $name::new($($value),+)
+        }
+
+        impl<$($ty: Compute),+> Compute for $name<$($ty),+> {
+            type Output = ($($ty::Output,)+);
+
+            fn compute(&self) -> Self::Output {
+                let nested_zip_pat!($($value),+) = self.0.compute();
+                ($($value,)+)
+            }
+
+            fn add_watcher(&self, watcher: impl Watcher<Self::Output>) -> WatcherGuard {
+                self.0.add_watcher(move |nested_zip_pat!($($value),+), metadata| {
+                    watcher.notify(($($value,)+), metadata)
+                })
+            }
+        }
+    };
+}
+
+impl_zip_n!(Zip3, zip3, 3, A a, B b, C c);
+impl_zip_n!(Zip4, zip4, 4, A a, B b, C c, D d);
+impl_zip_n!(Zip5, zip5, 5, A a, B b, C c, D d, E e);
+impl_zip_n!(Zip6, zip6, 6, A a, B b, C c, D d, E e, F f);
+impl_zip_n!(Zip7, zip7, 7, A a, B b, C c, D d, E e, F f, G g);
+impl_zip_n!(Zip8, zip8, 8, A a, B b, C c, D d, E e, F f, G g, H h);
+
+/// A computation combining a runtime-sized list of computations into a `Vec` of their results.
+///
+/// Watchers keep a cache of the latest value of every source. When a source changes,
+/// only its slot in the cache is replaced, and the watcher is notified once with the
+/// updated list; the other sources are not recomputed.
+pub struct ZipAll<C> {
+    sources: Rc<[C]>,
+}
+
+impl<C> Clone for ZipAll<C> {
+    fn clone(&self) -> Self {
+        Self {
+            sources: self.sources.clone(),
+        }
+    }
+}
+
+impl<C: Compute> ZipAll<C> {
+    /// Creates a new `ZipAll` from a list of computations.
+    pub fn new(sources: impl IntoIterator<Item = C>) -> Self {
+        Self {
+            sources: sources.into_iter().collect(),
+        }
+    }
+
+    /// Returns the number of zipped computations.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns `true` if there is no computation to zip.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+impl<C> Compute for ZipAll<C>
+where
+    C: Compute,
+    C::Output: Clone,
+{
+    type Output = Vec<C::Output>;
+
+    fn compute(&self) -> Self::Output {
+        self.sources.iter().map(Compute::compute).collect()
+    }
+
+    fn add_watcher(&self, watcher: impl Watcher<Self::Output>) -> WatcherGuard {
+        let watcher = Rc::new(watcher);
+        let cache = Rc::new(RefCell::new(self.compute()));
+        let guards: Vec<WatcherGuard> = self
+            .sources
+            .iter()
+            .enumerate()
+            .map(|(index, source)| {
+                let watcher = watcher.clone();
+                let cache = cache.clone();
+                source.add_watcher(move |value, metadata| {
+                    let values = {
+                        let mut cache = cache.borrow_mut();
+                        cache[index] = value;
+                        cache.clone()
+                    };
+                    watcher.notify(values, metadata)
+                })
+            })
+            .collect();
+
+        WatcherGuard::new(move || drop(guards))
+    }
+}
+
+/// Combines a runtime-sized list of computations into one producing a `Vec` of their results.
+///
+/// # Example
+///
+/// ```rust
+/// use waterui_reactive::{Compute, ComputeExt, binding, zip::zip_all};
+///
+/// let a = binding(1.0);
+/// let b = binding(2.5);
+/// let total = zip_all(vec![a.clone().computed(), b.computed()])
+///     .map(|values| values.into_iter().sum::<f64>());
+///
+/// assert_eq!(total.compute(), 3.5);
+/// a.set(2.0);
+/// assert_eq!(total.compute(), 4.5);
+/// ```
+pub fn zip_all<T: Clone + 'static>(sources: Vec<Computed<T>>) -> Computed<Vec<T>> {
+    ZipAll::new(sources).computed()
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::RefCell;
+
+    use super::{zip_all, zip3};
+    use crate::{Compute, ComputeExt, binding};
+
+    #[test]
+    fn zip3_is_flat() {
+        let a = binding(1);
+        let b = binding("b");
+        let c = binding(3.0);
+        let zipped = zip3(a.clone(), b, c);
+        assert_eq!(zipped.compute(), (1, "b", 3.0));
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let _guard = {
+            let received = received.clone();
+            zipped.watch(move |value| received.borrow_mut().push(value))
+        };
+        a.set(2);
+        assert_eq!(*received.borrow(), vec![(2, "b", 3.0)]);
+    }
+
+    #[test]
+    fn zip_all_updates_changed_slot() {
+        let sources: Vec<_> = (0..4).map(binding).collect();
+        let zipped = zip_all(sources.iter().map(|s| s.clone().computed()).collect());
+        assert_eq!(zipped.compute(), vec![0, 1, 2, 3]);
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let _guard = {
+            let received = received.clone();
+            zipped.watch(move |value| received.borrow_mut().push(value))
+        };
+        sources[2].set(20);
+        sources[0].set(10);
+        assert_eq!(
+            *received.borrow(),
+            vec![vec![0, 1, 20, 3], vec![10, 1, 20, 3]]
+        );
+    }
+}
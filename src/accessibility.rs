@@ -0,0 +1,205 @@
+//! Accessibility support for the built-in views.
+//!
+//! Views are described for assistive technologies with the accessibility modifiers of
+//! [`ViewExt`](crate::ViewExt), and the components of WaterUI provide default roles and
+//! values from their configuration.
+//!
+//! Installing [`AccessibilityPlugin`] lets [`AccessibilityTree`] inspect the built-in views,
+//! so the accessibility of a screen can be checked in tests, without a renderer:
+//!
+//! ```rust
+//! use waterui::accessibility::{AccessibilityPlugin, AccessibilityTree};
+//! use waterui::component::{button, text};
+//! use waterui::layout::stack::hstack;
+//! use waterui::{Environment, View, ViewExt};
+//!
+//! fn player() -> impl View {
+//!     hstack((text("Now playing"), button("▶").accessibility_label("Play")))
+//! }
+//!
+//! let env = Environment::new().install(AccessibilityPlugin);
+//! let issues = AccessibilityTree::new(player(), &env).lint();
+//! assert!(issues.is_empty(), "{issues:?}");
+//! ```
+
+#[doc(inline)]
+pub use waterui_core::accessibility::*;
+
+use alloc::{string::ToString, vec};
+use waterui_core::{
+    AnyView, Compute, ComputeExt, Computed, Environment,
+    components::{IgnorableMetadata, Metadata},
+    gesture::GestureHandler,
+    plugin::Plugin,
+    view::ConfigurableView,
+};
+use waterui_form::{TextField, Toggle, slider::Slider, stepper::Stepper, text_field::SecureField};
+use waterui_layout::{Edge, Frame, grid::Grid, scroll::ScrollView, spacer::Spacer, stack::Stack};
+use waterui_media::{LivePhoto, Photo, picker::MediaPicker, video::VideoPlayer};
+use waterui_navigation::{NavigationLink, tab::Tabs};
+
+use crate::{
+    background::{Background, ForegroundColor},
+    component::{
+        Button, Progress, Text,
+        badge::Badge,
+        divder::Divider,
+        focu::Focused,
+        text::{attributed::StyledText, link::Link},
+    },
+    lifecycle::{OnAppear, OnDisappear},
+};
+
+/// A plugin letting [`AccessibilityTree`] inspect the built-in views.
+///
+/// It registers an inspector for every raw and configurable view of WaterUI, on top of
+/// the inspectors already in the environment. Other views depending on a renderer, like
+/// [`Native`](waterui_core::components::Native) views, appear as elements without a label.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn install(self, env: &mut Environment) {
+        let mut inspectors = env
+            .get::<AccessibilityInspectors>()
+            .cloned()
+            .unwrap_or_default();
+        register(&mut inspectors);
+        env.insert(inspectors);
+        env.insert(self);
+    }
+}
+
+fn register(inspectors: &mut AccessibilityInspectors) {
+    inspectors.accessible::<Text>();
+    inspectors.accessible::<StyledText>();
+    inspectors.accessible::<Link>();
+    inspectors.accessible::<Button>();
+    inspectors.accessible::<Progress>();
+    inspectors.accessible::<Toggle>();
+    inspectors.accessible::<Slider>();
+    inspectors.accessible::<Stepper>();
+    inspectors.accessible::<TextField>();
+    inspectors.insert(|field: SecureField, _env: &Environment| {
+        // The content of secure fields is never exposed.
+        let mut element = field.config().accessibility_element();
+        element.accessibility.value = None;
+        Inspection::Element(element)
+    });
+
+    inspectors.insert(|link: NavigationLink, _env: &Environment| {
+        Inspection::Element(
+            AccessibilityElement::new(Accessibility::new().role(AccessibilityRole::Button))
+                .content(link.label),
+        )
+    });
+    inspectors.insert(|_: Photo, _env: &Environment| image());
+    inspectors.insert(|_: LivePhoto, _env: &Environment| image());
+    inspectors.insert(|_: VideoPlayer, _env: &Environment| image());
+    // Media pickers have no label of their own, so they need an accessibility label.
+    inspectors.insert(|_: MediaPicker, _env: &Environment| {
+        Inspection::Element(AccessibilityElement::new(
+            Accessibility::new().role(AccessibilityRole::Button),
+        ))
+    });
+
+    inspectors.hidden::<Divider>();
+    inspectors.hidden::<Spacer>();
+
+    inspectors.contents(|stack: Stack| stack.contents);
+    inspectors.contents(|scroll: ScrollView| vec![scroll.content]);
+    inspectors.contents(|grid: Grid| grid.rows.into_iter().flat_map(|row| row.columns).collect());
+    inspectors.contents(|tabs: Tabs| {
+        let config = tabs.config();
+        let selection = config.selection.compute();
+        config
+            .tabs
+            .into_iter()
+            .map(|tab| {
+                let traits = if tab.label.tag == selection {
+                    AccessibilityTraits::SELECTED
+                } else {
+                    AccessibilityTraits::NONE
+                };
+                AnyView::new(IgnorableMetadata::new(
+                    tab.label.content,
+                    Accessibility::new()
+                        .role(AccessibilityRole::Button)
+                        .traits(traits),
+                ))
+            })
+            .collect()
+    });
+    inspectors.contents(|badge: Badge| {
+        // The count is announced as the value of the badged view.
+        let config = badge.config();
+        let value = config.value.map(|value| value.to_string());
+        vec![AnyView::new(IgnorableMetadata::new(
+            config.content,
+            Accessibility::new().value(value),
+        ))]
+    });
+
+    inspectors.contents(|view: Metadata<Edge>| vec![view.content]);
+    inspectors.contents(|view: Metadata<Computed<Frame>>| vec![view.content]);
+    inspectors.contents(|view: Metadata<Background>| vec![view.content]);
+    inspectors.contents(|view: Metadata<ForegroundColor>| vec![view.content]);
+    inspectors.contents(|view: Metadata<Focused>| vec![view.content]);
+    inspectors.contents(|view: Metadata<GestureHandler>| vec![view.content]);
+    inspectors.contents(|view: Metadata<OnAppear>| vec![view.content]);
+    inspectors.contents(|view: Metadata<OnDisappear>| vec![view.content]);
+}
+
+fn image() -> Inspection {
+    Inspection::Element(AccessibilityElement::new(
+        Accessibility::new().role(AccessibilityRole::Image),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_core::{Environment, components::Native};
+    use waterui_layout::stack::vstack;
+    use waterui_media::{Video, video::VideoPlayer};
+
+    use super::{AccessibilityPlugin, AccessibilityRole, AccessibilityTree};
+    use crate::{ViewExt, component::text};
+
+    #[test]
+    fn views_depending_on_a_renderer() {
+        let env = Environment::new().install(AccessibilityPlugin);
+        let tree = AccessibilityTree::new(
+            vstack((
+                Video::new("https://example.com/trailer.mp4").accessibility_label("Trailer"),
+                Native(()),
+                text("Credits"),
+            )),
+            &env,
+        );
+
+        let nodes: Vec<_> = tree
+            .iter()
+            .map(|node| (node.role, node.label.as_deref(), node.view))
+            .collect();
+        assert_eq!(
+            nodes,
+            [
+                (
+                    AccessibilityRole::Image,
+                    Some("Trailer"),
+                    core::any::type_name::<VideoPlayer>()
+                ),
+                (
+                    AccessibilityRole::Group,
+                    None,
+                    core::any::type_name::<Native<()>>()
+                ),
+                (
+                    AccessibilityRole::Text,
+                    Some("Credits"),
+                    core::any::type_name::<crate::component::Text>()
+                ),
+            ]
+        );
+    }
+}
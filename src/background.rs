@@ -1,6 +1,10 @@
 //! This module provides types for defining background and foreground colors in a UI.
 
-use waterui_core::{Color, Computed};
+use waterui_core::{
+    Color, Computed,
+    gradient::{AngularGradient, LinearGradient, RadialGradient},
+    shape::Paint,
+};
 use waterui_reactive::compute::IntoComputed;
 use waterui_str::Str;
 
@@ -11,6 +15,12 @@ pub enum Background {
     Color(Computed<Color>),
     Image(Computed<Str>),
     Material(Material),
+    /// A linear gradient background.
+    LinearGradient(LinearGradient),
+    /// A radial gradient background.
+    RadialGradient(RadialGradient),
+    /// An angular gradient background.
+    AngularGradient(AngularGradient),
 }
 
 #[derive(Debug, uniffi::Enum)]
@@ -33,6 +43,35 @@ impl Background {
     }
 }
 
+impl From<LinearGradient> for Background {
+    fn from(value: LinearGradient) -> Self {
+        Self::LinearGradient(value)
+    }
+}
+
+impl From<RadialGradient> for Background {
+    fn from(value: RadialGradient) -> Self {
+        Self::RadialGradient(value)
+    }
+}
+
+impl From<AngularGradient> for Background {
+    fn from(value: AngularGradient) -> Self {
+        Self::AngularGradient(value)
+    }
+}
+
+impl From<Paint> for Background {
+    fn from(value: Paint) -> Self {
+        match value {
+            Paint::Color(color) => Self::Color(color),
+            Paint::LinearGradient(gradient) => Self::LinearGradient(gradient),
+            Paint::RadialGradient(gradient) => Self::RadialGradient(gradient),
+            Paint::AngularGradient(gradient) => Self::AngularGradient(gradient),
+        }
+    }
+}
+
 /// Represents the color of text or other foreground elements in a UI.
 #[derive(Debug, uniffi::Record)]
 pub struct ForegroundColor {
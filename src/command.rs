@@ -0,0 +1,138 @@
+//! Keyboard shortcuts and commands.
+//!
+//! Views handle commands with [`ViewExt::on_command`](crate::ViewExt::on_command), and
+//! buttons are given keyboard shortcuts with
+//! [`ViewExt::keyboard_shortcut`](crate::ViewExt::keyboard_shortcut). Both register their
+//! handlers in the [`CommandScope`] of their environment as they are built, and keep them
+//! registered as long as the renderer keeps the view.
+//!
+//! ```
+//! use waterui::ViewExt;
+//! use waterui::command::{Command, KeyBinding};
+//! use waterui::component::{button, text};
+//!
+//! let find: KeyBinding = "Cmd+F".parse().unwrap();
+//! let editor = text("Untitled").on_command(Command::new("editor.find", "Find").shortcut(find), || {
+//!     // Show the find bar.
+//! });
+//! let save: KeyBinding = "Cmd+S".parse().unwrap();
+//! let save = button("Save").action(|| {}).keyboard_shortcut(save);
+//! ```
+
+#[doc(inline)]
+pub use waterui_core::command::*;
+
+use alloc::rc::Rc;
+
+use waterui_core::{
+    AnyView, Environment, View,
+    handler::{Handler, HandlerFn, into_handler},
+    view::ConfigurableView,
+};
+
+use crate::{
+    ComputeExt,
+    component::{Button, Metadata, Retain},
+};
+
+/// A view handling a command, while the focus is in it or anywhere in its scope.
+///
+/// Created by [`ViewExt::on_command`](crate::ViewExt::on_command) and
+/// [`ViewExt::keyboard_shortcut`](crate::ViewExt::keyboard_shortcut).
+pub struct OnCommand<V> {
+    content: V,
+    command: Command,
+    handler: Rc<dyn Handler<()>>,
+    /// Whether the command only applies while the focus is in the content.
+    scoped: bool,
+}
+
+impl<V> core::fmt::Debug for OnCommand<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OnCommand")
+            .field("command", &self.command)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V: View> OnCommand<V> {
+    /// Handles `command` with `handler` while the focus is in `content`.
+    pub fn new<P: 'static>(content: V, command: Command, handler: impl HandlerFn<P, ()>) -> Self {
+        Self {
+            content,
+            command,
+            handler: Rc::new(into_handler(handler)),
+            scoped: true,
+        }
+    }
+}
+
+impl<V: View> View for OnCommand<V> {
+    fn body(self, env: &Environment) -> impl View {
+        // Without a dispatcher, the command is registered in a scope nobody dispatches to.
+        let parent = CommandScope::of(env).unwrap_or_default();
+        let (scope, content) = if self.scoped {
+            let scope = parent.child();
+            let env = env.clone().with(scope.clone());
+            (scope, AnyView::new(Metadata::new(self.content, env)))
+        } else {
+            (parent, AnyView::new(self.content))
+        };
+
+        let registration = scope.register_shared(self.command, self.handler, env.clone());
+        Retain::new(content, registration)
+    }
+}
+
+/// Gives a keyboard shortcut to a button, performing its action from anywhere in the
+/// enclosing scope while the button is enabled.
+pub(crate) fn keyboard_shortcut(button: Button, shortcut: KeyBinding) -> OnCommand<Button> {
+    let mut config = button.config();
+    let action: Rc<dyn Handler<()>> = Rc::from(config.action);
+    config.action = Box::new(action.clone());
+    let enabled = config.disabled.clone().map(|disabled| !disabled).computed();
+
+    let id = format!("shortcut.{shortcut}");
+    OnCommand {
+        content: Button::from(config),
+        command: Command::new(id, shortcut.to_string())
+            .shortcut(shortcut)
+            .enabled(enabled),
+        handler: action,
+        scoped: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use waterui_core::{Environment, View};
+
+    use super::{CommandDispatcher, KeyBinding, KeyEvent};
+    use crate::{ViewExt, component::button};
+
+    #[test]
+    fn shortcuts_are_registered_while_the_view_is_kept() {
+        let dispatcher = CommandDispatcher::new();
+        let env = Environment::new().install(dispatcher.clone());
+        let saved = Rc::new(Cell::new(0));
+        let shortcut: KeyBinding = "Cmd+S".parse().unwrap();
+        let event = KeyEvent::new(shortcut.key, shortcut.modifiers);
+
+        let view = button("Save")
+            .action({
+                let saved = saved.clone();
+                move || saved.set(saved.get() + 1)
+            })
+            .keyboard_shortcut(shortcut)
+            .body(&env);
+        assert!(dispatcher.dispatch(&event, None));
+        assert_eq!(saved.get(), 1);
+
+        drop(view);
+        assert!(!dispatcher.dispatch(&event, None));
+        assert_eq!(saved.get(), 1);
+    }
+}
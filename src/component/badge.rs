@@ -16,7 +16,7 @@
 
 use crate::ViewExt;
 use waterui_core::{AnyView, View};
-use waterui_core::{Color, configurable};
+use waterui_core::{Color, color::SemanticColor, configurable};
 use waterui_reactive::{Compute, Computed, compute::IntoComputed};
 
 /// Configuration for the Badge component
@@ -26,7 +26,7 @@ pub struct BadgeConfig {
     pub value: Computed<i32>,
     /// The content that the badge will be attached to
     pub content: AnyView,
-    /// The color of the badge
+    /// The color of the badge, the theme's error color by default
     pub color: Computed<Color>,
 }
 
@@ -44,7 +44,7 @@ impl Badge {
         Self(BadgeConfig {
             value: value.into_computed(),
             content: content.anyview(),
-            color: Color::default().into_computed(),
+            color: Color::semantic(SemanticColor::Error).into_computed(),
         })
     }
 
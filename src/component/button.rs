@@ -6,7 +6,7 @@
 //! # Examples
 //!
 //! ```
-//! use waterui::button;
+//! use waterui::component::button;
 //!
 //! let button = button("Click me").action(|| {
 //!     println!("Button clicked!");
@@ -15,15 +15,41 @@
 //!
 //! Tip: `action` receives a `HandlerFn`, it can extract value from environment and pass it to the action.
 //! To learn more about `HandlerFn`, see the [HandlerFn] documentation.
+//!
+//! Asynchronous actions are set with `async_action`. While the action runs, `running` is `true`,
+//! which can be used to disable the button or to show a loading indicator:
+//!
+//! ```
+//! use waterui::component::button;
+//!
+//! async fn save_document() {
+//!     // Writes the document to disk.
+//! }
+//!
+//! let save = button("Save").async_action(async || {
+//!     save_document().await;
+//! });
+//! let running = save.running();
+//! let save = save.disabled(running).loading_indicator();
+//! ```
 
 use core::fmt::Debug;
 
 use alloc::boxed::Box;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, AccessibilityTraits, Accessible,
+};
 use waterui_core::configurable;
-use waterui_core::handler::{ActionObject, HandlerFn, into_handler};
+use waterui_core::handler::{
+    ActionObject, ActionPolicy, AsyncAction, AsyncHandlerFn, HandlerFn, into_handler,
+};
+use waterui_reactive::{Compute, Computed, compute::IntoComputed};
 
 use crate::View;
+use crate::component::loading;
+use crate::widget::condition::when;
 use crate::{AnyView, ViewExt};
+use waterui_layout::stack::hstack;
 
 /// Configuration for a button component.
 ///
@@ -35,17 +61,42 @@ pub struct ButtonConfig {
     pub label: AnyView,
     /// The action to execute when the button is clicked
     pub action: ActionObject,
+    /// Whether the button is disabled
+    pub disabled: Computed<bool>,
+    /// Whether an asynchronous action of the button is running
+    pub running: Computed<bool>,
 }
 
 impl_debug!(ButtonConfig);
 
 configurable!(Button, ButtonConfig);
 
+impl Accessible for ButtonConfig {
+    /// A button is labeled by the text of its label, and is busy while its action runs.
+    fn accessibility_element(self) -> AccessibilityElement {
+        let mut traits = AccessibilityTraits::NONE;
+        if self.disabled.compute() {
+            traits |= AccessibilityTraits::DISABLED;
+        }
+        if self.running.compute() {
+            traits |= AccessibilityTraits::BUSY;
+        }
+        AccessibilityElement::new(
+            Accessibility::new()
+                .role(AccessibilityRole::Button)
+                .traits(traits),
+        )
+        .content(self.label)
+    }
+}
+
 impl Default for Button {
     fn default() -> Self {
         Self(ButtonConfig {
             label: ().anyview(),
             action: Box::new(into_handler(|| {})),
+            disabled: Computed::constant(false),
+            running: Computed::constant(false),
         })
     }
 }
@@ -73,10 +124,90 @@ impl Button {
     /// The modified button with the action set
     pub fn action<P: 'static>(mut self, action: impl HandlerFn<P, ()>) -> Self {
         self.0.action = Box::new(into_handler(action));
+        self.set_running(Computed::constant(false));
+        self
+    }
+
+    /// Sets an asynchronous action to be performed when the button is clicked.
+    ///
+    /// The action runs on the main thread. Clicks while it is running are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The async function to execute when button is clicked
+    pub fn async_action<P: 'static>(self, action: impl AsyncHandlerFn<P, ()>) -> Self {
+        self.async_action_with_policy(action, ActionPolicy::Ignore)
+    }
+
+    /// Sets an asynchronous action, choosing what happens on clicks while it is running.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The async function to execute when button is clicked
+    /// * `policy` - Whether to ignore new clicks or to cancel the running action
+    pub fn async_action_with_policy<P: 'static>(
+        mut self,
+        action: impl AsyncHandlerFn<P, ()>,
+        policy: ActionPolicy,
+    ) -> Self {
+        let action = AsyncAction::new(action, policy);
+        self.set_running(action.running());
+        self.0.action = Box::new(action);
+        self
+    }
+
+    fn set_running(&mut self, running: Computed<bool>) {
+        if let Some(label) = self.0.label.downcast_mut::<LoadingLabel>() {
+            label.running = running.clone();
+        }
+        self.0.running = running;
+    }
+
+    /// Returns whether the asynchronous action of this button is running.
+    ///
+    /// This is always `false` for synchronous actions.
+    pub fn running(&self) -> Computed<bool> {
+        self.0.running.clone()
+    }
+
+    /// Shows a [`loading`] indicator next to the label while the asynchronous action runs.
+    ///
+    /// The indicator follows the action of the button, whether it is set before or after.
+    pub fn loading_indicator(mut self) -> Self {
+        if self.0.label.is::<LoadingLabel>() {
+            return self;
+        }
+        let label = core::mem::replace(&mut self.0.label, ().anyview());
+        self.0.label = AnyView::new(LoadingLabel {
+            label,
+            running: self.0.running.clone(),
+        });
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `disabled` - The condition under which the button is disabled
+    pub fn disabled(mut self, disabled: impl IntoComputed<bool>) -> Self {
+        self.0.disabled = disabled.into_computed();
         self
     }
 }
 
+/// The label of a button with a loading indicator, following the action of the button.
+struct LoadingLabel {
+    label: AnyView,
+    running: Computed<bool>,
+}
+
+impl View for LoadingLabel {
+    fn body(self, _env: &waterui_core::Environment) -> impl View {
+        hstack((when(self.running, loading), self.label))
+    }
+}
+
 /// Convenience function to create a new button with the specified label.
 ///
 /// # Arguments
@@ -102,3 +233,25 @@ macro_rules! bindgen {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use waterui_core::Environment;
+    use waterui_core::view::ConfigurableView;
+    use waterui_reactive::Compute;
+
+    use super::{LoadingLabel, button};
+
+    #[test]
+    fn loading_indicator_follows_an_action_set_afterwards() {
+        let config = button("Save")
+            .loading_indicator()
+            .async_action(async || core::future::pending::<()>().await)
+            .config();
+        let label = config.label.downcast_ref::<LoadingLabel>().unwrap();
+        assert!(!label.running.compute());
+
+        config.action.handle(&Environment::new());
+        assert!(label.running.compute());
+    }
+}
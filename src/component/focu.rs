@@ -49,8 +49,27 @@
 //!
 //! When one element receives focus, any previously focused element will automatically
 //! lose focus due to the shared binding mechanism.
+//!
+//! ## Navigation
+//!
+//! Once a [`FocusEngine`] is installed, focused views join the [`FocusScope`] of their
+//! environment as they are built, so renderers and handlers can move the focus with
+//! [`FocusScope::focus_next`] and [`FocusScope::focus_previous`]. The sequence follows the
+//! order in which views are built: views appearing later, such as the new content of a
+//! dynamic view, come last unless given an explicit order with
+//! [`FocusElement::order`]. Views wrapped with
+//! [`ViewExt::focus_scope`](crate::ViewExt::focus_scope), such as the content of a sheet,
+//! keep the navigation inside them, and return the focus where it was when they disappear.
+
+#[doc(inline)]
+pub use waterui_core::focus::{FocusElement, FocusEngine, FocusRegistration, FocusScope};
+
+use waterui_core::{Environment, View};
 
-use crate::Binding;
+use crate::{
+    Binding,
+    component::{Metadata, Retain},
+};
 
 /// A struct that represents a focused state based on a binding to a boolean value.
 #[derive(Debug, Clone)]
@@ -78,8 +97,107 @@ impl Focused {
             move |binding, value| {
                 if value {
                     binding.set(Some(equals.clone()));
+                } else if binding.get().as_ref() == Some(&equals) {
+                    binding.set(None);
                 }
             },
         ))
     }
 }
+
+/// A view joining the focus sequence of its scope.
+///
+/// Created by [`ViewExt::focused`](crate::ViewExt::focused).
+#[derive(Debug)]
+pub struct Focusable<V> {
+    content: V,
+    focused: Focused,
+    order: i32,
+    default: bool,
+}
+
+impl<V: View> Focusable<V> {
+    /// Makes `content` focusable, focused while `focused` is `true`.
+    pub fn new(content: V, focused: Focused) -> Self {
+        Self {
+            content,
+            focused,
+            order: 0,
+            default: false,
+        }
+    }
+
+    /// Sets the position of this view in the focus sequence, lower first.
+    ///
+    /// Views default to 0, and keep the order they are built in among equal orders.
+    pub fn focus_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Focuses this view when its scope appears without focus.
+    pub fn default_focus(mut self) -> Self {
+        self.default = true;
+        self
+    }
+}
+
+impl<V: View> View for Focusable<V> {
+    fn body(self, env: &Environment) -> impl View {
+        let mut element = FocusElement::new(self.focused.0.clone()).order(self.order);
+        element.default = self.default;
+        // Without a focus engine, the view can still be focused through its binding.
+        let registration = FocusScope::of(env).map(|scope| scope.register(element));
+        Retain::new(Metadata::new(self.content, self.focused), registration)
+    }
+}
+
+/// A view keeping focus navigation inside it, and returning the focus where it was when
+/// it disappears.
+///
+/// Created by [`ViewExt::focus_scope`](crate::ViewExt::focus_scope).
+#[derive(Debug)]
+pub struct FocusScopeView<V> {
+    content: V,
+}
+
+impl<V: View> FocusScopeView<V> {
+    /// Creates a focus scope for `content`.
+    pub fn new(content: V) -> Self {
+        Self { content }
+    }
+}
+
+impl<V: View> View for FocusScopeView<V> {
+    fn body(self, env: &Environment) -> impl View {
+        let scope = FocusScope::of(env).map(|parent| parent.child());
+        let mut content_env = env.clone();
+        if let Some(scope) = scope.clone() {
+            content_env.insert(scope);
+        }
+        Retain::new(Metadata::new(self.content, content_env), scope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_core::{Environment, View};
+
+    use super::FocusScope;
+    use crate::{Binding, ViewExt};
+
+    #[test]
+    fn registered_while_the_view_is_kept() {
+        let scope = FocusScope::new();
+        let env = Environment::new().with(scope.clone());
+        let field = Binding::container(None::<u8>);
+
+        let view = ().focused(field.clone(), 1).body(&env);
+        assert_eq!(scope.len(), 1);
+        scope.focus_next();
+        assert_eq!(field.get(), Some(1));
+
+        drop(view);
+        assert_eq!(scope.len(), 0);
+    }
+}
@@ -0,0 +1,103 @@
+//! Markdown documents, such as release notes and help screens.
+//!
+//! A [`Markdown`] view parses its source with the CommonMark subset of
+//! [`waterui_text::markdown`], and composes the blocks out of styled texts and stacks:
+//!
+//! ```
+//! let notes = markdown("## What's new\n\n- **Faster** sync\n- [Docs](https://waterui.dev)");
+//! ```
+
+#[doc(inline)]
+pub use waterui_text::markdown::{Block, parse, parse_inline};
+
+use alloc::format;
+
+use waterui_core::{AnyView, Color, Environment, View, color::SemanticColor};
+use waterui_layout::stack::{HStack, VStack};
+use waterui_reactive::{Computed, compute::IntoComputed};
+use waterui_str::Str;
+use waterui_text::{
+    attributed::{Attribute, AttributedStr, styled_text},
+    font::{Font, TextStyle},
+    text,
+};
+
+use crate::{
+    ViewExt,
+    background::Background,
+    component::{Dynamic, divder::Divider},
+};
+
+/// A view rendering a markdown document.
+#[derive(Debug)]
+pub struct Markdown {
+    source: Computed<Str>,
+}
+
+impl Markdown {
+    /// Creates a view rendering the markdown `source`, again whenever it changes.
+    pub fn new(source: impl IntoComputed<Str>) -> Self {
+        Self {
+            source: source.into_computed(),
+        }
+    }
+}
+
+/// Creates a view rendering the markdown `source`.
+pub fn markdown(source: impl IntoComputed<Str>) -> Markdown {
+    Markdown::new(source)
+}
+
+impl View for Markdown {
+    fn body(self, _env: &Environment) -> impl View {
+        Dynamic::watch(self.source, |source| blocks(parse(&source)))
+    }
+}
+
+fn blocks(blocks: impl IntoIterator<Item = Block>) -> VStack {
+    blocks.into_iter().map(block).collect()
+}
+
+fn block(block: Block) -> AnyView {
+    match block {
+        Block::Paragraph(content) => styled_text(content).anyview(),
+        Block::Heading { level, content } => {
+            let style = match level {
+                1 => TextStyle::Title,
+                2 => TextStyle::Title2,
+                3 => TextStyle::Title3,
+                4 => TextStyle::Headline,
+                5 => TextStyle::Subheadline,
+                _ => TextStyle::Footnote,
+            };
+            styled_text(content)
+                .font(Font::style(style).bold())
+                .anyview()
+        }
+        Block::CodeBlock { code, .. } => {
+            let mut content = AttributedStr::new();
+            content.push(code, [Attribute::Code]);
+            styled_text(content)
+                .padding()
+                .background(Background::color(Color::from(SemanticColor::Surface)))
+                .anyview()
+        }
+        Block::Quote(children) => blocks(children)
+            .padding()
+            .foreground(Color::from(SemanticColor::SecondaryLabel))
+            .anyview(),
+        Block::List { start, items } => items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let marker = match start {
+                    Some(start) => format!("{}.", start + index as u64),
+                    None => "•".into(),
+                };
+                HStack::new((text(marker), blocks(item)))
+            })
+            .collect::<VStack>()
+            .anyview(),
+        Block::Rule => Divider.anyview(),
+    }
+}
@@ -9,6 +9,9 @@ pub use button::{Button, button};
 pub mod badge;
 pub mod divder;
 pub mod focu;
+pub mod markdown;
+#[doc(inline)]
+pub use markdown::{Markdown, markdown};
 
 //pub mod list;
 
@@ -26,10 +26,13 @@ use crate::ComputeExt;
 use crate::ViewExt;
 use alloc::format;
 use waterui_core::View;
+use waterui_core::accessibility::{
+    Accessibility, AccessibilityElement, AccessibilityRole, AccessibilityTraits, Accessible,
+};
 use waterui_core::configurable;
-use waterui_reactive::Computed;
 use waterui_reactive::compute::IntoComputed;
 use waterui_reactive::zip::FlattenMap;
+use waterui_reactive::{Compute, Computed};
 use waterui_text::text;
 
 /// Configuration for progress indicators.
@@ -60,6 +63,31 @@ pub enum ProgressStyle {
 
 configurable!(Progress, ProgressConfig);
 
+impl Accessible for ProgressConfig {
+    /// The value is announced as a percentage, and indeterminate indicators are busy.
+    fn accessibility_element(self) -> AccessibilityElement {
+        let traits = if self.value.compute().is_nan() {
+            AccessibilityTraits::BUSY
+        } else {
+            AccessibilityTraits::UPDATES_FREQUENTLY
+        };
+        let value = self.value.map(|value| {
+            if value.is_nan() {
+                alloc::string::String::new()
+            } else {
+                format!("{:.0}%", value * 100.0)
+            }
+        });
+        AccessibilityElement::new(
+            Accessibility::new()
+                .role(AccessibilityRole::ProgressIndicator)
+                .value(value)
+                .traits(traits),
+        )
+        .content(self.label)
+    }
+}
+
 /// A progress indicator with a calculated total.
 ///
 /// Created by calling `total()` on a `Progress` instance.
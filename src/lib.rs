@@ -7,9 +7,12 @@ extern crate alloc;
 
 #[macro_use]
 mod macros;
+pub mod accessibility;
 pub mod background;
+pub mod command;
 pub mod component;
 pub mod filter;
+pub mod lifecycle;
 pub mod task;
 pub mod view;
 pub mod widget;
@@ -21,8 +24,11 @@ pub use view::ViewExt;
 pub use waterui_core::{
     AnyView,
     env::{self, Environment},
+    extract::Extractor,
     impl_extractor, raw_view,
 };
+#[doc(inline)]
+pub use waterui_macro::Extractor;
 
 #[doc(inline)]
 pub use waterui_reactive::{Binding, Compute, Computed, compute};
@@ -36,3 +42,50 @@ pub use waterui_layout as layout;
 pub use waterui_str::Str;
 
 uniffi::setup_scaffolding!();
+
+// Lets the derive macros refer to this crate as `::waterui` in tests.
+#[cfg(test)]
+extern crate self as waterui;
+
+#[cfg(test)]
+mod tests {
+    use waterui_core::extract::Use;
+
+    use crate::{Environment, Extractor};
+
+    #[derive(Extractor)]
+    struct Settings {
+        scale: Use<i32>,
+        theme: Option<Use<&'static str>>,
+        limit: Result<Use<u8>, waterui_core::Error>,
+    }
+
+    #[derive(Extractor)]
+    struct Required(Use<i32>, Use<u16>, Use<u8>);
+
+    #[derive(Extractor)]
+    struct Empty;
+
+    #[test]
+    fn derive_extractor() {
+        let env = Environment::new().with(2i32);
+        let settings = Settings::extract(&env).unwrap();
+        assert_eq!(settings.scale.0, 2);
+        assert!(settings.theme.is_none());
+        assert!(settings.limit.is_err());
+
+        let env = env.with("dark").with(10u8);
+        let settings = Settings::extract(&env).unwrap();
+        assert_eq!(settings.theme.map(|theme| theme.0), Some("dark"));
+        assert_eq!(settings.limit.map(|limit| limit.0).ok(), Some(10));
+
+        // Every missing field is reported.
+        let Err(error) = Required::extract(&Environment::new()) else {
+            panic!("extraction succeeded");
+        };
+        assert_eq!(alloc::format!("{error}").matches("\n  - ").count(), 3);
+        let Required(scale, count, limit) = Required::extract(&env.clone().with(3u16)).unwrap();
+        assert_eq!((scale.0, count.0, limit.0), (2, 3, 10));
+        assert!(Empty::extract(&env).is_ok());
+    }
+}
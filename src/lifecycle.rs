@@ -0,0 +1,103 @@
+//! Lifecycle events of views.
+//!
+//! Renderers call the action of an [`OnAppear`] each time its view is shown on screen, and
+//! the action of an [`OnDisappear`] each time it is removed, so the actions may run several
+//! times, for instance as the user switches between tabs.
+//!
+//! Work tied to the presence of a view is started with [`ViewExt::task`](crate::ViewExt::task),
+//! which cancels it once the view disappears:
+//!
+//! ```
+//! let feed = list(posts.clone()).task(async move || {
+//!     posts.set(fetch_posts().await);
+//! });
+//! ```
+
+use alloc::{boxed::Box, rc::Rc};
+use core::{cell::RefCell, marker::PhantomData};
+
+use waterui_core::{
+    Environment,
+    handler::{ActionObject, AsyncHandlerFn, Handler, HandlerFn, into_handler},
+};
+use waterui_task::LocalTask;
+
+/// An action called by renderers each time a view appears.
+#[derive(uniffi::Record)]
+pub struct OnAppear {
+    /// The action to call.
+    pub action: ActionObject,
+}
+
+impl_debug!(OnAppear);
+
+impl OnAppear {
+    /// Creates an appearance action from a handler function.
+    pub fn new<P: 'static>(action: impl HandlerFn<P, ()>) -> Self {
+        Self {
+            action: Box::new(into_handler(action)),
+        }
+    }
+}
+
+/// An action called by renderers each time a view disappears.
+#[derive(uniffi::Record)]
+pub struct OnDisappear {
+    /// The action to call.
+    pub action: ActionObject,
+}
+
+impl_debug!(OnDisappear);
+
+impl OnDisappear {
+    /// Creates a disappearance action from a handler function.
+    pub fn new<P: 'static>(action: impl HandlerFn<P, ()>) -> Self {
+        Self {
+            action: Box::new(into_handler(action)),
+        }
+    }
+}
+
+/// The task of a view, running while it is on screen.
+type Running = Rc<RefCell<Option<LocalTask<()>>>>;
+
+/// Starts the task of a view when it appears.
+struct StartTask<H, P> {
+    handler: Rc<H>,
+    running: Running,
+    _marker: PhantomData<P>,
+}
+
+impl<H, P> Handler<()> for StartTask<H, P>
+where
+    H: AsyncHandlerFn<P, ()>,
+    P: 'static,
+{
+    fn handle(&self, env: &Environment) {
+        cancel(&self.running);
+        let handler = self.handler.clone();
+        let env = env.clone();
+        let task = LocalTask::on_main(async move { handler.handle_inner(&env).await });
+        self.running.replace(Some(task));
+    }
+}
+
+fn cancel(running: &Running) {
+    if let Some(task) = running.take() {
+        LocalTask::on_main(task.cancel());
+    }
+}
+
+/// Creates the actions running `handler` from the appearance of a view to its disappearance.
+pub(crate) fn task<P: 'static>(handler: impl AsyncHandlerFn<P, ()>) -> (OnAppear, OnDisappear) {
+    let running = Running::default();
+    let appear = OnAppear {
+        action: Box::new(StartTask {
+            handler: Rc::new(handler),
+            running: running.clone(),
+            _marker: PhantomData,
+        }),
+    };
+    let disappear = OnDisappear::new(move || cancel(&running));
+    (appear, disappear)
+}
@@ -1,15 +1,28 @@
-use core::cell::RefCell;
+use alloc::{boxed::Box, rc::Rc};
+use core::{cell::RefCell, fmt};
 
 use crate::ComputeExt;
-use waterui_core::{Compute, View};
+use waterui_core::{Compute, Environment, View, components::Retain};
 use waterui_reactive::watcher::WatcherGuard;
 pub use waterui_task::*;
 
 /// A view that executes a callback when a computed value changes.
-#[derive(Debug)]
+///
+/// The callback receives the previous and the new value. It is called for changes only,
+/// unless the view is created with [`OnChange::initial`], which also calls it with the
+/// current value, as both the previous and the new one, when the view is built.
+///
+/// The source is watched as long as the renderer keeps the view.
 pub struct OnChange<V> {
     content: V,
-    _guard: WatcherGuard,
+    initial: Option<Box<dyn FnOnce()>>,
+    guard: WatcherGuard,
+}
+
+impl<V> fmt::Debug for OnChange<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(core::any::type_name::<Self>())
+    }
 }
 
 impl<V> OnChange<V> {
@@ -20,32 +33,111 @@ impl<V> OnChange<V> {
     ///
     /// * `content` - The view to render
     /// * `source` - The computed value to watch for changes
-    /// * `handler` - The callback to execute when the value changes
+    /// * `handler` - The callback to execute with the previous and the new value
     pub fn new<C, F>(content: V, source: C, handler: F) -> Self
     where
         C: Compute,
         V: View,
         C::Output: PartialEq + Clone,
-        F: Fn(C::Output) + 'static,
+        F: Fn(C::Output, C::Output) + 'static,
     {
-        let cache: RefCell<Option<C::Output>> = RefCell::new(None);
+        let cache = RefCell::new(source.compute());
         let guard = source.watch(move |value| {
-            if let Some(cache) = &mut *cache.borrow_mut() {
-                if *cache != value {
-                    *cache = value.clone();
-                    handler(value)
-                }
+            let mut cache = cache.borrow_mut();
+            if *cache != value {
+                let previous = core::mem::replace(&mut *cache, value.clone());
+                // Release the cache, as the handler may change the source.
+                drop(cache);
+                handler(previous, value);
             }
         });
         Self {
             content,
-            _guard: guard,
+            initial: None,
+            guard,
         }
     }
+
+    /// Creates a new OnChange view that executes the provided handler with the
+    /// current value when it is built, then whenever the source value changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The view to render
+    /// * `source` - The computed value to watch for changes
+    /// * `handler` - The callback to execute with the previous and the new value
+    pub fn initial<C, F>(content: V, source: C, handler: F) -> Self
+    where
+        C: Compute,
+        V: View,
+        C::Output: PartialEq + Clone,
+        F: Fn(C::Output, C::Output) + 'static,
+    {
+        let handler = Rc::new(handler);
+        let mut view = Self::new(content, source.clone(), {
+            let handler = handler.clone();
+            move |previous, value| handler(previous, value)
+        });
+        view.initial = Some(Box::new(move || {
+            let value = source.compute();
+            handler(value.clone(), value);
+        }));
+        view
+    }
 }
 
 impl<V: View> View for OnChange<V> {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
-        self.content
+    fn body(self, _env: &Environment) -> impl View {
+        if let Some(initial) = self.initial {
+            initial();
+        }
+        Retain::new(self.content, self.guard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::RefCell;
+
+    use waterui_core::{Binding, Environment, View};
+
+    use super::OnChange;
+
+    type Changes = Rc<RefCell<Vec<(i32, i32)>>>;
+
+    fn record(changes: &Changes) -> impl Fn(i32, i32) + 'static {
+        let changes = changes.clone();
+        move |previous, value| changes.borrow_mut().push((previous, value))
+    }
+
+    #[test]
+    fn called_with_previous_and_new_value() {
+        let source = Binding::int(1);
+        let changes = Changes::default();
+        let view = OnChange::new((), source.clone(), record(&changes)).body(&Environment::new());
+        assert!(changes.borrow().is_empty());
+
+        source.set(2);
+        source.set(2);
+        source.set(5);
+        assert_eq!(*changes.borrow(), [(1, 2), (2, 5)]);
+
+        drop(view);
+        source.set(6);
+        assert_eq!(changes.borrow().len(), 2);
+    }
+
+    #[test]
+    fn initial_is_called_once_when_built() {
+        let source = Binding::int(1);
+        let changes = Changes::default();
+        let view = OnChange::initial((), source.clone(), record(&changes));
+        assert!(changes.borrow().is_empty());
+
+        let _view = view.body(&Environment::new());
+        assert_eq!(*changes.borrow(), [(1, 1)]);
+        source.set(3);
+        assert_eq!(*changes.borrow(), [(1, 1), (1, 3)]);
     }
 }
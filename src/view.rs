@@ -12,17 +12,35 @@
 pub use waterui_core::view::*;
 use waterui_core::{
     AnyView, Color, Environment,
-    env::With,
-    handler::{Handler, HandlerFn, IntoHandler},
+    accessibility::{Accessibility, AccessibilityRole, AccessibilityTraits},
+    animation::Transition,
+    color::ColorScheme,
+    env::{EnvKey, EnvValue, With},
+    gesture::{
+        DragGesture, Gesture, GestureExt, GestureHandler, LongPressGesture, MagnifyGesture,
+        RotateGesture, TapGesture,
+    },
+    handler::{AsyncHandlerFn, Handler, HandlerFn, IntoHandler},
 };
 
 use alloc::boxed::Box;
 use waterui_navigation::NavigationView;
 use waterui_reactive::{Binding, Computed, compute::IntoComputed};
+use waterui_str::Str;
 
 use crate::background::{Background, ForegroundColor};
-use crate::component::{Metadata, Text, badge::Badge, focu::Focused};
+use crate::command::{self, Command, KeyBinding, OnCommand};
+use crate::component::Button;
+use crate::component::{
+    IgnorableMetadata, Metadata, Text,
+    badge::Badge,
+    focu::{FocusScopeView, Focusable, Focused},
+    text::font::DynamicTypeSize,
+};
+use crate::lifecycle::{self, OnAppear, OnDisappear};
+use crate::task::OnChange;
 use waterui_core::id::TaggedView;
+use waterui_reactive::Compute;
 
 use waterui_layout::{Edge, Frame};
 
@@ -30,10 +48,15 @@ use waterui_layout::{Edge, Frame};
 pub trait ConfigViewExt: ConfigurableView + Sized {
     /// Apply a modifier to this view, returning a new modified view.
     ///
+    /// The modifier runs after the modifiers installed in the environment for this view type.
+    ///
     /// # Arguments
     /// * `modifier` - The modifier to apply to this view
-    fn modifier(self, modifier: impl Into<Modifier<Self>>) -> impl View {
-        modifier.into().modify(Environment::new(), self.config())
+    fn modifier(self, modifier: impl Into<Modifier<Self>>) -> Modified<Self>
+    where
+        Self: From<Self::Config>,
+    {
+        Modified::new(self, modifier)
     }
 }
 
@@ -112,6 +135,40 @@ pub trait ViewExt: View + Sized {
         With::new(self, value)
     }
 
+    /// Sets the value of the environment key `K` for this view and its children.
+    ///
+    /// # Arguments
+    /// * `value` - The value to store under the key
+    fn environment<K: EnvKey>(self, value: K::Value) -> With<Self, EnvValue<K>> {
+        With::new(self, EnvValue(value))
+    }
+
+    /// Sets the color scheme for this view and its children.
+    ///
+    /// Semantic colors inside the view are resolved against the palette of this scheme.
+    ///
+    /// # Arguments
+    /// * `scheme` - The color scheme to use, which may change over time
+    fn color_scheme(
+        self,
+        scheme: impl IntoComputed<ColorScheme>,
+    ) -> With<Self, Computed<ColorScheme>> {
+        With::new(self, scheme.into_computed())
+    }
+
+    /// Sets the dynamic type size for this view and its children.
+    ///
+    /// Fonts of text styles inside the view are scaled for this size.
+    ///
+    /// # Arguments
+    /// * `size` - The dynamic type size to use, which may change over time
+    fn dynamic_type_size(
+        self,
+        size: impl IntoComputed<DynamicTypeSize>,
+    ) -> With<Self, Computed<DynamicTypeSize>> {
+        With::new(self, size.into_computed())
+    }
+
     /// Sets this view as the content of a navigation view with the specified title.
     ///
     /// # Arguments
@@ -122,6 +179,8 @@ pub trait ViewExt: View + Sized {
 
     /// Marks this view as focused when the binding matches the specified value.
     ///
+    /// The view joins the focus sequence of the enclosing scope, in the order views are built.
+    ///
     /// # Arguments
     /// * `value` - Binding to the focused value
     /// * `equals` - The value to compare against for focus
@@ -129,8 +188,241 @@ pub trait ViewExt: View + Sized {
         self,
         value: Binding<Option<T>>,
         equals: T,
-    ) -> Metadata<Focused> {
-        Metadata::new(self, Focused::new(value, equals))
+    ) -> Focusable<Self> {
+        Focusable::new(self, Focused::new(value, equals))
+    }
+
+    /// Keeps focus navigation inside this view, as for a sheet, and returns the focus to
+    /// the view focused before once this view disappears.
+    fn focus_scope(self) -> FocusScopeView<Self> {
+        FocusScopeView::new(self)
+    }
+
+    /// Sets how this view enters and leaves when a dynamic view swaps its content.
+    ///
+    /// The transition plays with the animation of the change causing the swap.
+    /// Renderers without transition support show and hide the view immediately.
+    /// It must be the last modifier of the content, as the transitions of wrapped views
+    /// are ignored.
+    ///
+    /// # Arguments
+    /// * `transition` - The transition used for insertion and removal
+    fn transition(self, transition: Transition) -> IgnorableMetadata<Transition> {
+        IgnorableMetadata::new(self, transition)
+    }
+
+    /// Describes this view for assistive technologies.
+    ///
+    /// Properties left unset keep the defaults of the view.
+    ///
+    /// # Arguments
+    /// * `accessibility` - The description of the view
+    fn accessibility(self, accessibility: Accessibility) -> IgnorableMetadata<Accessibility> {
+        IgnorableMetadata::new(self, accessibility)
+    }
+
+    /// Sets the label read by assistive technologies for this view.
+    ///
+    /// Views that don't show text, such as icon-only buttons, need one.
+    ///
+    /// # Arguments
+    /// * `label` - A short name of the view, such as "Play"
+    fn accessibility_label(
+        self,
+        label: impl IntoComputed<Str>,
+    ) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().label(label))
+    }
+
+    /// Sets the hint read by assistive technologies after the label of this view.
+    ///
+    /// # Arguments
+    /// * `hint` - The result of interacting with the view, such as "Plays the song"
+    fn accessibility_hint(self, hint: impl IntoComputed<Str>) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().hint(hint))
+    }
+
+    /// Sets the value read by assistive technologies for this view.
+    ///
+    /// # Arguments
+    /// * `value` - The current value of the view, such as "50%"
+    fn accessibility_value(
+        self,
+        value: impl IntoComputed<Str>,
+    ) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().value(value))
+    }
+
+    /// Sets the role announced by assistive technologies for this view.
+    ///
+    /// # Arguments
+    /// * `role` - What the view is
+    fn accessibility_role(self, role: AccessibilityRole) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().role(role))
+    }
+
+    /// Adds accessibility traits to this view.
+    ///
+    /// # Arguments
+    /// * `traits` - The states and behaviors of the view
+    fn accessibility_traits(self, traits: AccessibilityTraits) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().traits(traits))
+    }
+
+    /// Hides this view and its content from assistive technologies.
+    ///
+    /// # Arguments
+    /// * `hidden` - Whether the view is hidden, such as for decorative images
+    fn accessibility_hidden(
+        self,
+        hidden: impl IntoComputed<bool>,
+    ) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().hidden(hidden))
+    }
+
+    /// Sets the priority of this view in the reading order of its siblings.
+    ///
+    /// # Arguments
+    /// * `priority` - The priority, higher first, 0 by default
+    fn accessibility_sort_priority(self, priority: f64) -> IgnorableMetadata<Accessibility> {
+        self.accessibility(Accessibility::new().sort_priority(priority))
+    }
+
+    /// Attaches a gesture to this view, driven by the pointer events of the renderer.
+    ///
+    /// # Arguments
+    /// * `gesture` - The gesture to recognize, with its handlers
+    fn gesture(self, gesture: impl Gesture) -> Metadata<GestureHandler> {
+        Metadata::new(self, GestureHandler::new(gesture))
+    }
+
+    /// Calls a handler when this view is tapped.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to call, which can extract the `TapValue`
+    fn on_tap<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> Metadata<GestureHandler> {
+        self.gesture(TapGesture::new().on_ended(handler))
+    }
+
+    /// Calls a handler when this view is pressed for half a second.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to call, which can extract the `TapValue`
+    fn on_long_press<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> Metadata<GestureHandler> {
+        self.gesture(LongPressGesture::new().on_ended(handler))
+    }
+
+    /// Calls a handler as this view is dragged.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to call on every move, which can extract the `DragValue`
+    fn on_drag<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> Metadata<GestureHandler> {
+        self.gesture(DragGesture::new().on_changed(handler))
+    }
+
+    /// Calls a handler as this view is pinched.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to call on every change, which can extract the `MagnifyValue`
+    fn on_magnify<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> Metadata<GestureHandler> {
+        self.gesture(MagnifyGesture::new().on_changed(handler))
+    }
+
+    /// Calls a handler as this view is rotated with two fingers.
+    ///
+    /// # Arguments
+    /// * `handler` - The handler to call on every change, which can extract the `RotateValue`
+    fn on_rotate<P: 'static>(self, handler: impl HandlerFn<P, ()>) -> Metadata<GestureHandler> {
+        self.gesture(RotateGesture::new().on_changed(handler))
+    }
+
+    /// Calls a handler each time this view appears on screen.
+    ///
+    /// # Arguments
+    /// * `action` - The handler to call
+    fn on_appear<P: 'static>(self, action: impl HandlerFn<P, ()>) -> Metadata<OnAppear> {
+        Metadata::new(self, OnAppear::new(action))
+    }
+
+    /// Calls a handler each time this view disappears from screen.
+    ///
+    /// # Arguments
+    /// * `action` - The handler to call
+    fn on_disappear<P: 'static>(self, action: impl HandlerFn<P, ()>) -> Metadata<OnDisappear> {
+        Metadata::new(self, OnDisappear::new(action))
+    }
+
+    /// Calls a handler with the previous and the new value each time `source` changes.
+    ///
+    /// # Arguments
+    /// * `source` - The value to watch
+    /// * `handler` - The handler to call with the previous and the new value
+    fn on_change<C>(
+        self,
+        source: C,
+        handler: impl Fn(C::Output, C::Output) + 'static,
+    ) -> OnChange<Self>
+    where
+        C: Compute,
+        C::Output: PartialEq + Clone,
+    {
+        OnChange::new(self, source, handler)
+    }
+
+    /// Calls a handler with the current value of `source` when this view is built, then
+    /// with the previous and the new value each time it changes.
+    ///
+    /// # Arguments
+    /// * `source` - The value to watch
+    /// * `handler` - The handler to call with the previous and the new value
+    fn on_change_initial<C>(
+        self,
+        source: C,
+        handler: impl Fn(C::Output, C::Output) + 'static,
+    ) -> OnChange<Self>
+    where
+        C: Compute,
+        C::Output: PartialEq + Clone,
+    {
+        OnChange::initial(self, source, handler)
+    }
+
+    /// Runs an asynchronous task on the main thread each time this view appears, and
+    /// cancels it when the view disappears.
+    ///
+    /// # Arguments
+    /// * `task` - The async function to run, which can extract values from the environment
+    fn task<P: 'static>(self, task: impl AsyncHandlerFn<P, ()>) -> Metadata<OnAppear> {
+        let (appear, disappear) = lifecycle::task(task);
+        Metadata::new(Metadata::new(self, disappear), appear)
+    }
+
+    /// Handles a command while the focus is in this view.
+    ///
+    /// The command is performed from its key binding or from a menu, once a
+    /// `CommandDispatcher` is installed.
+    ///
+    /// # Arguments
+    /// * `command` - The command to handle
+    /// * `handler` - The handler performing the command
+    fn on_command<P: 'static>(
+        self,
+        command: Command,
+        handler: impl HandlerFn<P, ()>,
+    ) -> OnCommand<Self> {
+        OnCommand::new(self, command, handler)
+    }
+
+    /// Performs the action of this button when a key binding is pressed, wherever the focus
+    /// is in the enclosing command scope.
+    ///
+    /// # Arguments
+    /// * `shortcut` - The key binding, such as `"Cmd+S".parse().unwrap()`
+    fn keyboard_shortcut(self, shortcut: impl Into<KeyBinding>) -> OnCommand<Button>
+    where
+        Self: Into<Button>,
+    {
+        command::keyboard_shortcut(self.into(), shortcut.into())
     }
 
     /// Converts this view to an `AnyView` type-erased container.
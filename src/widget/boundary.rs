@@ -0,0 +1,319 @@
+//! Error boundaries, containing the failures of a part of the view tree.
+//!
+//! An [`ErrorBoundary`] gives its content an [`ErrorSink`] in the environment. Views report
+//! errors to it, for instance from an action or a task, and the panics of their `body`
+//! are reported to it as [`PanicError`]s, as renderers resolve them with
+//! [`body_or_report`](waterui_core::error::body_or_report). Panics while building the
+//! content are caught by the boundary itself. Either way, the content is replaced by a
+//! fallback, rendered by the [`DefaultErrorView`](crate::widget::error::DefaultErrorView)
+//! of the environment unless a custom one is set.
+//!
+//! The content is rebuilt when the reset binding is set to `true`, so the fallback can
+//! offer to retry:
+//!
+//! ```
+//! use waterui::component::{button, text};
+//! use waterui::layout::stack::vstack;
+//! use waterui::widget::ErrorBoundary;
+//! use waterui::{Environment, View};
+//!
+//! fn profile_view() -> impl View {
+//!     text("Ada Lovelace")
+//! }
+//!
+//! let profile = ErrorBoundary::new(|_: Environment| profile_view()).fallback(|error, reset| {
+//!     vstack((text(error.to_string()), button("Retry").action(move || reset.set(true))))
+//! });
+//! ```
+//!
+//! Catching panics needs the standard library, so this module is only available with the
+//! `std` feature, which isn't enabled by default.
+
+#[doc(inline)]
+pub use waterui_core::error::{BoxedError, ErrorSink, PanicError};
+
+use alloc::rc::{Rc, Weak};
+use core::{cell::Cell, fmt};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use waterui_core::{AnyView, Environment, View};
+use waterui_reactive::watcher::WatcherGuard;
+
+use crate::{
+    Binding, ComputeExt,
+    component::{Dynamic, Metadata, Retain, dynamic::DynamicHandler},
+    view::ViewBuilder,
+    widget::error::UseDefaultErrorView,
+};
+
+type Fallback = Rc<dyn Fn(BoxedError, Binding<bool>) -> AnyView>;
+
+/// A view showing a fallback in place of its content when the content fails.
+///
+/// Only available with the `std` feature.
+pub struct ErrorBoundary<B> {
+    content: B,
+    fallback: Fallback,
+    reset: Binding<bool>,
+}
+
+impl<B> fmt::Debug for ErrorBoundary<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorBoundary")
+            .field("reset", &self.reset)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: ViewBuilder> ErrorBoundary<B> {
+    /// Creates a boundary for the content built by `content`, failing to the default error
+    /// view.
+    pub fn new(content: B) -> Self {
+        Self {
+            content,
+            fallback: Rc::new(|error, _| AnyView::new(UseDefaultErrorView::from(error))),
+            reset: Binding::container(false),
+        }
+    }
+
+    /// Sets the view shown when the content fails, built from the error and the reset
+    /// binding of the boundary.
+    pub fn fallback<V: View>(
+        mut self,
+        fallback: impl Fn(BoxedError, Binding<bool>) -> V + 'static,
+    ) -> Self {
+        self.fallback = Rc::new(move |error, reset| AnyView::new(fallback(error, reset)));
+        self
+    }
+
+    /// Rebuilds the content each time `reset` is set to `true`, setting it back to `false`.
+    pub fn reset(mut self, reset: Binding<bool>) -> Self {
+        self.reset = reset;
+        self
+    }
+}
+
+impl<B: ViewBuilder> View for ErrorBoundary<B> {
+    fn body(self, env: &Environment) -> impl View {
+        let (handler, view) = Dynamic::new();
+        let state = Rc::new(State {
+            content: self.content,
+            fallback: self.fallback,
+            reset: self.reset.clone(),
+            env: env.clone(),
+            handler,
+            generation: Cell::new(0),
+            failed: Cell::new(false),
+        });
+        state.build();
+
+        let guard: WatcherGuard = self.reset.watch({
+            let state = state.clone();
+            move |reset| {
+                if reset {
+                    state.reset.set(false);
+                    state.build();
+                }
+            }
+        });
+        Retain::new(view, guard)
+    }
+}
+
+struct State<B> {
+    content: B,
+    fallback: Fallback,
+    reset: Binding<bool>,
+    env: Environment,
+    handler: DynamicHandler,
+    /// Incremented on every build, so errors of previous contents are ignored.
+    generation: Cell<u64>,
+    failed: Cell<bool>,
+}
+
+impl<B: ViewBuilder> State<B> {
+    fn build(self: &Rc<Self>) {
+        let generation = self.generation.get() + 1;
+        self.generation.set(generation);
+        self.failed.set(false);
+
+        // The sink doesn't keep the boundary alive, as the content keeps the sink.
+        let state = Rc::downgrade(self);
+        let sink = ErrorSink::new(move |error| {
+            if let Some(state) = Weak::upgrade(&state) {
+                state.fail(generation, error);
+            }
+        });
+        let env = self.env.clone().with(sink);
+
+        let content = catch_unwind(AssertUnwindSafe(|| AnyView::new(self.content.view(&env))));
+        match content {
+            // The content may have reported an error while being built.
+            Ok(content) if !self.failed.get() => self.handler.set(Metadata::new(content, env)),
+            Ok(_) => {}
+            Err(payload) => self.fail(generation, PanicError::from_payload(&*payload).into()),
+        }
+    }
+
+    fn fail(&self, generation: u64, error: BoxedError) {
+        if generation != self.generation.get() || self.failed.replace(true) {
+            return;
+        }
+        self.handler.set((self.fallback)(error, self.reset.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, string::String, vec::Vec};
+    use core::{
+        cell::{Cell, RefCell},
+        fmt,
+    };
+
+    use waterui_core::{AnyView, Environment, View, components::Metadata};
+
+    use super::{ErrorBoundary, ErrorSink};
+    use crate::{Binding, component::Dynamic, widget::error::UseDefaultErrorView};
+
+    /// The label of the view a boundary shows, updated as it changes.
+    type Shown = Rc<RefCell<&'static str>>;
+
+    /// Builds `view` as a renderer does, returning what it shows and the environment
+    /// keeping it alive.
+    fn show(view: impl View) -> (Shown, Environment) {
+        let (mut view, mut env) = (AnyView::new(view), Environment::new());
+        let dynamic = loop {
+            view = match view.downcast::<Dynamic>() {
+                Ok(dynamic) => break *dynamic,
+                Err(other) => match other.downcast::<Metadata<Environment>>() {
+                    Ok(metadata) => {
+                        env = metadata.value;
+                        metadata.content
+                    }
+                    Err(other) => AnyView::new(other.body(&env)),
+                },
+            };
+        };
+        let shown = Shown::default();
+        dynamic.connect({
+            let shown = shown.clone();
+            move |view, _| {
+                let view = match view.downcast::<Metadata<Environment>>() {
+                    Ok(metadata) => metadata.content,
+                    Err(view) => view,
+                };
+                *shown.borrow_mut() = view
+                    .downcast_ref::<&'static str>()
+                    .copied()
+                    .unwrap_or(view.name());
+            }
+        });
+        (shown, env)
+    }
+
+    /// Content keeping the error sink of each of its builds.
+    fn content(
+        sinks: &Rc<RefCell<Vec<ErrorSink>>>,
+    ) -> impl Fn(Environment) -> &'static str + use<> {
+        let sinks = sinks.clone();
+        move |env| {
+            sinks.borrow_mut().extend(ErrorSink::of(&env));
+            "content"
+        }
+    }
+
+    #[test]
+    fn reported_errors_show_the_default_error_view() {
+        let sinks = Rc::default();
+        let (shown, _env) = show(ErrorBoundary::new(content(&sinks)));
+        assert_eq!(*shown.borrow(), "content");
+
+        sinks.borrow()[0].report(fmt::Error);
+        assert_eq!(
+            *shown.borrow(),
+            core::any::type_name::<UseDefaultErrorView>()
+        );
+    }
+
+    #[test]
+    fn panics_while_building_are_caught() {
+        let message = Rc::new(RefCell::new(String::new()));
+        let view = ErrorBoundary::new(|_: Environment| -> &'static str { panic!("no profile") })
+            .fallback({
+                let message = message.clone();
+                move |error, _| {
+                    *message.borrow_mut() = error.to_string();
+                    "failed"
+                }
+            });
+        let (shown, _env) = show(view);
+        assert_eq!(*shown.borrow(), "failed");
+        assert!(
+            message.borrow().contains("no profile"),
+            "{}",
+            message.borrow()
+        );
+    }
+
+    #[test]
+    fn custom_fallbacks_receive_the_error() {
+        let sinks = Rc::default();
+        let message = Rc::new(RefCell::new(String::new()));
+        let view = ErrorBoundary::new(content(&sinks)).fallback({
+            let message = message.clone();
+            move |error, _| {
+                *message.borrow_mut() = error.to_string();
+                "failed"
+            }
+        });
+        let (shown, _env) = show(view);
+
+        sinks.borrow()[0].report(fmt::Error);
+        assert_eq!(*shown.borrow(), "failed");
+        assert_eq!(*message.borrow(), fmt::Error.to_string());
+        // Only the first error replaces the content.
+        sinks.borrow()[0].report(fmt::Error);
+        assert_eq!(*shown.borrow(), "failed");
+    }
+
+    #[test]
+    fn resetting_rebuilds_the_content() {
+        let sinks = Rc::default();
+        let reset = Binding::container(false);
+        let builds = Rc::new(Cell::new(0));
+        let view = ErrorBoundary::new({
+            let (content, builds) = (content(&sinks), builds.clone());
+            move |env| {
+                builds.set(builds.get() + 1);
+                content(env)
+            }
+        })
+        .fallback(|_, _| "failed")
+        .reset(reset.clone());
+        let (shown, _env) = show(view);
+
+        sinks.borrow()[0].report(fmt::Error);
+        assert_eq!(*shown.borrow(), "failed");
+        reset.set(true);
+        assert_eq!(*shown.borrow(), "content");
+        assert_eq!(builds.get(), 2);
+        assert!(!reset.get());
+    }
+
+    #[test]
+    fn errors_of_previous_contents_are_ignored() {
+        let sinks = Rc::default();
+        let reset = Binding::container(false);
+        let view = ErrorBoundary::new(content(&sinks))
+            .fallback(|_, _| "failed")
+            .reset(reset.clone());
+        let (shown, _env) = show(view);
+
+        reset.set(true);
+        sinks.borrow()[0].report(fmt::Error);
+        assert_eq!(*shown.borrow(), "content");
+        sinks.borrow()[1].report(fmt::Error);
+        assert_eq!(*shown.borrow(), "failed");
+    }
+}
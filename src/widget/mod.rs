@@ -1,4 +1,8 @@
+#[cfg(feature = "std")]
+pub mod boundary;
 pub mod condition;
 pub mod error;
 pub mod suspense;
-pub use suspense::Suspense;
+#[cfg(feature = "std")]
+pub use boundary::ErrorBoundary;
+pub use suspense::{Suspense, SuspenseGroup};
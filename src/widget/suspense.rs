@@ -2,16 +2,32 @@
 //!
 //! This module implements a suspense mechanism similar to React Suspense,
 //! allowing components to show loading states while async content is being prepared.
+//!
+//! The content loads while the suspense view is kept by the renderer, and the load is
+//! cancelled once the view is dropped. Loads can time out, and can fail when created with
+//! [`Suspense::try_new`], showing their error with the default error view. Several suspense
+//! views in a [`SuspenseGroup`] reveal their content together, or in order.
 
-use core::future::Future;
+use alloc::{rc::Rc, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+    future::Future,
+    time::Duration,
+};
 
 use waterui_core::{AnyView, Environment, View};
-use waterui_task::LocalTask;
+use waterui_task::{
+    LocalTask,
+    future::{or, zip},
+    timer::Timer,
+};
 
 use crate::{
     ViewExt,
-    component::Dynamic,
+    component::{Dynamic, Metadata, Retain, dynamic::DynamicHandler},
     view::{AnyViewBuilder, ViewBuilder},
+    widget::error::{StdError, UseDefaultErrorView},
 };
 
 /// A component that displays a loading view while waiting for content to load.
@@ -22,13 +38,34 @@ use crate::{
 ///
 /// # Example
 /// ```
-/// let view = Suspense::new(async_content())
-///     .loading(Text::new("Loading..."));
+/// use core::time::Duration;
+/// use waterui::component::{Text, text};
+/// use waterui::widget::Suspense;
+///
+/// async fn load_greeting() -> Text {
+///     text("Hello!")
+/// }
+///
+/// let view = Suspense::new(load_greeting())
+///     .loading(Text::new("Loading..."))
+///     .timeout(Duration::from_secs(10), text("The server is not responding"))
+///     .min_duration(Duration::from_millis(300));
 /// ```
-#[derive(Debug)]
 pub struct Suspense<V, Loading> {
     content: V,
     loading: Loading,
+    timeout: Option<(Duration, AnyView)>,
+    min_duration: Option<Duration>,
+}
+
+impl<V, Loading: fmt::Debug> fmt::Debug for Suspense<V, Loading> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Suspense")
+            .field("loading", &self.loading)
+            .field("timeout", &self.timeout)
+            .field("min_duration", &self.min_duration)
+            .finish_non_exhaustive()
+    }
 }
 
 /// Trait for views that can be suspended (loaded asynchronously).
@@ -50,6 +87,43 @@ where
     }
 }
 
+/// Trait for views that are loaded asynchronously and can fail to load.
+///
+/// Used with [`Suspense::try_new`], which shows errors with the default error view.
+pub trait TrySuspendedView: 'static {
+    /// Takes an environment and returns a future that resolves to a view or an error.
+    fn body(
+        self,
+        _env: Environment,
+    ) -> impl Future<Output = Result<impl View, impl StdError + 'static>>;
+}
+
+impl<Fut, V, E> TrySuspendedView for Fut
+where
+    Fut: Future<Output = Result<V, E>> + 'static,
+    V: View,
+    E: StdError + 'static,
+{
+    fn body(
+        self,
+        _env: Environment,
+    ) -> impl Future<Output = Result<impl View, impl StdError + 'static>> {
+        self
+    }
+}
+
+/// A suspended view showing the error of a [`TrySuspendedView`] with the default error view.
+#[derive(Debug)]
+pub struct Fallible<V>(V);
+
+impl<V: TrySuspendedView> SuspendedView for Fallible<V> {
+    async fn body(self, env: Environment) -> impl View {
+        TrySuspendedView::body(self.0, env)
+            .await
+            .map_err(UseDefaultErrorView::new)
+    }
+}
+
 /// Container for the default loading view builder.
 ///
 /// This is typically set in the environment and used by `UseDefaultLoadingView`.
@@ -82,10 +156,25 @@ impl<V: SuspendedView> Suspense<V, UseDefaultLoadingView> {
         Self {
             content,
             loading: UseDefaultLoadingView,
+            timeout: None,
+            min_duration: None,
         }
     }
 }
 
+impl<V: TrySuspendedView> Suspense<Fallible<V>, UseDefaultLoadingView> {
+    /// Creates a new `Suspense` component with content that can fail to load.
+    ///
+    /// An error is shown with the default error view of the environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The suspended view to be displayed when loaded
+    pub fn try_new(content: V) -> Self {
+        Suspense::new(Fallible(content))
+    }
+}
+
 impl<V, Loading> Suspense<V, Loading> {
     /// Sets a custom loading view to display while content is loading.
     ///
@@ -96,12 +185,36 @@ impl<V, Loading> Suspense<V, Loading> {
     /// # Returns
     ///
     /// A new `Suspense` with the specified loading view
-    pub fn loading<Loading2, Output: View>(self, loading: Loading2) -> Suspense<V, Loading2> {
+    pub fn loading<Loading2: View>(self, loading: Loading2) -> Suspense<V, Loading2> {
         Suspense {
             content: self.content,
             loading,
+            timeout: self.timeout,
+            min_duration: self.min_duration,
         }
     }
+
+    /// Gives up loading after `duration`, showing `view` instead of the content.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The time allowed to load the content
+    /// * `view` - The view to show once the time is up
+    pub fn timeout(mut self, duration: Duration, view: impl View) -> Self {
+        self.timeout = Some((duration, AnyView::new(view)));
+        self
+    }
+
+    /// Shows the loading view for at least `duration`, so content loading quickly
+    /// doesn't flicker.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The minimum time the loading view is shown
+    pub fn min_duration(mut self, duration: Duration) -> Self {
+        self.min_duration = Some(duration);
+        self
+    }
 }
 
 impl<V, Loading> View for Suspense<V, Loading>
@@ -113,12 +226,430 @@ where
         let (handler, view) = Dynamic::new();
         handler.set(self.loading);
 
-        let new_env = env.clone();
-        LocalTask::on_main(async move {
-            let content = SuspendedView::body(self.content, new_env).await;
+        let reveal = match env.get::<Group>() {
+            Some(group) => Reveal::Group(group.clone(), group.join(handler)),
+            None => Reveal::Direct(handler),
+        };
+
+        let Self {
+            content,
+            timeout,
+            min_duration,
+            ..
+        } = self;
+        let env = env.clone();
+        let task = LocalTask::on_main({
+            let reveal = reveal.clone();
+            async move {
+                let load = async move {
+                    let load = async { AnyView::new(SuspendedView::body(content, env).await) };
+                    let min_duration = async {
+                        if let Some(duration) = min_duration {
+                            Timer::after(duration).await;
+                        }
+                    };
+                    zip(load, min_duration).await.0
+                };
+                let content = match timeout {
+                    Some((duration, view)) => {
+                        let timeout = async move {
+                            Timer::after(duration).await;
+                            view
+                        };
+                        or(load, timeout).await
+                    }
+                    None => load.await,
+                };
+                reveal.ready(content);
+            }
+        });
+
+        // The load is cancelled once the renderer drops the view.
+        let pending = Pending {
+            task: Some(task),
+            reveal,
+        };
+        Retain::new(view, pending)
+    }
+}
+
+/// How the suspense views of a [`SuspenseGroup`] reveal their content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RevealOrder {
+    /// All contents are revealed at once, when the last one is loaded.
+    #[default]
+    Together,
+    /// Contents are revealed in tree order, each once the ones before it are revealed.
+    InOrder,
+}
+
+/// A view coordinating the reveal of the suspense views in its content.
+///
+/// # Example
+/// ```
+/// use waterui::component::{Text, text};
+/// use waterui::layout::stack::vstack;
+/// use waterui::widget::{Suspense, SuspenseGroup, suspense::RevealOrder};
+///
+/// async fn load_header() -> Text {
+///     text("Ada Lovelace")
+/// }
+///
+/// async fn load_posts() -> Text {
+///     text("Notes on the Analytical Engine")
+/// }
+///
+/// let profile = SuspenseGroup::new(vstack((
+///     Suspense::new(load_header()),
+///     Suspense::new(load_posts()),
+/// )))
+/// .order(RevealOrder::InOrder);
+/// ```
+#[derive(Debug)]
+pub struct SuspenseGroup<V> {
+    content: V,
+    order: RevealOrder,
+}
+
+impl<V: View> SuspenseGroup<V> {
+    /// Creates a group revealing the suspense views in `content` together.
+    pub fn new(content: V) -> Self {
+        Self {
+            content,
+            order: RevealOrder::Together,
+        }
+    }
+
+    /// Sets how the suspense views reveal their content.
+    pub fn order(mut self, order: RevealOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+impl<V: View> View for SuspenseGroup<V> {
+    fn body(self, env: &Environment) -> impl View {
+        let group = Group(Rc::new(GroupState {
+            order: self.order,
+            members: RefCell::default(),
+            next_id: Cell::new(0),
+        }));
+        Metadata::new(self.content, env.clone().with(group))
+    }
+}
+
+struct Member {
+    id: u64,
+    handler: DynamicHandler,
+    content: Option<AnyView>,
+    revealed: bool,
+}
+
+struct GroupState {
+    order: RevealOrder,
+    members: RefCell<Vec<Member>>,
+    next_id: Cell<u64>,
+}
+
+#[derive(Clone)]
+struct Group(Rc<GroupState>);
+
+impl Group {
+    fn join(&self, handler: DynamicHandler) -> u64 {
+        let id = self.0.next_id.get();
+        self.0.next_id.set(id + 1);
+        self.0.members.borrow_mut().push(Member {
+            id,
+            handler,
+            content: None,
+            revealed: false,
+        });
+        id
+    }
+
+    fn leave(&self, id: u64) {
+        self.0.members.borrow_mut().retain(|member| member.id != id);
+        self.reveal();
+    }
+
+    fn ready(&self, id: u64, content: AnyView) {
+        if let Some(member) = self
+            .0
+            .members
+            .borrow_mut()
+            .iter_mut()
+            .find(|member| member.id == id)
+        {
+            member.content = Some(content);
+        }
+        self.reveal();
+    }
+
+    fn reveal(&self) {
+        let mut revealed = Vec::new();
+        {
+            let mut members = self.0.members.borrow_mut();
+            let all_loaded = members
+                .iter()
+                .all(|member| member.revealed || member.content.is_some());
+            for member in members.iter_mut().filter(|member| !member.revealed) {
+                let Some(content) = (match self.0.order {
+                    RevealOrder::Together if !all_loaded => None,
+                    _ => member.content.take(),
+                }) else {
+                    break;
+                };
+                member.revealed = true;
+                revealed.push((member.handler.clone(), content));
+            }
+        }
+        // Contents are revealed once the group is released, as they may join it.
+        for (handler, content) in revealed {
             handler.set(content);
+        }
+    }
+}
+
+/// Where a suspense view reveals its content.
+#[derive(Clone)]
+enum Reveal {
+    Direct(DynamicHandler),
+    Group(Group, u64),
+}
+
+impl Reveal {
+    fn ready(&self, content: AnyView) {
+        match self {
+            Self::Direct(handler) => handler.set(content),
+            Self::Group(group, id) => group.ready(*id, content),
+        }
+    }
+}
+
+/// The load of a suspense view, cancelled when dropped.
+struct Pending {
+    task: Option<LocalTask<()>>,
+    reveal: Reveal,
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        if let Reveal::Group(group, id) = &self.reveal {
+            group.leave(*id);
+        }
+        if let Some(task) = self.task.take() {
+            LocalTask::on_main(task.cancel());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::{
+        cell::{Cell, RefCell},
+        fmt,
+        future::poll_fn,
+        task::{Poll, Waker},
+        time::Duration,
+    };
+
+    use waterui_core::{AnyView, Environment, View, components::Metadata};
+
+    use super::{RevealOrder, Suspense, SuspenseGroup};
+    use crate::{component::Dynamic, widget::error::UseDefaultErrorView};
+
+    /// A signal letting a test decide when content finishes loading.
+    #[derive(Default)]
+    struct Gate {
+        open: Cell<bool>,
+        waker: RefCell<Option<Waker>>,
+    }
+
+    impl Gate {
+        async fn wait(self: Rc<Self>) {
+            poll_fn(|cx| {
+                if self.open.get() {
+                    Poll::Ready(())
+                } else {
+                    *self.waker.borrow_mut() = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            })
+            .await;
+        }
+
+        fn open(&self) {
+            self.open.set(true);
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// The label of the view a suspense view shows, updated as it changes.
+    type Shown = Rc<RefCell<&'static str>>;
+
+    /// Builds `view` as a renderer does, up to its dynamic content, returning it with the
+    /// environment keeping it alive.
+    fn build(view: impl View, env: &Environment) -> (Dynamic, Environment) {
+        let (mut view, mut env) = (AnyView::new(view), env.clone());
+        loop {
+            view = match view.downcast::<Dynamic>() {
+                Ok(dynamic) => return (*dynamic, env),
+                Err(other) => match other.downcast::<Metadata<Environment>>() {
+                    Ok(metadata) => {
+                        env = metadata.value;
+                        metadata.content
+                    }
+                    Err(other) => AnyView::new(other.body(&env)),
+                },
+            };
+        }
+    }
+
+    /// Builds `view`, returning what it shows and the environment keeping it alive.
+    fn show(view: impl View, env: &Environment) -> (Shown, Environment) {
+        let (dynamic, env) = build(view, env);
+        let shown = Shown::default();
+        dynamic.connect({
+            let shown = shown.clone();
+            move |view, _| {
+                *shown.borrow_mut() = view
+                    .downcast_ref::<&'static str>()
+                    .copied()
+                    .unwrap_or(view.name());
+            }
         });
+        (shown, env)
+    }
+
+    async fn load(gate: Rc<Gate>, label: &'static str) -> &'static str {
+        gate.wait().await;
+        label
+    }
+
+    #[test]
+    fn shows_the_content_once_loaded() {
+        let gate = Rc::new(Gate::default());
+        let view = Suspense::new(load(gate.clone(), "content")).loading("loading");
+        let (shown, _env) = show(view, &Environment::new());
+        assert_eq!(*shown.borrow(), "loading");
+
+        gate.open();
+        assert_eq!(*shown.borrow(), "content");
+    }
+
+    #[test]
+    fn dropping_the_view_cancels_the_load() {
+        let gate = Rc::new(Gate::default());
+        let view = Suspense::new(load(gate.clone(), "content")).loading("loading");
+        let (shown, env) = show(view, &Environment::new());
+        assert_eq!(Rc::strong_count(&gate), 2);
+
+        drop(env);
+        assert_eq!(Rc::strong_count(&gate), 1);
+        gate.open();
+        assert_eq!(*shown.borrow(), "loading");
+    }
+
+    #[test]
+    fn shows_the_timeout_view_when_loading_takes_too_long() {
+        let gate = Rc::new(Gate::default());
+        let view = Suspense::new(load(gate.clone(), "content"))
+            .loading("loading")
+            .timeout(Duration::from_secs(10), "timed out");
+        let (shown, _env) = show(view, &Environment::new());
+        assert_eq!(*shown.borrow(), "timed out");
+
+        gate.open();
+        assert_eq!(*shown.borrow(), "timed out");
+    }
+
+    #[test]
+    fn errors_are_shown_with_the_default_error_view() {
+        let view = Suspense::try_new(async { Err::<(), _>(fmt::Error) });
+        let (dynamic, _env) = build(view, &Environment::new());
+        let failed = Rc::new(Cell::new(false));
+        dynamic.connect({
+            let failed = failed.clone();
+            move |view, _| {
+                let result = view.downcast_ref::<Result<(), UseDefaultErrorView>>();
+                failed.set(result.is_some_and(Result::is_err));
+            }
+        });
+        assert!(failed.get());
+    }
+
+    #[test]
+    fn min_duration_still_waits_for_the_content() {
+        // Timers of the test executor fire at once, so only the content delays the reveal.
+        let gate = Rc::new(Gate::default());
+        let view = Suspense::new(load(gate.clone(), "content"))
+            .loading("loading")
+            .min_duration(Duration::from_millis(300));
+        let (shown, _env) = show(view, &Environment::new());
+        assert_eq!(*shown.borrow(), "loading");
+
+        gate.open();
+        assert_eq!(*shown.borrow(), "content");
+    }
+
+    /// Shows two suspense views in a group, returning what they show and their gates.
+    fn group(order: RevealOrder) -> (Vec<(Shown, Environment)>, [Rc<Gate>; 2]) {
+        let group = AnyView::new(
+            SuspenseGroup::new(())
+                .order(order)
+                .body(&Environment::new()),
+        );
+        let env = group
+            .downcast::<Metadata<Environment>>()
+            .expect("a suspense group changes the environment")
+            .value;
+        let gates = [Rc::new(Gate::default()), Rc::new(Gate::default())];
+        let members = [("first", &gates[0]), ("second", &gates[1])]
+            .into_iter()
+            .map(|(label, gate)| {
+                show(
+                    Suspense::new(load(gate.clone(), label)).loading("loading"),
+                    &env,
+                )
+            })
+            .collect();
+        (members, gates)
+    }
+
+    fn shown(members: &[(Shown, Environment)]) -> Vec<&'static str> {
+        members.iter().map(|(shown, _)| *shown.borrow()).collect()
+    }
+
+    #[test]
+    fn groups_reveal_together() {
+        let (members, [first, second]) = group(RevealOrder::Together);
+        second.open();
+        assert_eq!(shown(&members), ["loading", "loading"]);
+        first.open();
+        assert_eq!(shown(&members), ["first", "second"]);
+    }
+
+    #[test]
+    fn groups_reveal_in_order() {
+        let (members, [first, second]) = group(RevealOrder::InOrder);
+        second.open();
+        assert_eq!(shown(&members), ["loading", "loading"]);
+        first.open();
+        assert_eq!(shown(&members), ["first", "second"]);
+
+        let (members, [first, _second]) = group(RevealOrder::InOrder);
+        first.open();
+        assert_eq!(shown(&members), ["first", "loading"]);
+    }
 
-        view
+    #[test]
+    fn dropped_members_leave_the_group() {
+        let (mut members, [first, _second]) = group(RevealOrder::Together);
+        members.pop();
+        first.open();
+        assert_eq!(shown(&members), ["first"]);
     }
 }